@@ -0,0 +1,38 @@
+//! Criterion benchmarks for every registered algorithm.
+//!
+//! Run with `cargo bench --bench algorithms`; filter with e.g.
+//! `cargo bench --bench algorithms -- markdown/cjk`.
+
+mod common;
+
+use common::{Profile, SIZES};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use std::hint::black_box;
+use std::time::Duration;
+
+fn bench_algorithms(c: &mut Criterion) {
+    let config = common::config();
+
+    for (name, algorithm) in common::algorithms() {
+        for profile in Profile::ALL {
+            let mut group = c.benchmark_group(format!("{}/{}", name, profile.name()));
+
+            for &(label, size) in SIZES {
+                let text = common::corpus(&name, profile, size);
+                group.throughput(Throughput::Bytes(text.len() as u64));
+                if size > 1024 * 1024 {
+                    // Keep the 20 MB runs to a few seconds each
+                    group.sample_size(10);
+                    group.measurement_time(Duration::from_secs(10));
+                }
+                group.bench_with_input(BenchmarkId::from_parameter(label), &text, |b, text| {
+                    b.iter(|| algorithm.chunk(black_box(text), &config))
+                });
+            }
+            group.finish();
+        }
+    }
+}
+
+criterion_group!(benches, bench_algorithms);
+criterion_main!(benches);
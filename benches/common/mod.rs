@@ -0,0 +1,216 @@
+//! Deterministic corpora shared by the criterion benches and `examples/bench.rs`.
+
+#![allow(dead_code)]
+
+use bunkatsu::{AlgorithmRegistry, ChunkAlgorithm, ChunkConfig};
+use serde_json::json;
+use std::sync::Arc;
+
+/// Corpus sizes in bytes: 10 KB, 1 MB and 20 MB.
+pub const SIZES: &[(&str, usize)] = &[
+    ("10KB", 10 * 1024),
+    ("1MB", 1024 * 1024),
+    ("20MB", 20 * 1024 * 1024),
+];
+
+/// Character profile of the generated prose.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Profile {
+    Ascii,
+    Cjk,
+}
+
+impl Profile {
+    pub const ALL: [Profile; 2] = [Profile::Ascii, Profile::Cjk];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Profile::Ascii => "ascii",
+            Profile::Cjk => "cjk",
+        }
+    }
+
+    fn words(self) -> &'static [&'static str] {
+        match self {
+            Profile::Ascii => &[
+                "the",
+                "quick",
+                "brown",
+                "fox",
+                "jumps",
+                "over",
+                "lazy",
+                "dog",
+                "chunk",
+                "text",
+                "library",
+                "document",
+                "retrieval",
+                "model",
+                "index",
+                "query",
+                "answer",
+                "context",
+                "window",
+                "boundary",
+                "sentence",
+                "paragraph",
+                "section",
+                "data",
+            ],
+            Profile::Cjk => &[
+                "分割",
+                "文章",
+                "検索",
+                "モデル",
+                "索引",
+                "質問",
+                "回答",
+                "文脈",
+                "境界",
+                "段落",
+                "東京",
+                "天気",
+                "今日",
+                "明日",
+                "データ",
+                "処理",
+                "高速",
+                "日本語",
+                "文書",
+                "結果",
+            ],
+        }
+    }
+
+    fn word_separator(self) -> &'static str {
+        match self {
+            Profile::Ascii => " ",
+            Profile::Cjk => "",
+        }
+    }
+
+    fn terminator(self) -> &'static str {
+        match self {
+            Profile::Ascii => ". ",
+            Profile::Cjk => "。",
+        }
+    }
+}
+
+/// Small xorshift generator so corpora are identical on every run and platform.
+struct Rng(u64);
+
+impl Rng {
+    fn below(&mut self, n: usize) -> usize {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 % n as u64) as usize
+    }
+}
+
+/// Paragraphs of generated prose until their total reaches `size` bytes.
+fn paragraphs(profile: Profile, size: usize) -> Vec<String> {
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+    let words = profile.words();
+    let mut total = 0;
+    let mut out = Vec::new();
+
+    while total < size {
+        let mut paragraph = String::new();
+        for _ in 0..2 + rng.below(5) {
+            for w in 0..4 + rng.below(12) {
+                if w > 0 {
+                    paragraph.push_str(profile.word_separator());
+                }
+                paragraph.push_str(words[rng.below(words.len())]);
+            }
+            paragraph.push_str(profile.terminator());
+        }
+        let paragraph = paragraph.trim_end().to_string();
+        total += paragraph.len() + 2;
+        out.push(paragraph);
+    }
+    out
+}
+
+/// A document of about `size` bytes in the input format `algorithm` expects.
+///
+/// Structural algorithms get their markup (sections every eight
+/// paragraphs); everything else gets Markdown-flavoured prose.
+pub fn corpus(algorithm: &str, profile: Profile, size: usize) -> String {
+    let paragraphs = paragraphs(profile, size);
+    let section = |i: usize| i.is_multiple_of(8);
+
+    match algorithm {
+        "html" => paragraphs
+            .iter()
+            .enumerate()
+            .map(|(i, p)| {
+                if section(i) {
+                    format!("<h2>Section {}</h2>\n<p>{}</p>", i / 8, p)
+                } else {
+                    format!("<p>{}</p>", p)
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+        "json" => serde_json::to_string(&paragraphs).unwrap(),
+        "latex" => paragraphs
+            .iter()
+            .enumerate()
+            .map(|(i, p)| {
+                if section(i) {
+                    format!("\\section{{Section {}}}\n\n{}", i / 8, p)
+                } else {
+                    p.clone()
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n"),
+        "xml" => {
+            let body: String = paragraphs.iter().map(|p| format!("<p>{}</p>", p)).collect();
+            format!("<doc>{}</doc>", body)
+        }
+        _ => paragraphs
+            .iter()
+            .enumerate()
+            .map(|(i, p)| {
+                if section(i) {
+                    format!("## Section {}\n\n{}", i / 8, p)
+                } else {
+                    p.clone()
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n"),
+    }
+}
+
+/// Every registered algorithm, by name, ready to run on [`corpus`] output.
+pub fn algorithms() -> Vec<(String, Arc<dyn ChunkAlgorithm>)> {
+    let registry = AlgorithmRegistry::new();
+    registry
+        .list()
+        .into_iter()
+        .map(|name| {
+            let mut algorithm = registry.get(&name).unwrap();
+            if name == "xml" {
+                // Registered without element paths, which extracts nothing
+                let options = json!({ "element_paths": ["doc/p"] });
+                algorithm = algorithm.with_options(&options).unwrap().into();
+            }
+            (name, algorithm)
+        })
+        .collect()
+}
+
+/// Config used for every benchmark: 512-byte chunks, with the separator and
+/// pattern that the delimiter and regex algorithms need.
+pub fn config() -> ChunkConfig {
+    ChunkConfig::new(512)
+        .with_overlap(64)
+        .with_separator("\n\n")
+        .with_pattern(r"(?m)^## ")
+}
@@ -0,0 +1,40 @@
+//! Criterion benchmarks for the linear-time algorithms on 50 MB of ASCII.
+//!
+//! These catch regressions to quadratic behaviour that the unit tests only
+//! check under `--ignored`. Run with `cargo bench --bench large_input`.
+
+mod common;
+
+use bunkatsu::{ChunkAlgorithm, ChunkConfig, FixedSizeChunker, SlidingWindowChunker};
+use common::Profile;
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use std::hint::black_box;
+use std::time::Duration;
+
+const SIZE: usize = 50 * 1024 * 1024;
+
+fn bench_large_input(c: &mut Criterion) {
+    let text = common::corpus("fixed_size", Profile::Ascii, SIZE);
+    let algorithms: [(&str, &dyn ChunkAlgorithm, ChunkConfig); 2] = [
+        ("fixed_size", &FixedSizeChunker, ChunkConfig::new(4096)),
+        (
+            "sliding_window",
+            &SlidingWindowChunker,
+            ChunkConfig::new(1000).with_overlap(100),
+        ),
+    ];
+
+    let mut group = c.benchmark_group("large_input/ascii");
+    group.throughput(Throughput::Bytes(text.len() as u64));
+    group.sample_size(10);
+    group.measurement_time(Duration::from_secs(20));
+    for (name, algorithm, config) in algorithms {
+        group.bench_function(name, |b| {
+            b.iter(|| algorithm.chunk(black_box(&text), &config))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_large_input);
+criterion_main!(benches);
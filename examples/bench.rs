@@ -0,0 +1,40 @@
+//! Print chunks per second for every algorithm on generated corpora.
+//!
+//! `cargo run --release --example bench [SIZE_BYTES]`, 1 MB by default.
+
+#[path = "../benches/common/mod.rs"]
+mod common;
+
+use common::Profile;
+use std::time::Instant;
+
+fn main() {
+    let size = match std::env::args().nth(1) {
+        Some(arg) => arg.parse().expect("SIZE_BYTES must be a number"),
+        None => 1024 * 1024,
+    };
+    let config = common::config();
+
+    println!(
+        "{:<20} {:<6} {:>8} {:>10} {:>14} {:>10}",
+        "algorithm", "text", "chunks", "ms", "chunks/sec", "MB/s"
+    );
+    for (name, algorithm) in common::algorithms() {
+        for profile in Profile::ALL {
+            let text = common::corpus(&name, profile, size);
+            let begin = Instant::now();
+            let chunks = algorithm.chunk(&text, &config);
+            let secs = begin.elapsed().as_secs_f64();
+
+            println!(
+                "{:<20} {:<6} {:>8} {:>10.2} {:>14.0} {:>10.1}",
+                name,
+                profile.name(),
+                chunks.len(),
+                secs * 1000.0,
+                chunks.len() as f64 / secs,
+                text.len() as f64 / secs / (1024.0 * 1024.0)
+            );
+        }
+    }
+}
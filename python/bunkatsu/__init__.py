@@ -5,13 +5,44 @@ A Rust-core, Python-first text chunking library designed for RAG, NLP,
 and Document AI systems.
 """
 
-from bunkatsu._bunkatsu import Chunk, ChunkMetadata, Chunker, SentenceDetector
+from bunkatsu._bunkatsu import (
+    AlgorithmNotFoundError,
+    BunkatsuError,
+    Chunk,
+    ChunkConfig,
+    ChunkIndex,
+    ChunkIterator,
+    ChunkMetadata,
+    Chunker,
+    EmptyInputError,
+    InvalidConfigError,
+    ProcessingError,
+    SentenceDetector,
+    load_jsonl,
+    merge_small,
+    save_jsonl,
+    stats,
+    verify_coverage,
+)
 
 __all__ = [
     "Chunker",
     "Chunk", 
+    "ChunkIterator",
+    "ChunkIndex",
+    "ChunkConfig",
     "ChunkMetadata",
     "SentenceDetector",
+    "stats",
+    "merge_small",
+    "save_jsonl",
+    "load_jsonl",
+    "verify_coverage",
+    "BunkatsuError",
+    "InvalidConfigError",
+    "ProcessingError",
+    "EmptyInputError",
+    "AlgorithmNotFoundError",
 ]
 
 __version__ = "0.1.0"
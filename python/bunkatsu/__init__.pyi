@@ -1,13 +1,25 @@
 """Type stubs for bunkatsu."""
 
-from typing import Optional
+import os
+from typing import Any, Callable, Iterator, Literal, Optional, Union
 
 class ChunkMetadata:
     """Metadata associated with a chunk."""
     method: str
     section: Optional[str]
+    section_path: Optional[list[str]]
+    block_type: Optional[str]
     overlap_chars: Optional[int]
     parent_chunk_id: Optional[str]
+    extra: dict[str, str]
+    checksum: Optional[str]
+    chunk_index: Optional[int]
+    total_chunks: Optional[int]
+    prev_chunk_id: Optional[str]
+    next_chunk_id: Optional[str]
+    merged_from: Optional[list[str]]
+    language: Optional[str]
+    language_confidence: Optional[float]
     
     def __init__(
         self,
@@ -15,9 +27,24 @@ class ChunkMetadata:
         section: Optional[str] = None,
         overlap_chars: Optional[int] = None,
         parent_chunk_id: Optional[str] = None,
+        extra: Optional[dict[str, str]] = None,
+        checksum: Optional[str] = None,
+        section_path: Optional[list[str]] = None,
+        block_type: Optional[str] = None,
+        chunk_index: Optional[int] = None,
+        total_chunks: Optional[int] = None,
+        prev_chunk_id: Optional[str] = None,
+        next_chunk_id: Optional[str] = None,
+        merged_from: Optional[list[str]] = None,
+        language: Optional[str] = None,
+        language_confidence: Optional[float] = None,
     ) -> None: ...
     
     def to_dict(self) -> dict: ...
+    
+    def __eq__(self, other: object) -> bool: ...
+    
+    def __hash__(self) -> int: ...
 
 class Chunk:
     """A text chunk with position and metadata."""
@@ -39,41 +66,701 @@ class Chunk:
     @property
     def len(self) -> int: ...
     
+    @property
+    def lexical_richness(self) -> float: ...
+    
+    @property
+    def token_estimate(self) -> int:
+        """Estimated cl100k_base token count of the text, computed on access."""
+        ...
+    
+    @property
+    def overlap_text(self) -> Optional[str]:
+        """Text shared with the previous chunk (first `metadata.overlap_chars` characters)."""
+        ...
+    
+    def verify_checksum(self) -> bool:
+        """Check that the stored checksum matches the chunk text."""
+        ...
+    
+    def original_slice(self, text: str) -> str:
+        """Return the exact substring of the source text at this chunk's span."""
+        ...
+    
     def __len__(self) -> int: ...
+    
+    def __str__(self) -> str:
+        """The full chunk text."""
+        ...
+    
+    def __eq__(self, other: object) -> bool:
+        """Compare id, text, start, end and metadata."""
+        ...
+    
+    def __hash__(self) -> int: ...
 
 class SentenceDetector:
     """Sentence detection method."""
     Regex: "SentenceDetector"
     Unicode: "SentenceDetector"
+    Smart: "SentenceDetector"
+    Custom: "SentenceDetector"
+    Multilingual: "SentenceDetector"
+
+class ChunkConfig:
+    """Reusable configuration for chunking operations."""
+    max_size: int
+    overlap: int
+    sentence_detector: SentenceDetector
+    respect_word_boundaries: bool
+    split_inside_inline_code: bool
+    treat_math_as_atomic: bool
+    preserve_html_blocks: bool
+    separator: Optional[str]
+    keep_separator: bool
+    max_merge_gap: Optional[int]
+    overlap_lines: int
+    pattern: Optional[str]
+    id_strategy: str
+    doc_id: Optional[str]
+    error_on_empty: bool
+    compute_checksum: bool
+    abbreviations: Optional[list[str]]
+    respect_enclosures: bool
+    window_unit: str
+    sentence_terminators: list[str]
+    extra: dict[str, str]
+    strict_paragraphs: bool
+    paragraph_separator: str
+    preserve_separators: bool
+    trim: bool
+    collapse_whitespace: bool
+    normalize_unicode: Optional[str]
+    strip_control_chars: bool
+    normalization: Optional[str]
+    grapheme_boundaries: bool
+    size_mode: Literal["chars", "display_width"]
+    max_tokens: Optional[int]
+    token_estimator: Literal["cl100k_estimate", "cl100k"]
+    balanced: bool
+    detect_language: bool
+    prepend_section: bool
+    skip_empty_segments: bool
+    
+    def __init__(
+        self,
+        max_size: int = 512,
+        overlap: int = 0,
+        sentence_detector: SentenceDetector = ...,
+        respect_word_boundaries: bool = False,
+        split_inside_inline_code: bool = False,
+        treat_math_as_atomic: bool = True,
+        preserve_html_blocks: bool = False,
+        separator: Optional[str] = None,
+        keep_separator: bool = False,
+        max_merge_gap: Optional[int] = None,
+        overlap_lines: int = 0,
+        pattern: Optional[str] = None,
+        id_strategy: str = "uuid",
+        doc_id: Optional[str] = None,
+        error_on_empty: bool = False,
+        compute_checksum: bool = False,
+        abbreviations: Optional[list[str]] = None,
+        respect_enclosures: bool = False,
+        window_unit: str = "chars",
+        sentence_terminators: list[str] = [".", "!", "?"],
+        extra: Optional[dict[str, str]] = None,
+        strict_paragraphs: bool = False,
+        paragraph_separator: str = "\n\n",
+        preserve_separators: bool = False,
+        trim: bool = False,
+        collapse_whitespace: bool = False,
+        normalize_unicode: Optional[str] = None,
+        strip_control_chars: bool = False,
+        normalization: Optional[str] = None,
+        grapheme_boundaries: bool = False,
+        size_mode: Literal["chars", "display_width"] = "chars",
+        max_tokens: Optional[int] = None,
+        token_estimator: Literal["cl100k_estimate", "cl100k"] = "cl100k_estimate",
+        balanced: bool = False,
+        detect_language: bool = False,
+        prepend_section: bool = False,
+        skip_empty_segments: bool = True,
+    ) -> None: ...
+    
+    def validate(self) -> None:
+        """Raise ValueError if the configuration is invalid."""
+        ...
+
+class ChunkIterator(Iterator[Chunk]):
+    """Lazy iterator over chunks, returned by `Chunker.iter_chunks`."""
+    
+    def __iter__(self) -> ChunkIterator: ...
+    def __next__(self) -> Chunk: ...
 
 class Chunker:
     """Main chunker class for text chunking operations."""
+    segmenter: Optional[Callable[[str], list[int]]]
     
-    def __init__(self) -> None: ...
+    def __init__(self, segmenter: Optional[Callable[[str], list[int]]] = None) -> None:
+        """Create a chunker.
+
+        `segmenter` returns the byte offsets at which sentences end and is
+        used when the sentence detector is `SentenceDetector.Custom`.
+        """
+        ...
     
-    def chunk_fixed(self, text: str, max_size: int = 512) -> list[Chunk]:
-        """Chunk text using fixed-size character-based chunking."""
+    def chunk_fixed(
+        self,
+        text: str,
+        max_size: Optional[int] = None,
+        config: Optional[ChunkConfig] = None,
+        respect_words: Optional[bool] = None,
+        graphemes: Optional[bool] = None,
+        size_mode: Optional[Literal["chars", "display_width"]] = None,
+        *,
+        id_strategy: Optional[str] = None,
+        doc_id: Optional[str] = None,
+        normalize: Optional[str] = None,
+        trim: Optional[bool] = None,
+        collapse_whitespace: Optional[bool] = None,
+        normalize_unicode: Optional[str] = None,
+        strip_control_chars: Optional[bool] = None,
+        detect_language: Optional[bool] = None,
+        extra: Optional[dict[str, str]] = None,
+    ) -> list[Chunk]:
+        """Chunk text using fixed-size character-based chunking.
+
+        With `graphemes=True`, `max_size` counts grapheme clusters and chunks
+        never split one. With `size_mode="display_width"`, it counts terminal
+        columns, so full-width CJK characters count 2.
+        """
         ...
     
     def chunk_sliding(
-        self, text: str, max_size: int = 512, overlap: int = 64
+        self,
+        text: str,
+        max_size: Optional[int] = None,
+        overlap: Optional[int] = None,
+        config: Optional[ChunkConfig] = None,
+        unit: Optional[Literal["chars", "sentences"]] = None,
+        *,
+        id_strategy: Optional[str] = None,
+        doc_id: Optional[str] = None,
+        normalize: Optional[str] = None,
+        trim: Optional[bool] = None,
+        collapse_whitespace: Optional[bool] = None,
+        normalize_unicode: Optional[str] = None,
+        strip_control_chars: Optional[bool] = None,
+        detect_language: Optional[bool] = None,
+        extra: Optional[dict[str, str]] = None,
     ) -> list[Chunk]:
-        """Chunk text using sliding window with overlap."""
+        """Chunk text using sliding window with overlap.
+
+        With unit="sentences", max_size and overlap count whole sentences.
+        """
         ...
     
     def chunk_sentences(
         self,
         text: str,
-        max_size: int = 512,
-        detector: SentenceDetector = ...,
+        max_size: Optional[int] = None,
+        detector: Optional[SentenceDetector] = None,
+        config: Optional[ChunkConfig] = None,
+        terminators: Optional[list[str]] = None,
+        *,
+        id_strategy: Optional[str] = None,
+        doc_id: Optional[str] = None,
+        normalize: Optional[str] = None,
+        trim: Optional[bool] = None,
+        collapse_whitespace: Optional[bool] = None,
+        normalize_unicode: Optional[str] = None,
+        strip_control_chars: Optional[bool] = None,
+        detect_language: Optional[bool] = None,
+        extra: Optional[dict[str, str]] = None,
     ) -> list[Chunk]:
-        """Chunk text by sentence boundaries."""
+        """Chunk text by sentence boundaries.
+
+        `terminators` replaces the characters that end a sentence (default
+        ".", "!" and "?"); CJK terminators are always recognized.
+        """
         ...
     
-    def chunk_paragraphs(self, text: str, max_size: int = 512) -> list[Chunk]:
-        """Chunk text by paragraph boundaries."""
+    def chunk_paragraphs(
+        self,
+        text: str,
+        max_size: Optional[int] = None,
+        config: Optional[ChunkConfig] = None,
+        strict_paragraphs: Optional[bool] = None,
+        *,
+        id_strategy: Optional[str] = None,
+        doc_id: Optional[str] = None,
+        normalize: Optional[str] = None,
+        trim: Optional[bool] = None,
+        collapse_whitespace: Optional[bool] = None,
+        normalize_unicode: Optional[str] = None,
+        strip_control_chars: Optional[bool] = None,
+        detect_language: Optional[bool] = None,
+        extra: Optional[dict[str, str]] = None,
+    ) -> list[Chunk]:
+        """Chunk text by paragraph boundaries.
+
+        A paragraph larger than max_size is split by sentence (and by size as
+        a last resort) unless `strict_paragraphs` is true.
+        """
+        ...
+    
+    def chunk_paragraph_sentences(
+        self,
+        text: str,
+        max_size: Optional[int] = None,
+        overlap: Optional[int] = None,
+        config: Optional[ChunkConfig] = None,
+        *,
+        id_strategy: Optional[str] = None,
+        doc_id: Optional[str] = None,
+        normalize: Optional[str] = None,
+        trim: Optional[bool] = None,
+        collapse_whitespace: Optional[bool] = None,
+        normalize_unicode: Optional[str] = None,
+        strip_control_chars: Optional[bool] = None,
+        detect_language: Optional[bool] = None,
+        extra: Optional[dict[str, str]] = None,
+    ) -> list[Chunk]:
+        """Chunk by paragraphs, splitting oversized paragraphs by sentence."""
+        ...
+    
+    def chunk_markdown(
+        self,
+        text: str,
+        max_size: Optional[int] = None,
+        config: Optional[ChunkConfig] = None,
+        split_levels: Optional[list[int]] = None,
+        *,
+        id_strategy: Optional[str] = None,
+        doc_id: Optional[str] = None,
+        normalize: Optional[str] = None,
+        trim: Optional[bool] = None,
+        collapse_whitespace: Optional[bool] = None,
+        normalize_unicode: Optional[str] = None,
+        strip_control_chars: Optional[bool] = None,
+        detect_language: Optional[bool] = None,
+        extra: Optional[dict[str, str]] = None,
+    ) -> list[Chunk]:
+        """Chunk markdown, keeping code, tables and lists whole.
+
+        A new chunk starts at each heading whose level is in ``split_levels``
+        (default all levels); other headings stay inline.
+        """
+        ...
+    
+    def chunk_headings(
+        self,
+        text: str,
+        max_size: Optional[int] = None,
+        config: Optional[ChunkConfig] = None,
+        levels: Optional[list[int]] = None,
+        breadcrumbs: bool = False,
+        respect_max_size: bool = False,
+        *,
+        id_strategy: Optional[str] = None,
+        doc_id: Optional[str] = None,
+        normalize: Optional[str] = None,
+        trim: Optional[bool] = None,
+        collapse_whitespace: Optional[bool] = None,
+        normalize_unicode: Optional[str] = None,
+        strip_control_chars: Optional[bool] = None,
+        detect_language: Optional[bool] = None,
+        extra: Optional[dict[str, str]] = None,
+    ) -> list[Chunk]:
+        """Chunk text at headings of the given levels (default h1 and h2).
+
+        With ``breadcrumbs``, ``metadata.section`` lists every enclosing
+        heading, e.g. ``"h1: Guide > h2: Install"``. With
+        ``respect_max_size``, sections over ``max_size`` are split by
+        paragraphs and then sentences, the first piece keeping the heading.
+        """
+        ...
+    
+    def chunk_recursive(
+        self,
+        text: str,
+        max_size: Optional[int] = None,
+        config: Optional[ChunkConfig] = None,
+        strategy: Optional[Literal["paragraph", "sentence", "markdown"]] = None,
+        *,
+        id_strategy: Optional[str] = None,
+        doc_id: Optional[str] = None,
+        normalize: Optional[str] = None,
+        trim: Optional[bool] = None,
+        collapse_whitespace: Optional[bool] = None,
+        normalize_unicode: Optional[str] = None,
+        strip_control_chars: Optional[bool] = None,
+        detect_language: Optional[bool] = None,
+        extra: Optional[dict[str, str]] = None,
+    ) -> list[Chunk]:
+        """Chunk text by paragraphs, then sentences, then fixed size as needed.
+
+        With ``strategy="markdown"``, markdown structure is split first and
+        deeper chunks keep the ``metadata.section`` they came from.
+        """
+        ...
+    
+    def chunk_html(
+        self,
+        text: str,
+        max_size: Optional[int] = None,
+        config: Optional[ChunkConfig] = None,
+        *,
+        id_strategy: Optional[str] = None,
+        doc_id: Optional[str] = None,
+        normalize: Optional[str] = None,
+        trim: Optional[bool] = None,
+        collapse_whitespace: Optional[bool] = None,
+        normalize_unicode: Optional[str] = None,
+        strip_control_chars: Optional[bool] = None,
+        detect_language: Optional[bool] = None,
+        extra: Optional[dict[str, str]] = None,
+    ) -> list[Chunk]:
+        """Chunk HTML by block-level elements, stripping markup (default max_size 1000)."""
+        ...
+    
+    def chunk_json(
+        self,
+        text: str,
+        max_size: Optional[int] = None,
+        config: Optional[ChunkConfig] = None,
+        *,
+        id_strategy: Optional[str] = None,
+        doc_id: Optional[str] = None,
+        normalize: Optional[str] = None,
+        trim: Optional[bool] = None,
+        collapse_whitespace: Optional[bool] = None,
+        normalize_unicode: Optional[str] = None,
+        strip_control_chars: Optional[bool] = None,
+        detect_language: Optional[bool] = None,
+        extra: Optional[dict[str, str]] = None,
+    ) -> list[Chunk]:
+        """Chunk JSON keeping top-level array elements and object entries intact (default max_size 1000)."""
+        ...
+    
+    def chunk_xml(
+        self,
+        text: str,
+        element_paths: list[str],
+        max_size: Optional[int] = None,
+        config: Optional[ChunkConfig] = None,
+        *,
+        id_strategy: Optional[str] = None,
+        doc_id: Optional[str] = None,
+        normalize: Optional[str] = None,
+        trim: Optional[bool] = None,
+        collapse_whitespace: Optional[bool] = None,
+        normalize_unicode: Optional[str] = None,
+        strip_control_chars: Optional[bool] = None,
+        detect_language: Optional[bool] = None,
+        extra: Optional[dict[str, str]] = None,
+    ) -> list[Chunk]:
+        """Chunk XML by extracting the text of the given element paths (default max_size 1000)."""
+        ...
+    
+    def chunk_latex(
+        self,
+        text: str,
+        max_size: Optional[int] = None,
+        config: Optional[ChunkConfig] = None,
+        strip_comments: bool = True,
+        *,
+        id_strategy: Optional[str] = None,
+        doc_id: Optional[str] = None,
+        normalize: Optional[str] = None,
+        trim: Optional[bool] = None,
+        collapse_whitespace: Optional[bool] = None,
+        normalize_unicode: Optional[str] = None,
+        strip_control_chars: Optional[bool] = None,
+        detect_language: Optional[bool] = None,
+        extra: Optional[dict[str, str]] = None,
+    ) -> list[Chunk]:
+        """Chunk LaTeX at sectioning commands (default max_size 1500).
+
+        Math, float and verbatim environments are kept whole; a chunk holding
+        a single environment has its name in `metadata.block_type`.
+        """
+        ...
+    
+    def chunk_delimiter(
+        self,
+        text: str,
+        separator: str,
+        max_size: Optional[int] = None,
+        keep_separator: Optional[bool] = None,
+        config: Optional[ChunkConfig] = None,
+        *,
+        id_strategy: Optional[str] = None,
+        doc_id: Optional[str] = None,
+        normalize: Optional[str] = None,
+        trim: Optional[bool] = None,
+        collapse_whitespace: Optional[bool] = None,
+        normalize_unicode: Optional[str] = None,
+        strip_control_chars: Optional[bool] = None,
+        detect_language: Optional[bool] = None,
+        extra: Optional[dict[str, str]] = None,
+    ) -> list[Chunk]:
+        """Chunk text on a custom separator string."""
+        ...
+    
+    def chunk_words(
+        self,
+        text: str,
+        words_per_chunk: Optional[int] = None,
+        overlap: Optional[int] = None,
+        config: Optional[ChunkConfig] = None,
+        *,
+        id_strategy: Optional[str] = None,
+        doc_id: Optional[str] = None,
+        normalize: Optional[str] = None,
+        trim: Optional[bool] = None,
+        collapse_whitespace: Optional[bool] = None,
+        normalize_unicode: Optional[str] = None,
+        strip_control_chars: Optional[bool] = None,
+        detect_language: Optional[bool] = None,
+        extra: Optional[dict[str, str]] = None,
+    ) -> list[Chunk]:
+        """Chunk text into groups of at most `words_per_chunk` words."""
+        ...
+    
+    def chunk_regex(
+        self,
+        text: str,
+        pattern: str,
+        max_size: Optional[int] = None,
+        config: Optional[ChunkConfig] = None,
+        keep_delimiter: str = "start",
+        *,
+        id_strategy: Optional[str] = None,
+        doc_id: Optional[str] = None,
+        normalize: Optional[str] = None,
+        trim: Optional[bool] = None,
+        collapse_whitespace: Optional[bool] = None,
+        normalize_unicode: Optional[str] = None,
+        strip_control_chars: Optional[bool] = None,
+        detect_language: Optional[bool] = None,
+        extra: Optional[dict[str, str]] = None,
+    ) -> list[Chunk]:
+        """Chunk text at each match of a regular expression.
+
+        Each match begins the following segment ("start"), ends the
+        preceding one ("end"), or is left out ("drop").
+        """
+        ...
+    
+    def chunk_by_pattern(
+        self,
+        text: str,
+        pattern: str,
+        max_size: Optional[int] = None,
+        config: Optional[ChunkConfig] = None,
+        keep_delimiter: str = "start",
+        *,
+        id_strategy: Optional[str] = None,
+        doc_id: Optional[str] = None,
+        normalize: Optional[str] = None,
+        trim: Optional[bool] = None,
+        collapse_whitespace: Optional[bool] = None,
+        normalize_unicode: Optional[str] = None,
+        strip_control_chars: Optional[bool] = None,
+        detect_language: Optional[bool] = None,
+        extra: Optional[dict[str, str]] = None,
+    ) -> list[Chunk]:
+        """Alias of `chunk_regex`."""
+        ...
+    
+    def chunk_lines(
+        self,
+        text: str,
+        max_size: Optional[int] = None,
+        overlap_lines: Optional[int] = None,
+        config: Optional[ChunkConfig] = None,
+        lines_per_chunk: Optional[int] = None,
+        overlap: Optional[int] = None,
+        *,
+        id_strategy: Optional[str] = None,
+        doc_id: Optional[str] = None,
+        normalize: Optional[str] = None,
+        trim: Optional[bool] = None,
+        collapse_whitespace: Optional[bool] = None,
+        normalize_unicode: Optional[str] = None,
+        strip_control_chars: Optional[bool] = None,
+        detect_language: Optional[bool] = None,
+        extra: Optional[dict[str, str]] = None,
+    ) -> list[Chunk]:
+        """Chunk text by whole lines, or every `lines_per_chunk` lines.
+
+        Packed chunks repeat `overlap_lines` lines; fixed windows share
+        `overlap` lines, which must be less than `lines_per_chunk`.
+        """
+        ...
+    
+    def chunk(
+        self,
+        method: str,
+        text: str,
+        config: Optional[ChunkConfig] = None,
+        max_size: Optional[int] = None,
+        options: Optional[dict[str, Any]] = None,
+        *,
+        id_strategy: Optional[str] = None,
+        doc_id: Optional[str] = None,
+        normalize: Optional[str] = None,
+        trim: Optional[bool] = None,
+        collapse_whitespace: Optional[bool] = None,
+        normalize_unicode: Optional[str] = None,
+        strip_control_chars: Optional[bool] = None,
+        detect_language: Optional[bool] = None,
+        extra: Optional[dict[str, str]] = None,
+    ) -> list[Chunk]:
+        """Chunk with any registered method, setting algorithm knobs from `options`.
+
+        Called as `chunk(method, text, config)`, so one config can be reused
+        across methods.
+        E.g. `options={"levels": [1, 2, 3]}` for heading,
+        `{"strategy": "sentence_first"}` for recursive or `{"mode": "count"}`
+        for line windows of `max_size` lines. Unknown keys raise
+        InvalidConfigError.
+        """
+        ...
+    
+    def chunk_hierarchical(
+        self,
+        text: str,
+        parent_size: Optional[int] = None,
+        child_size: Optional[int] = None,
+        config: Optional[ChunkConfig] = None,
+        method_parent: str = "paragraph",
+        method_child: str = "sentence",
+        *,
+        id_strategy: Optional[str] = None,
+        doc_id: Optional[str] = None,
+        normalize: Optional[str] = None,
+        trim: Optional[bool] = None,
+        collapse_whitespace: Optional[bool] = None,
+        normalize_unicode: Optional[str] = None,
+        strip_control_chars: Optional[bool] = None,
+        detect_language: Optional[bool] = None,
+        extra: Optional[dict[str, str]] = None,
+    ) -> tuple[list[Chunk], list[Chunk]]:
+        """Chunk into parents (default 2048 bytes) and children (default 512 bytes).
+
+        Returns `(parents, children)`. Children are split from each parent's
+        span of the text, keep document offsets, and have
+        `metadata.parent_chunk_id` set to their parent's ID.
+        """
+        ...
+    
+    def iter_chunks(
+        self,
+        text: str,
+        method: str,
+        max_size: Optional[int] = None,
+        config: Optional[ChunkConfig] = None,
+        *,
+        id_strategy: Optional[str] = None,
+        doc_id: Optional[str] = None,
+        normalize: Optional[str] = None,
+        trim: Optional[bool] = None,
+        collapse_whitespace: Optional[bool] = None,
+        normalize_unicode: Optional[str] = None,
+        strip_control_chars: Optional[bool] = None,
+        detect_language: Optional[bool] = None,
+        extra: Optional[dict[str, str]] = None,
+    ) -> ChunkIterator:
+        """Iterate over chunks produced by `method`, yielding them on demand.
+
+        Lazily produced chunks have no `total_chunks` or `next_chunk_id`.
+        """
+        ...
+    
+    def outline(self, text: str) -> list[dict[str, Any]]:
+        """Heading tree of a markdown document.
+
+        Each entry has "level", "title", "section", "start", "end" (byte
+        offsets) and nested "children".
+        """
+        ...
+    
+    def front_matter(self, text: str) -> Optional[dict[str, Any]]:
+        """YAML (`---`) or TOML (`+++`) front matter at the start of a markdown document.
+
+        Returns a dict with "format", "content" and "end" (byte offset where
+        the body begins), or None. `chunk_markdown` leaves it out of chunks.
+        """
         ...
     
     def available_methods(self) -> list[str]:
         """List available chunking methods."""
         ...
+
+class ChunkIndex:
+    """Lookup from byte offsets in the source text to the chunks covering them."""
+    def __init__(self, chunks: list[Chunk]) -> None: ...
+    def find(self, offset: int) -> list[str]:
+        """IDs of the chunks whose span contains byte `offset`, ordered by start."""
+        ...
+    def find_range(self, start: int, end: int) -> list[str]:
+        """IDs of the chunks overlapping bytes `start..end`, ordered by start."""
+        ...
+    def __len__(self) -> int: ...
+
+def stats(
+    chunks: list[Chunk],
+    original_len: Optional[int] = None,
+    max_size: Optional[int] = None,
+) -> dict[str, Any]:
+    """Summarize chunk sizes, coverage, gaps, overlaps and ordering.
+
+    "coverage" is None unless `original_len` is given, and "exceeding" is
+    None unless `max_size` is given.
+    """
+    ...
+
+def merge_small(chunks: list[Chunk], target_size: int, max_size: int) -> list[Chunk]:
+    """Merge adjacent small chunks toward `target_size` bytes without exceeding `max_size`.
+
+    Merged chunks keep the first member's metadata and list the member IDs
+    in `metadata.merged_from`.
+    """
+    ...
+
+def save_jsonl(chunks: list[Chunk], path: Union[str, os.PathLike[str]]) -> None:
+    """Write chunks to `path` as JSON Lines, one chunk per line."""
+    ...
+
+def load_jsonl(path: Union[str, os.PathLike[str]]) -> list[Chunk]:
+    """Read chunks from a JSON Lines file written by `save_jsonl`.
+
+    Unknown fields are ignored and missing metadata fields take their defaults.
+    """
+    ...
+
+def verify_coverage(text: str, chunks: list[Chunk]) -> dict[str, Any]:
+    """Report which byte ranges of `text` the chunks cover.
+
+    "gaps" lists `(start, end)` ranges no chunk covers and "overlaps" lists
+    ranges covered more than once; "complete" is True when there are no gaps.
+    """
+    ...
+
+class BunkatsuError(ValueError):
+    """Base class for bunkatsu errors."""
+
+class InvalidConfigError(BunkatsuError):
+    """Invalid configuration or arguments."""
+
+class ProcessingError(BunkatsuError):
+    """Error while processing the input text."""
+
+class EmptyInputError(BunkatsuError):
+    """Input text was empty or whitespace-only with error_on_empty set."""
+
+class AlgorithmNotFoundError(BunkatsuError):
+    """Unknown chunking method."""
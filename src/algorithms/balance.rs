@@ -0,0 +1,87 @@
+//! Balanced packing of segments into evenly sized chunks.
+
+/// Split segments of the given sizes into as few contiguous groups as greedy
+/// packing would, with sizes as even as possible.
+///
+/// A group's size is the sum of its segment sizes plus `separator` between
+/// each pair. No group exceeds `limit` unless it is a single segment that
+/// does on its own. Among partitions with the fewest groups, the one with
+/// the smallest variance of group sizes is chosen by dynamic programming
+/// over group end positions. Returns the number of segments in each group.
+pub(crate) fn balanced_partition(sizes: &[usize], separator: usize, limit: usize) -> Vec<usize> {
+    let n = sizes.len();
+    if n == 0 {
+        return Vec::new();
+    }
+
+    // Size of the group holding segments start..end
+    let mut prefix = vec![0; n + 1];
+    for (i, size) in sizes.iter().enumerate() {
+        prefix[i + 1] = prefix[i] + size;
+    }
+    let group_size =
+        |start: usize, end: usize| prefix[end] - prefix[start] + separator * (end - start - 1);
+
+    // Greedy packing gives the fewest groups, which fixes the mean size
+    let mut groups = 0u128;
+    let mut start = 0;
+    while start < n {
+        let mut end = start + 1;
+        while end < n && group_size(start, end + 1) <= limit {
+            end += 1;
+        }
+        groups += 1;
+        start = end;
+    }
+    let total = (prefix[n] + separator * (n - groups as usize)) as u128;
+
+    // best[end] = (groups, sum of squared deviations from the mean, scaled by
+    // `groups` to stay integral) for segments 0..end, and where its last group starts
+    let mut best: Vec<Option<(usize, u128, usize)>> = vec![None; n + 1];
+    best[0] = Some((0, 0, 0));
+    for end in 1..=n {
+        for start in (0..end).rev() {
+            let size = group_size(start, end);
+            if size > limit && start + 1 < end {
+                break;
+            }
+            let Some((count, cost, _)) = best[start] else {
+                continue;
+            };
+            let deviation = (size as u128 * groups).abs_diff(total);
+            let candidate = (count + 1, cost + deviation * deviation, start);
+            if best[end].is_none_or(|current| (candidate.0, candidate.1) < (current.0, current.1)) {
+                best[end] = Some(candidate);
+            }
+        }
+    }
+
+    let mut lengths = Vec::new();
+    let mut end = n;
+    while end > 0 {
+        let (_, _, start) = best[end].expect("every prefix can be partitioned");
+        lengths.push(end - start);
+        end = start;
+    }
+    lengths.reverse();
+    lengths
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_balanced_partition() {
+        // Greedy packs 9 + 9 + 1; balanced keeps three groups but sizes 6, 6, 7
+        assert_eq!(
+            balanced_partition(&[3, 3, 3, 3, 3, 3, 1], 0, 9),
+            vec![2, 2, 3]
+        );
+        assert_eq!(balanced_partition(&[3, 3, 3, 3, 3, 1], 1, 11), vec![3, 3]);
+        assert_eq!(balanced_partition(&[5, 1, 1, 1, 1, 1], 0, 6), vec![1, 5]);
+        // Oversized segments stay alone
+        assert_eq!(balanced_partition(&[2, 20, 2], 0, 10), vec![1, 1, 1]);
+        assert!(balanced_partition(&[], 0, 10).is_empty());
+    }
+}
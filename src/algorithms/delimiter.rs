@@ -0,0 +1,230 @@
+//! Delimiter-based chunking algorithm.
+//!
+//! Splits text on a user-supplied separator string (e.g. `\n---\n` or
+//! `<<SPLIT>>`) and packs the resulting segments up to max_size.
+
+use crate::chunk::{Chunk, ChunkMetadata};
+use crate::config::ChunkConfig;
+use crate::traits::ChunkAlgorithm;
+
+/// Separator used when the config does not provide one.
+const DEFAULT_SEPARATOR: &str = "\n\n";
+
+/// Delimiter chunker that splits on `config.separator`.
+pub struct DelimiterChunker;
+
+impl DelimiterChunker {
+    /// Split text into segment spans.
+    ///
+    /// When `keep` is set, each span includes its trailing separator and
+    /// spans are contiguous; otherwise spans cover the trimmed segment only.
+    /// Empty segments (consecutive, leading or trailing separators) are
+    /// skipped when `skip_empty` is set, and kept as empty spans otherwise.
+    fn segments(text: &str, separator: &str, keep: bool, skip_empty: bool) -> Vec<(usize, usize)> {
+        let mut spans: Vec<(usize, usize)> = Vec::new();
+        let mut part_start = 0;
+
+        let mut boundaries: Vec<usize> = text.match_indices(separator).map(|(i, _)| i).collect();
+        boundaries.push(text.len());
+
+        for boundary in boundaries {
+            let part = &text[part_start..boundary];
+            let separator_end = (boundary + separator.len()).min(text.len());
+            let trimmed = part.trim();
+
+            if trimmed.is_empty() && !skip_empty {
+                spans.push((part_start, if keep { separator_end } else { part_start }));
+            } else if trimmed.is_empty() {
+                // Fold the extra separator into the previous segment
+                if let (true, Some(last)) = (keep, spans.last_mut()) {
+                    last.1 = separator_end;
+                }
+            } else {
+                let content_start = part_start + (part.len() - part.trim_start().len());
+                let end = if keep {
+                    separator_end
+                } else {
+                    content_start + trimmed.len()
+                };
+                spans.push((content_start, end));
+            }
+
+            part_start = separator_end;
+        }
+
+        spans
+    }
+
+    /// Create a chunk from packed segment spans.
+    fn make_chunk(&self, text: &str, spans: &[(usize, usize)], keep: bool) -> Chunk {
+        let start = spans.first().map_or(0, |s| s.0);
+        let end = spans.last().map_or(0, |s| s.1);
+        let chunk_text = if keep {
+            text[start..end].to_string()
+        } else {
+            spans
+                .iter()
+                .map(|&(s, e)| &text[s..e])
+                .collect::<Vec<_>>()
+                .join("\n\n")
+        };
+
+        let metadata = ChunkMetadata::for_method(self.name());
+        Chunk::with_uuid(chunk_text, start, end, metadata)
+    }
+}
+
+impl ChunkAlgorithm for DelimiterChunker {
+    fn chunk(&self, text: &str, config: &ChunkConfig) -> Vec<Chunk> {
+        if text.is_empty() {
+            return Vec::new();
+        }
+
+        let separator = config
+            .separator
+            .as_deref()
+            .filter(|s| !s.is_empty())
+            .unwrap_or(DEFAULT_SEPARATOR);
+        let keep = config.keep_separator;
+
+        let mut chunks = Vec::new();
+        let mut current: Vec<(usize, usize)> = Vec::new();
+        let mut current_len = 0;
+
+        for (start, end) in Self::segments(text, separator, keep, config.skip_empty_segments) {
+            // Check if adding this segment would exceed max_size
+            let potential_len = if current.is_empty() {
+                end - start
+            } else if keep {
+                end - current[0].0
+            } else {
+                current_len + 2 + (end - start) // +2 for segment separator
+            };
+
+            if potential_len > config.max_size && !current.is_empty() {
+                chunks.push(self.make_chunk(text, &current, keep));
+                current.clear();
+                current_len = end - start;
+            } else {
+                current_len = potential_len;
+            }
+            current.push((start, end));
+        }
+
+        // Flush remaining segments
+        if !current.is_empty() {
+            chunks.push(self.make_chunk(text, &current, keep));
+        }
+
+        Chunk::link_sequence(chunks)
+    }
+
+    fn name(&self) -> &str {
+        "delimiter"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_delimiter_basic() {
+        let chunker = DelimiterChunker;
+        let config = ChunkConfig::new(12).with_separator("<<SPLIT>>");
+        let chunks = chunker.chunk("alpha<<SPLIT>>beta<<SPLIT>>gamma", &config);
+
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[0].text, "alpha\n\nbeta");
+        assert_eq!(chunks[1].text, "gamma");
+        assert_eq!(chunks[1].start, 27);
+    }
+
+    #[test]
+    fn test_delimiter_keep_separator() {
+        let chunker = DelimiterChunker;
+        let config = ChunkConfig::new(1000)
+            .with_separator("\n---\n")
+            .with_keep_separator(true);
+        let text = "one\n---\ntwo\n---\nthree";
+        let chunks = chunker.chunk(text, &config);
+
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].text, text);
+    }
+
+    #[test]
+    fn test_delimiter_back_to_back() {
+        let chunker = DelimiterChunker;
+        let config = ChunkConfig::new(3).with_separator("|");
+        let chunks = chunker.chunk("a||b|||c", &config);
+
+        let texts: Vec<&str> = chunks.iter().map(|c| c.text.as_str()).collect();
+        assert_eq!(texts, vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn test_delimiter_leading_and_trailing() {
+        let chunker = DelimiterChunker;
+        let config = ChunkConfig::new(1).with_separator("|");
+        let chunks = chunker.chunk("|a|b|", &config);
+
+        let texts: Vec<&str> = chunks.iter().map(|c| c.text.as_str()).collect();
+        assert_eq!(texts, vec!["a", "b"]);
+        assert_eq!(chunks[0].start, 1);
+
+        let keep = config.with_keep_separator(true);
+        let chunks = chunker.chunk("|a||b|", &keep);
+        let texts: Vec<&str> = chunks.iter().map(|c| c.text.as_str()).collect();
+        assert_eq!(texts, vec!["a||", "b|"]);
+    }
+
+    #[test]
+    fn test_delimiter_keep_empty_segments() {
+        let chunker = DelimiterChunker;
+        let config = ChunkConfig::new(1)
+            .with_separator("|")
+            .with_skip_empty_segments(false);
+        let texts = |text: &str, config: &ChunkConfig| -> Vec<(String, usize, usize)> {
+            chunker
+                .chunk(text, config)
+                .into_iter()
+                .map(|c| (c.text, c.start, c.end))
+                .collect()
+        };
+
+        // Back-to-back, leading and trailing separators each leave an empty segment
+        let s = |text: &str, start, end| (text.to_string(), start, end);
+        assert_eq!(
+            texts("a||b", &config),
+            vec![s("a", 0, 1), s("", 2, 2), s("b", 3, 4)]
+        );
+        assert_eq!(
+            texts("|a|b|", &config),
+            vec![s("", 0, 0), s("a", 1, 2), s("b", 3, 4), s("", 5, 5)]
+        );
+        assert_eq!(
+            texts("|a||b|", &config.clone().with_keep_separator(true)),
+            vec![
+                s("|", 0, 1),
+                s("a|", 1, 3),
+                s("|", 3, 4),
+                s("b|", 4, 6),
+                s("", 6, 6)
+            ]
+        );
+
+        // Suppressed by default
+        let skipping = ChunkConfig::new(1).with_separator("|");
+        assert_eq!(texts("|a||b|", &skipping), vec![s("a", 1, 2), s("b", 4, 5)]);
+    }
+
+    #[test]
+    fn test_delimiter_only_separators() {
+        let chunker = DelimiterChunker;
+        let config = ChunkConfig::new(10).with_separator("|");
+
+        assert!(chunker.chunk("|||", &config).is_empty());
+        assert!(chunker.chunk("", &config).is_empty());
+    }
+}
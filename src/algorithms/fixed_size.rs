@@ -1,43 +1,142 @@
 //! Fixed-size character-based chunking algorithm.
 
 use crate::chunk::{Chunk, ChunkMetadata};
-use crate::config::ChunkConfig;
+use crate::config::{ChunkConfig, SizeMode};
+use crate::error::ChunkError;
 use crate::traits::ChunkAlgorithm;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
 /// Fixed-size chunker that splits text into chunks of a specified maximum character count.
 pub struct FixedSizeChunker;
 
-impl ChunkAlgorithm for FixedSizeChunker {
-    fn chunk(&self, text: &str, config: &ChunkConfig) -> Vec<Chunk> {
-        if text.is_empty() || config.max_size == 0 {
-            return Vec::new();
+impl FixedSizeChunker {
+    /// Lazily yield fixed-size chunks without materializing the whole vector.
+    ///
+    /// `text` may be borrowed (`&str`) or owned (`String`), so the iterator can
+    /// outlive the caller's borrow when needed.
+    pub fn chunk_iter<S: AsRef<str>>(&self, text: S, config: &ChunkConfig) -> FixedSizeIter<S> {
+        FixedSizeIter {
+            text,
+            max_size: config.max_size,
+            respect_words: config.respect_word_boundaries,
+            graphemes: config.grapheme_boundaries,
+            size_mode: config.size_mode,
+            start_byte: 0,
         }
+    }
+}
 
-        let mut chunks = Vec::new();
-        let chars: Vec<char> = text.chars().collect();
-        let mut start_char_idx = 0;
+/// Iterator returned by [`FixedSizeChunker::chunk_iter`].
+pub struct FixedSizeIter<S> {
+    text: S,
+    max_size: usize,
+    respect_words: bool,
+    /// Count `max_size` in grapheme clusters instead of chars.
+    graphemes: bool,
+    size_mode: SizeMode,
+    start_byte: usize,
+}
 
-        while start_char_idx < chars.len() {
-            let end_char_idx = (start_char_idx + config.max_size).min(chars.len());
-            let chunk_text: String = chars[start_char_idx..end_char_idx].iter().collect();
+impl<S: AsRef<str>> Iterator for FixedSizeIter<S> {
+    type Item = Chunk;
 
-            // Calculate byte positions for start/end
-            let start_byte = chars[..start_char_idx].iter().map(|c| c.len_utf8()).sum();
-            let end_byte = start_byte + chunk_text.len();
+    fn next(&mut self) -> Option<Chunk> {
+        let text = self.text.as_ref();
+        let start_byte = self.start_byte;
+        if self.max_size == 0 || start_byte >= text.len() {
+            return None;
+        }
 
-            let metadata = ChunkMetadata {
-                method: self.name().to_string(),
-                section: None,
-                overlap_chars: None,
-                parent_chunk_id: None,
-            };
+        let rest = &text[start_byte..];
+        let next_start = match (self.size_mode, self.graphemes) {
+            (SizeMode::Chars, true) => rest
+                .grapheme_indices(true)
+                .nth(self.max_size)
+                .map(|(idx, _)| idx),
+            (SizeMode::Chars, false) => rest.char_indices().nth(self.max_size).map(|(idx, _)| idx),
+            (SizeMode::DisplayWidth, true) => FixedSizeChunker::width_limit(
+                rest.grapheme_indices(true).map(|(idx, g)| (idx, g.width())),
+                self.max_size,
+            ),
+            (SizeMode::DisplayWidth, false) => FixedSizeChunker::width_limit(
+                rest.char_indices()
+                    .map(|(idx, c)| (idx, c.width().unwrap_or(0))),
+                self.max_size,
+            ),
+        };
+        let mut end_byte = next_start.map_or(text.len(), |idx| start_byte + idx);
+        if self.respect_words {
+            end_byte = FixedSizeChunker::word_boundary(text, start_byte, end_byte);
+        }
 
-            chunks.push(Chunk::with_uuid(chunk_text, start_byte, end_byte, metadata));
+        let metadata = ChunkMetadata::for_method(FixedSizeChunker.name());
+        let chunk = Chunk::with_uuid(
+            text[start_byte..end_byte].to_string(),
+            start_byte,
+            end_byte,
+            metadata,
+        );
 
-            start_char_idx = end_char_idx;
+        self.start_byte = end_byte;
+        Some(chunk)
+    }
+}
+
+impl FixedSizeChunker {
+    /// Offset of the first `(offset, width)` unit that would push the summed
+    /// width past `max_width`, or `None` if everything fits.
+    ///
+    /// The first unit is always taken so that a unit wider than `max_width`
+    /// still makes progress.
+    fn width_limit(units: impl Iterator<Item = (usize, usize)>, max_width: usize) -> Option<usize> {
+        let mut width = 0;
+        for (idx, unit_width) in units {
+            width += unit_width;
+            if width > max_width && idx > 0 {
+                return Some(idx);
+            }
         }
+        None
+    }
 
-        chunks
+    /// Move a chunk end back to just after the last whitespace in
+    /// `text[start..end]` so that no word is cut.
+    ///
+    /// The end is kept when it already falls on a word boundary, or when the
+    /// chunk is a single word longer than max_size (which is then hard-split).
+    fn word_boundary(text: &str, start: usize, end: usize) -> usize {
+        let window = &text[start..end];
+        let rest = &text[end..];
+        let cuts_word = !window.ends_with(char::is_whitespace)
+            && rest.starts_with(|c: char| !c.is_whitespace());
+        if !cuts_word {
+            return end;
+        }
+
+        window
+            .char_indices()
+            .rev()
+            .find(|(_, c)| c.is_whitespace())
+            .map_or(end, |(idx, c)| start + idx + c.len_utf8())
+    }
+}
+
+impl ChunkAlgorithm for FixedSizeChunker {
+    fn chunk(&self, text: &str, config: &ChunkConfig) -> Vec<Chunk> {
+        // Byte offsets are tracked incrementally by the iterator, so no Vec<char> is needed
+        Chunk::link_sequence(self.chunk_iter(text, config).collect())
+    }
+
+    fn chunk_finalized_iter(
+        &self,
+        text: String,
+        config: &ChunkConfig,
+    ) -> Result<Box<dyn Iterator<Item = Chunk> + Send + Sync>, ChunkError> {
+        Ok(Box::new(Chunk::finalize_iter(
+            self.chunk_iter(text, config),
+            config.clone(),
+        )))
     }
 
     fn name(&self) -> &str {
@@ -48,6 +147,7 @@ impl ChunkAlgorithm for FixedSizeChunker {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::IdStrategy;
 
     #[test]
     fn test_fixed_size_basic() {
@@ -92,4 +192,288 @@ mod tests {
         assert_eq!(chunks[1].start, 5);
         assert_eq!(chunks[1].end, 10);
     }
+
+    #[test]
+    fn test_fixed_size_iter_matches_chunk() {
+        let chunker = FixedSizeChunker;
+        let config = ChunkConfig::new(4);
+        let text = "日本語テスト and some ascii text";
+        let eager = chunker.chunk(text, &config);
+        let lazy: Vec<Chunk> = chunker.chunk_iter(text, &config).collect();
+
+        assert_eq!(eager.len(), lazy.len());
+        for (a, b) in eager.iter().zip(lazy.iter()) {
+            assert_eq!(a.text, b.text);
+            assert_eq!(a.start, b.start);
+            assert_eq!(a.end, b.end);
+            assert_eq!(a.metadata.method, b.metadata.method);
+        }
+    }
+
+    #[test]
+    fn test_fixed_size_respect_word_boundaries() {
+        let chunker = FixedSizeChunker;
+        let config = ChunkConfig::new(12).with_respect_word_boundaries(true);
+        let text = "The quick brown fox jumps over the lazy dog near the riverbank.";
+        let chunks = chunker.chunk(text, &config);
+
+        for chunk in &chunks {
+            assert!(chunk.text.chars().count() <= 12);
+            assert_eq!(&text[chunk.start..chunk.end], chunk.text);
+            // Every chunk ends on a word boundary
+            assert!(
+                chunk.end == text.len()
+                    || chunk.text.ends_with(' ')
+                    || text[chunk.end..].starts_with(' ')
+            );
+        }
+        assert_eq!(chunks[0].text, "The quick ");
+
+        let lazy: Vec<Chunk> = chunker.chunk_iter(text, &config).collect();
+        assert_eq!(lazy.len(), chunks.len());
+        assert!(lazy
+            .iter()
+            .zip(&chunks)
+            .all(|(a, b)| a.text == b.text && a.start == b.start));
+    }
+
+    #[test]
+    fn test_fixed_size_long_word_hard_split() {
+        let chunker = FixedSizeChunker;
+        let config = ChunkConfig::new(5).with_respect_word_boundaries(true);
+        let chunks = chunker.chunk("a supercalifragilistic word", &config);
+
+        let texts: Vec<&str> = chunks.iter().map(|c| c.text.as_str()).collect();
+        assert_eq!(
+            texts,
+            vec!["a ", "super", "calif", "ragil", "istic", " word"]
+        );
+    }
+
+    /// The previous `Vec<char>` implementation, kept as a reference for output equality.
+    fn reference_chunks(
+        text: &str,
+        max_size: usize,
+        respect_words: bool,
+    ) -> Vec<(String, usize, usize)> {
+        let chars: Vec<char> = text.chars().collect();
+        let mut chunks = Vec::new();
+        let mut start_char_idx = 0;
+
+        while start_char_idx < chars.len() {
+            let mut end_char_idx = (start_char_idx + max_size).min(chars.len());
+            if respect_words
+                && end_char_idx < chars.len()
+                && !chars[end_char_idx - 1].is_whitespace()
+                && !chars[end_char_idx].is_whitespace()
+            {
+                if let Some(space) = chars[start_char_idx..end_char_idx]
+                    .iter()
+                    .rposition(|c| c.is_whitespace())
+                {
+                    end_char_idx = start_char_idx + space + 1;
+                }
+            }
+            let chunk_text: String = chars[start_char_idx..end_char_idx].iter().collect();
+            let start_byte: usize = chars[..start_char_idx].iter().map(|c| c.len_utf8()).sum();
+            let end_byte = start_byte + chunk_text.len();
+            chunks.push((chunk_text, start_byte, end_byte));
+            start_char_idx = end_char_idx;
+        }
+
+        chunks
+    }
+
+    #[test]
+    fn test_fixed_size_matches_reference() {
+        let chunker = FixedSizeChunker;
+        let text = "日本語テスト mixed ascii, émojis 🙂🙃 and longwordswithoutspaces ".repeat(200);
+
+        for max_size in [1, 3, 7, 64, 500] {
+            for respect_words in [false, true] {
+                let config = ChunkConfig::new(max_size).with_respect_word_boundaries(respect_words);
+                let chunks: Vec<(String, usize, usize)> = chunker
+                    .chunk(&text, &config)
+                    .into_iter()
+                    .map(|c| (c.text, c.start, c.end))
+                    .collect();
+                assert_eq!(chunks, reference_chunks(&text, max_size, respect_words));
+            }
+        }
+    }
+
+    #[test]
+    fn test_fixed_size_original_slice() {
+        let text = "日本語 text, split by size.";
+        let chunks = FixedSizeChunker.chunk(text, &ChunkConfig::new(7));
+
+        for chunk in &chunks {
+            assert_eq!(chunk.original_slice(text).unwrap(), chunk.text);
+        }
+
+        // Out of range or mid-character spans are reported, not panicked on
+        let mut bad = chunks[0].clone();
+        bad.end = text.len() + 1;
+        assert!(bad.original_slice(text).is_err());
+        bad.end = 1;
+        assert!(bad.original_slice(text).is_err());
+    }
+
+    #[test]
+    fn test_fixed_size_50mb_output() {
+        let chunker = FixedSizeChunker;
+        let config = ChunkConfig::new(4096);
+        // 50 MB of ASCII and of 3-byte CJK characters
+        for unit in ["a", "語"] {
+            let text = unit.repeat(50_000_000 / unit.len());
+            let chunks = chunker.chunk(&text, &config);
+
+            assert_eq!(chunks.last().unwrap().end, text.len());
+            assert_eq!(chunks.len(), text.chars().count().div_ceil(4096));
+            assert!(chunks.iter().all(|c| c.text.chars().count() <= 4096));
+            assert!(chunks.windows(2).all(|w| w[0].end == w[1].start));
+        }
+    }
+
+    /// Wall-clock check, run with `--ignored`; `benches/large_input.rs`
+    /// tracks the same case with criterion.
+    #[test]
+    #[ignore = "timing-sensitive"]
+    fn test_fixed_size_linear_on_50mb() {
+        use std::time::Instant;
+
+        let chunker = FixedSizeChunker;
+        let config = ChunkConfig::new(4096);
+        // 50 MB of ASCII and of 3-byte CJK characters, against a 5 MB baseline
+        for unit in ["a", "語"] {
+            let small = unit.repeat(5_000_000 / unit.len());
+            let large = small.repeat(10);
+
+            let timer = Instant::now();
+            chunker.chunk(&small, &config);
+            let small_elapsed = timer.elapsed();
+
+            let timer = Instant::now();
+            chunker.chunk(&large, &config);
+            let large_elapsed = timer.elapsed();
+
+            // Linear scaling is ~10x and quadratic ~100x; allow 4x noise over linear
+            assert!(large_elapsed < small_elapsed * 40);
+        }
+    }
+
+    #[test]
+    fn test_fixed_size_finalized_normalizes_text() {
+        use crate::registry::AlgorithmRegistry;
+
+        let text = "  hello \u{7}  world  ";
+        let config = ChunkConfig::new(100)
+            .with_trim(true)
+            .with_collapse_whitespace(true)
+            .with_strip_control_chars(true);
+
+        // The raw output is untouched; the finalized chunks are cleaned up
+        assert_eq!(FixedSizeChunker.chunk(text, &config)[0].text, text);
+        let chunks = FixedSizeChunker.chunk_finalized(text, &config).unwrap();
+        assert_eq!(chunks[0].text, "hello world");
+        assert_eq!((chunks[0].start, chunks[0].end), (0, text.len()));
+
+        let chunks = AlgorithmRegistry::new()
+            .chunk_with("fixed_size", text, &config, "")
+            .unwrap();
+        assert_eq!(chunks[0].text, "hello world");
+    }
+
+    #[test]
+    fn test_fixed_size_finalized_iter_matches_finalized() {
+        let text = "hello world, hello again";
+        let config = ChunkConfig::new(5)
+            .with_id_strategy(IdStrategy::Sequential)
+            .with_compute_checksum(true);
+
+        let eager = FixedSizeChunker.chunk_finalized(text, &config).unwrap();
+        let lazy: Vec<Chunk> = FixedSizeChunker
+            .chunk_finalized_iter(text.to_string(), &config)
+            .unwrap()
+            .collect();
+        assert_eq!(lazy.len(), eager.len());
+        for (lazy, eager) in lazy.iter().zip(&eager) {
+            assert_eq!(lazy.id, eager.id);
+            assert_eq!(lazy.text, eager.text);
+            assert_eq!(lazy.metadata.chunk_index, eager.metadata.chunk_index);
+            assert_eq!(lazy.metadata.prev_chunk_id, eager.metadata.prev_chunk_id);
+            assert_eq!(lazy.metadata.checksum, eager.metadata.checksum);
+            // Unknown until the iterator is exhausted
+            assert_eq!(lazy.metadata.total_chunks, None);
+        }
+    }
+
+    #[test]
+    fn test_fixed_size_preserve_separators() {
+        // Fixed-size chunks are always verbatim slices
+        let text = " hello\n\n world\t ";
+        for respect in [false, true] {
+            let config = ChunkConfig::new(4)
+                .with_respect_word_boundaries(respect)
+                .with_preserve_separators(true);
+            let chunks = FixedSizeChunker.chunk(text, &config);
+            assert_eq!(
+                chunks.iter().map(|c| c.text.as_str()).collect::<String>(),
+                text
+            );
+        }
+    }
+
+    #[test]
+    fn test_fixed_size_grapheme_boundaries() {
+        let family = "👨\u{200d}👩\u{200d}👧";
+        let text = format!("{}{}e\u{301}a\u{308}xyz", family, family);
+        let config = ChunkConfig::new(2).with_grapheme_boundaries(true);
+        let chunks = FixedSizeChunker.chunk(&text, &config);
+
+        let texts: Vec<&str> = chunks.iter().map(|c| c.text.as_str()).collect();
+        assert_eq!(
+            texts,
+            vec![
+                format!("{}{}", family, family).as_str(),
+                "e\u{301}a\u{308}",
+                "xy",
+                "z"
+            ]
+        );
+        for chunk in &chunks {
+            assert_eq!(&text[chunk.start..chunk.end], chunk.text);
+        }
+        let lazy: Vec<Chunk> = FixedSizeChunker.chunk_iter(&text, &config).collect();
+        assert_eq!(lazy.len(), chunks.len());
+        assert!(lazy.iter().zip(&chunks).all(|(a, b)| a.text == b.text));
+
+        // Counting chars splits the family and the accent off its base
+        let chunks = FixedSizeChunker.chunk(&text, &ChunkConfig::new(2));
+        assert_eq!(chunks[0].text, "👨\u{200d}");
+    }
+
+    #[test]
+    fn test_fixed_size_display_width() {
+        let text = "ab日本語cd漢字e\u{301}fg";
+        let config = ChunkConfig::new(5).with_size_mode(SizeMode::DisplayWidth);
+        let chunks = FixedSizeChunker.chunk(text, &config);
+
+        let texts: Vec<&str> = chunks.iter().map(|c| c.text.as_str()).collect();
+        assert_eq!(texts, vec!["ab日", "本語c", "d漢字", "e\u{301}fg"]);
+        for chunk in &chunks {
+            assert!(chunk.text.width() <= 5);
+            assert_eq!(&text[chunk.start..chunk.end], chunk.text);
+        }
+        // Full-width chunks hold fewer chars than max_size
+        assert_eq!(chunks[1].text.chars().count(), 3);
+        // The combining accent counts 0 and stays with its base
+        assert_eq!(chunks[3].text.chars().count(), 4);
+
+        // A char wider than max_size still makes progress
+        let config = ChunkConfig::new(1).with_size_mode(SizeMode::DisplayWidth);
+        let chunks = FixedSizeChunker.chunk("日本", &config);
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[0].text, "日");
+    }
 }
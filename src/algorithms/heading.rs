@@ -1,11 +1,39 @@
 //! Heading-based chunking algorithm.
 //!
-//! Splits text at heading boundaries (# ## ### etc.)
+//! Splits text at heading boundaries (# ## ### etc., or setext `===`/`---` underlines)
 
+use crate::algorithms::markdown::{line_spans, prepend_sections, setext_level, trimmed_span};
+use crate::algorithms::ParagraphChunker;
 use crate::chunk::{Chunk, ChunkMetadata};
 use crate::config::ChunkConfig;
-use crate::traits::ChunkAlgorithm;
+use crate::error::ChunkError;
+use crate::traits::{parse_options, ChunkAlgorithm};
 use regex::Regex;
+use serde::Deserialize;
+use serde_json::Value;
+use std::sync::OnceLock;
+
+/// ATX heading line: `#` to `######` followed by the title.
+fn heading_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"^(#{1,6})\s+(.+)$").unwrap())
+}
+
+/// Reject an empty heading level list or levels outside 1..=6.
+pub(crate) fn check_levels(levels: &[usize]) -> Result<(), ChunkError> {
+    if levels.is_empty() {
+        return Err(ChunkError::InvalidConfig(
+            "heading levels must not be empty".to_string(),
+        ));
+    }
+    if let Some(level) = levels.iter().find(|l| !(1..=6).contains(*l)) {
+        return Err(ChunkError::InvalidConfig(format!(
+            "heading levels must be between 1 and 6, got {}",
+            level
+        )));
+    }
+    Ok(())
+}
 
 /// A parsed heading with its content.
 #[derive(Debug)]
@@ -14,48 +42,113 @@ struct HeadingSection {
     level: usize,
     /// The heading text
     title: String,
+    /// Titles of ancestor headings down to this one
+    path: Option<Vec<String>>,
+    /// Ancestor headings down to this one as "h1: Guide > h2: Install"
+    breadcrumb: Option<String>,
     /// Content under this heading
     content: String,
     /// Start byte position
     start: usize,
+    /// Byte position where the content after the heading line starts
+    content_start: usize,
     /// End byte position
     end: usize,
 }
 
 /// Heading-based chunker that splits at heading boundaries.
+///
+/// Like [`MarkdownChunker`](crate::algorithms::MarkdownChunker), chunk text is
+/// trimmed and `start..end` spans its first to last retained source byte.
+///
+/// Each section becomes one chunk whatever its size unless
+/// `respect_max_size` is set.
 pub struct HeadingChunker {
     /// Which heading levels to split at (e.g., [1, 2] for # and ##)
     pub levels: Vec<usize>,
+    /// Label sections with every enclosing heading ("h1: Guide > h2: Install")
+    /// instead of only the heading that starts the chunk
+    pub breadcrumbs: bool,
+    /// Split sections larger than the config's size limit with
+    /// [`ParagraphChunker`], which falls back to sentences. The heading line
+    /// starts the first piece.
+    pub respect_max_size: bool,
 }
 
 impl Default for HeadingChunker {
     fn default() -> Self {
-        Self {
-            levels: vec![1, 2], // Default: split at h1 and h2
-        }
+        Self::new(vec![1, 2]) // Default: split at h1 and h2
     }
 }
 
 impl HeadingChunker {
     /// Create a new HeadingChunker with specified levels.
     pub fn new(levels: Vec<usize>) -> Self {
-        Self { levels }
+        Self {
+            levels,
+            breadcrumbs: false,
+            respect_max_size: false,
+        }
+    }
+
+    /// Set whether `section` holds the full heading breadcrumb.
+    pub fn with_breadcrumbs(mut self, breadcrumbs: bool) -> Self {
+        self.breadcrumbs = breadcrumbs;
+        self
+    }
+
+    /// Set whether sections larger than the size limit are split further.
+    pub fn with_respect_max_size(mut self, respect_max_size: bool) -> Self {
+        self.respect_max_size = respect_max_size;
+        self
+    }
+
+    /// Create a new HeadingChunker, rejecting an empty level list or levels outside 1..=6.
+    pub fn try_new(levels: Vec<usize>) -> Result<Self, ChunkError> {
+        check_levels(&levels)?;
+        Ok(Self::new(levels))
     }
 
     /// Parse text into sections based on headings.
     fn parse_sections(&self, text: &str) -> Vec<HeadingSection> {
-        let heading_re = Regex::new(r"^(#{1,6})\s+(.+)$").unwrap();
         let mut sections = Vec::new();
         let mut current_section: Option<HeadingSection> = None;
-        let mut current_pos = 0;
-
-        for line in text.lines() {
-            let line_start = current_pos;
-            let line_end = current_pos + line.len();
+        let mut heading_stack: Vec<(usize, String)> = Vec::new();
+        let mut starts_paragraph = true;
+        let mut skip_underline = false;
+
+        for (line_start, line, next_line_start) in line_spans(text) {
+            // Underline of a setext heading already handled
+            if std::mem::take(&mut skip_underline) {
+                starts_paragraph = true;
+                continue;
+            }
 
-            if let Some(caps) = heading_re.captures(line) {
+            let heading = if let Some(caps) = heading_re().captures(line) {
                 let level = caps.get(1).map(|m| m.as_str().len()).unwrap_or(1);
-                let title = caps.get(2).map(|m| m.as_str()).unwrap_or("").to_string();
+                Some((level, caps.get(2).map(|m| m.as_str()).unwrap_or(""), line))
+            } else if starts_paragraph && !line.trim().is_empty() && setext_level(line).is_none() {
+                // Setext heading: first line of a paragraph underlined with === or ---
+                let underline = text
+                    .get(next_line_start..)
+                    .and_then(|rest| rest.lines().next());
+                let level = underline.and_then(setext_level);
+                skip_underline = level.is_some();
+                level.zip(underline).map(|(level, underline)| {
+                    let raw = &text[line_start..next_line_start + underline.len()];
+                    (level, line.trim(), raw)
+                })
+            } else {
+                None
+            };
+            starts_paragraph = line.trim().is_empty() || heading.is_some();
+
+            if let Some((level, title, raw)) = heading {
+                let title = title.to_string();
+
+                // Track ancestors: a heading closes every open heading at its level or deeper
+                heading_stack.retain(|(l, _)| *l < level);
+                heading_stack.push((level, title.clone()));
 
                 // Check if this heading level should trigger a split
                 if self.levels.contains(&level) {
@@ -70,13 +163,33 @@ impl HeadingChunker {
                     current_section = Some(HeadingSection {
                         level,
                         title,
+                        path: Some(heading_stack.iter().map(|(_, t)| t.clone()).collect()),
+                        breadcrumb: Some(
+                            heading_stack
+                                .iter()
+                                .map(|(l, t)| format!("h{}: {}", l, t))
+                                .collect::<Vec<_>>()
+                                .join(" > "),
+                        ),
                         content: String::new(),
                         start: line_start,
+                        content_start: line_start + raw.len(),
                         end: 0,
                     });
-                } else if let Some(ref mut section) = current_section {
-                    // Add heading to current section content
-                    section.content.push_str(line);
+                } else {
+                    // Add heading to current section content, opening an implicit
+                    // section if the text starts with a non-splitting heading
+                    let section = current_section.get_or_insert_with(|| HeadingSection {
+                        level: 0,
+                        title: String::new(),
+                        path: None,
+                        breadcrumb: None,
+                        content: String::new(),
+                        start: line_start,
+                        content_start: line_start,
+                        end: 0,
+                    });
+                    section.content.push_str(raw);
                     section.content.push('\n');
                 }
             } else if let Some(ref mut section) = current_section {
@@ -88,8 +201,11 @@ impl HeadingChunker {
                     current_section = Some(HeadingSection {
                         level: 0,
                         title: String::new(),
+                        path: None,
+                        breadcrumb: None,
                         content: format!("{}\n", line),
                         start: line_start,
+                        content_start: line_start,
                         end: 0,
                     });
                 } else if let Some(ref mut section) = current_section {
@@ -97,8 +213,6 @@ impl HeadingChunker {
                     section.content.push('\n');
                 }
             }
-
-            current_pos = line_end + 1; // +1 for newline
         }
 
         // Save final section
@@ -112,8 +226,60 @@ impl HeadingChunker {
     }
 }
 
+impl HeadingChunker {
+    /// Split an oversized section into paragraph-packed pieces with
+    /// document offsets.
+    ///
+    /// The heading line is packed like a paragraph, so it starts the first
+    /// piece. If the first paragraph does not fit beside it, the heading is
+    /// prefixed to the piece after it anyway.
+    fn split_section(
+        &self,
+        text: &str,
+        section: &HeadingSection,
+        section_name: Option<String>,
+        config: &ChunkConfig,
+    ) -> Vec<Chunk> {
+        let mut pieces = ParagraphChunker.chunk(&text[section.start..section.end], config);
+        if section.level > 0
+            && pieces.len() > 1
+            && section.start + pieces[0].end <= section.content_start
+        {
+            let heading = pieces.remove(0);
+            pieces[0].text = format!("{}\n\n{}", heading.text, pieces[0].text);
+            pieces[0].start = heading.start;
+        }
+
+        let metadata = ChunkMetadata {
+            section: section_name,
+            section_path: section.path.clone(),
+            ..ChunkMetadata::for_method(self.name())
+        };
+        pieces
+            .into_iter()
+            .map(|piece| {
+                Chunk::with_uuid(
+                    piece.text,
+                    section.start + piece.start,
+                    section.start + piece.end,
+                    metadata.clone(),
+                )
+            })
+            .collect()
+    }
+}
+
+/// Options accepted by [`HeadingChunker::with_options`](ChunkAlgorithm::with_options).
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct HeadingOptions {
+    levels: Option<Vec<usize>>,
+    breadcrumbs: Option<bool>,
+    respect_max_size: Option<bool>,
+}
+
 impl ChunkAlgorithm for HeadingChunker {
-    fn chunk(&self, text: &str, _config: &ChunkConfig) -> Vec<Chunk> {
+    fn chunk(&self, text: &str, config: &ChunkConfig) -> Vec<Chunk> {
         if text.is_empty() {
             return Vec::new();
         }
@@ -122,10 +288,12 @@ impl ChunkAlgorithm for HeadingChunker {
         let mut chunks = Vec::new();
 
         for section in sections {
-            let section_name = if section.level > 0 {
-                Some(format!("h{}: {}", section.level, section.title))
-            } else {
+            let section_name = if section.level == 0 {
                 None
+            } else if self.breadcrumbs {
+                section.breadcrumb.clone()
+            } else {
+                Some(format!("h{}: {}", section.level, section.title))
             };
 
             // Build chunk text with heading if present
@@ -140,31 +308,50 @@ impl ChunkAlgorithm for HeadingChunker {
                 section.content.clone()
             };
 
-            // If section exceeds max_size, we still keep it as one chunk
-            // (recursive chunking would handle further splitting)
+            if self.respect_max_size && config.measure(&chunk_text) > config.size_limit() {
+                chunks.extend(self.split_section(text, &section, section_name, config));
+                continue;
+            }
+
+            // Without respect_max_size, oversized sections stay one chunk
             if !chunk_text.trim().is_empty() {
                 let metadata = ChunkMetadata {
-                    method: self.name().to_string(),
                     section: section_name,
-                    overlap_chars: None,
-                    parent_chunk_id: None,
+                    section_path: section.path,
+                    ..ChunkMetadata::for_method(self.name())
                 };
 
+                let (start, end) = trimmed_span(text, section.start, section.end);
                 chunks.push(Chunk::with_uuid(
                     chunk_text.trim().to_string(),
-                    section.start,
-                    section.end,
+                    start,
+                    end,
                     metadata,
                 ));
             }
         }
 
-        chunks
+        if config.prepend_section {
+            prepend_sections(&mut chunks);
+        }
+        Chunk::link_sequence(chunks)
     }
 
     fn name(&self) -> &str {
         "heading"
     }
+
+    fn with_options(&self, options: &Value) -> Result<Box<dyn ChunkAlgorithm>, ChunkError> {
+        let options: HeadingOptions = parse_options(self.name(), options)?;
+        let levels = options.levels.unwrap_or_else(|| self.levels.clone());
+        let breadcrumbs = options.breadcrumbs.unwrap_or(self.breadcrumbs);
+        let respect_max_size = options.respect_max_size.unwrap_or(self.respect_max_size);
+        Ok(Box::new(
+            Self::try_new(levels)?
+                .with_breadcrumbs(breadcrumbs)
+                .with_respect_max_size(respect_max_size),
+        ))
+    }
 }
 
 #[cfg(test)]
@@ -234,4 +421,235 @@ More content.
 
         assert!(chunks.is_empty());
     }
+
+    #[test]
+    fn test_heading_prepend_section() {
+        let text = "Intro.\n\n# Usage\n\nRun it.\n\n## CLI\n\nType it.";
+        let config = ChunkConfig::new(1000).with_prepend_section(true);
+        let texts: Vec<String> = HeadingChunker::default()
+            .chunk(text, &config)
+            .into_iter()
+            .map(|c| c.text)
+            .collect();
+        assert_eq!(
+            texts,
+            vec![
+                "Intro.",
+                "Usage:\n# Usage\n\nRun it.",
+                "CLI:\n## CLI\n\nType it."
+            ]
+        );
+    }
+
+    #[test]
+    fn test_heading_respect_max_size() {
+        let sentence = "Sections can grow far beyond the limit. ";
+        let paragraphs: Vec<String> = (0..30)
+            .map(|i| format!("Paragraph {}. {}{}", i, sentence, sentence.trim_end()))
+            .collect();
+        let long_paragraph = sentence.repeat(15);
+        let text = format!(
+            "Preface.\n\n## Big\n\n{}\n\n{}\n\n## Small\n\nDone.",
+            paragraphs.join("\n\n"),
+            long_paragraph.trim_end()
+        );
+        assert!(text.len() > 3000);
+        let config = ChunkConfig::new(500);
+
+        // Off by default: the big section stays whole
+        let whole = HeadingChunker::default().chunk(&text, &config);
+        assert_eq!(whole.len(), 3);
+        assert!(whole[1].text.len() > 3000);
+
+        let chunks = HeadingChunker::default()
+            .with_respect_max_size(true)
+            .chunk(&text, &config);
+        assert!(chunks.len() > 8);
+        assert!(chunks.iter().all(|c| c.text.len() <= 500));
+        assert_eq!(chunks[0].text, "Preface.");
+        assert!(chunks[1].text.starts_with("## Big\n\nParagraph 0."));
+        assert_eq!(chunks[1].start, text.find("## Big").unwrap());
+        let big = &chunks[1..chunks.len() - 1];
+        for chunk in big {
+            assert_eq!(chunk.metadata.section.as_deref(), Some("h2: Big"));
+            assert_eq!(chunk.metadata.section_path, Some(vec!["Big".to_string()]));
+            assert_eq!(chunk.metadata.method, "heading");
+        }
+        for chunk in &big[1..] {
+            assert!(!chunk.text.contains("## Big"));
+            assert!(text[chunk.start..chunk.end].starts_with(&chunk.text[..20]));
+            assert!(text[chunk.start..chunk.end].ends_with(&chunk.text[chunk.text.len() - 20..]));
+        }
+        // The oversized paragraph falls back to sentences
+        assert!(big
+            .last()
+            .unwrap()
+            .text
+            .starts_with("Sections can grow far beyond the limit."));
+        assert_eq!(chunks.last().unwrap().text, "## Small\n\nDone.");
+
+        // A first paragraph too big to sit beside the heading still gets it
+        let text = format!("## Big\n\n{}", long_paragraph);
+        let chunks = HeadingChunker::default()
+            .with_respect_max_size(true)
+            .chunk(&text, &config);
+        assert!(chunks.len() > 1);
+        assert!(chunks[0].text.starts_with("## Big\n\nSections can grow"));
+        assert_eq!(chunks[0].start, 0);
+
+        let options = serde_json::json!({ "respect_max_size": true });
+        let chunker = HeadingChunker::default().with_options(&options).unwrap();
+        assert_eq!(chunker.chunk(&text, &config).len(), chunks.len());
+    }
+
+    #[test]
+    fn test_heading_section_path() {
+        let chunker = HeadingChunker::new(vec![1, 2, 3]);
+        let config = ChunkConfig::new(1000);
+        let text = "# Installation
+
+## Linux
+
+### Troubleshooting
+
+Fix it.
+
+# Usage
+
+Run it.";
+        let chunks = chunker.chunk(text, &config);
+
+        assert_eq!(chunks.len(), 4);
+        assert_eq!(
+            chunks[2].metadata.section_path,
+            Some(vec![
+                "Installation".to_string(),
+                "Linux".to_string(),
+                "Troubleshooting".to_string()
+            ])
+        );
+        assert_eq!(
+            chunks[2].metadata.section,
+            Some("h3: Troubleshooting".to_string())
+        );
+        // A new h1 resets the stack
+        assert_eq!(
+            chunks[3].metadata.section_path,
+            Some(vec!["Usage".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_heading_breadcrumbs() {
+        let config = ChunkConfig::new(1000);
+        let text = "# Guide\n\nIntro.\n\n## Install\n\n### Linux\n\napt.\n\n## Usage\n\nRun.\n\n# FAQ\n\nAsk.";
+        let sections = |chunker: HeadingChunker| {
+            chunker
+                .chunk(text, &config)
+                .into_iter()
+                .filter_map(|c| c.metadata.section)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            sections(HeadingChunker::default()),
+            vec!["h1: Guide", "h2: Install", "h2: Usage", "h1: FAQ"]
+        );
+        // The h3 stays inside its h2 chunk but nothing else changes
+        assert_eq!(
+            sections(HeadingChunker::default().with_breadcrumbs(true)),
+            vec![
+                "h1: Guide",
+                "h1: Guide > h2: Install",
+                "h1: Guide > h2: Usage",
+                "h1: FAQ"
+            ]
+        );
+        assert_eq!(
+            sections(HeadingChunker::new(vec![3]).with_breadcrumbs(true))[0],
+            "h1: Guide > h2: Install > h3: Linux"
+        );
+    }
+
+    #[test]
+    fn test_heading_custom_levels() {
+        let config = ChunkConfig::new(1000);
+        let text = "# Title\n\nIntro.\n\n## Part A\n\nA.\n\n### Detail\n\nD.";
+
+        let h1_only = HeadingChunker::try_new(vec![1]).unwrap();
+        assert_eq!(h1_only.chunk(text, &config).len(), 1);
+
+        let with_h3 = HeadingChunker::try_new(vec![1, 2, 3]).unwrap();
+        let chunks = with_h3.chunk(text, &config);
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(chunks[2].metadata.section, Some("h3: Detail".to_string()));
+
+        // A leading heading that does not split is kept as content
+        let h2_only = HeadingChunker::try_new(vec![2]).unwrap();
+        let chunks = h2_only.chunk(text, &config);
+        assert_eq!(chunks.len(), 2);
+        assert!(chunks[0].text.starts_with("# Title"));
+    }
+
+    #[test]
+    fn test_heading_invalid_levels() {
+        assert!(matches!(
+            HeadingChunker::try_new(vec![]),
+            Err(ChunkError::InvalidConfig(_))
+        ));
+        assert!(HeadingChunker::try_new(vec![0]).is_err());
+        assert!(HeadingChunker::try_new(vec![2, 7]).is_err());
+    }
+
+    #[test]
+    fn test_heading_setext() {
+        let config = ChunkConfig::new(1000);
+        let text = "Title\n=====\n\nIntro.\n\nSub\n---\n\nBody.\n\n---\n\nAfter.";
+        let chunks = HeadingChunker::default().chunk(text, &config);
+
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[0].metadata.section, Some("h1: Title".to_string()));
+        assert_eq!(chunks[0].text, "# Title\n\nIntro.");
+        assert_eq!(chunks[1].metadata.section, Some("h2: Sub".to_string()));
+        assert_eq!(chunks[1].start, text.find("Sub").unwrap());
+        assert_eq!(chunks[1].text, "## Sub\n\nBody.\n\n---\n\nAfter.");
+
+        // A setext heading below the split levels stays in the content verbatim
+        let chunks = HeadingChunker::new(vec![1]).chunk(text, &config);
+        assert_eq!(chunks.len(), 1);
+        assert!(chunks[0].text.contains("Sub\n---\n\nBody."));
+    }
+
+    #[test]
+    fn test_heading_spans_match_trimmed_text() {
+        let text = "\nPreface.\n\n# One\n\nBody one.\n\n\n# Two\n\nBody two.\n\n";
+        let chunks = HeadingChunker::default().chunk(text, &ChunkConfig::new(1000));
+        assert_eq!(chunks.len(), 3);
+
+        for chunk in &chunks {
+            let span = &text[chunk.start..chunk.end];
+            assert_eq!(span, chunk.text);
+        }
+        assert_eq!(chunks[2].end, text.trim_end().len());
+    }
+
+    #[test]
+    fn test_heading_crlf_offsets() {
+        let lf =
+            "Preface.\n\n# One\n\nBody one.\n\nTwo\n===\n\nBody two.\n\n## Three\n\nBody three.";
+        let crlf = lf.replace('\n', "\r\n");
+        let chunker = HeadingChunker::default();
+        let config = ChunkConfig::new(1000);
+        let lf_chunks = chunker.chunk(lf, &config);
+        let crlf_chunks = chunker.chunk(&crlf, &config);
+
+        let to_crlf = |pos: usize| pos + lf[..pos].matches('\n').count();
+        assert_eq!(lf_chunks.len(), 4);
+        assert_eq!(lf_chunks.len(), crlf_chunks.len());
+        for (a, b) in lf_chunks.iter().zip(&crlf_chunks) {
+            assert_eq!((to_crlf(a.start), to_crlf(a.end)), (b.start, b.end));
+            assert_eq!(a.metadata.section, b.metadata.section);
+        }
+        assert!(crlf[crlf_chunks[3].start..].starts_with("## Three"));
+    }
 }
@@ -0,0 +1,436 @@
+//! HTML-aware chunking algorithm.
+//!
+//! Strips markup and splits text at block-level elements:
+//! - Paragraphs, list items, block quotes and preformatted text
+//! - Headings (<h1> - <h6>) for section boundaries
+//! - Script and style content is dropped entirely
+
+use crate::chunk::{Chunk, ChunkMetadata};
+use crate::config::ChunkConfig;
+use crate::traits::ChunkAlgorithm;
+
+/// Elements that start or end a block of text.
+const BLOCK_TAGS: &[&str] = &[
+    "p",
+    "li",
+    "blockquote",
+    "pre",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "div",
+    "section",
+    "article",
+    "header",
+    "footer",
+    "ul",
+    "ol",
+    "table",
+    "tr",
+    "td",
+    "th",
+    "br",
+    "hr",
+];
+
+/// Elements whose content is not text and is dropped.
+const RAW_TEXT_TAGS: &[&str] = &["script", "style"];
+
+/// A block of extracted text.
+#[derive(Debug)]
+struct HtmlBlock {
+    /// The extracted text content
+    text: String,
+    /// The heading level (1-6) if this block is a heading
+    heading_level: Option<usize>,
+    /// Start byte position of the first text character
+    start: usize,
+    /// End byte position of the last text character
+    end: usize,
+}
+
+/// A parsed HTML tag.
+#[derive(Debug)]
+struct HtmlTag {
+    /// Lowercased tag name ("!" for comments and declarations)
+    name: String,
+    /// Whether this is a closing tag (</p>)
+    closing: bool,
+    /// Whether this tag closes itself (<br/>)
+    self_closing: bool,
+    /// Length of the tag in bytes
+    len: usize,
+}
+
+/// HTML-aware chunker that strips tags and splits at block elements.
+pub struct HtmlChunker;
+
+impl HtmlChunker {
+    /// Parse a tag at the start of `text`, returning `None` if it is not a tag.
+    fn parse_tag(text: &str) -> Option<HtmlTag> {
+        if let Some(rest) = text.strip_prefix("<!--") {
+            let len = rest.find("-->").map_or(text.len(), |i| i + 7);
+            return Some(HtmlTag {
+                name: "!".to_string(),
+                closing: false,
+                self_closing: true,
+                len,
+            });
+        }
+
+        let body = text.strip_prefix('<')?;
+        let (closing, body) = match body.strip_prefix('/') {
+            Some(rest) => (true, rest),
+            None => (false, body),
+        };
+
+        let is_declaration = body.starts_with('!') || body.starts_with('?');
+        if !is_declaration && !body.starts_with(|c: char| c.is_ascii_alphabetic()) {
+            return None;
+        }
+
+        // Find the closing '>' while skipping quoted attribute values
+        let mut quote: Option<char> = None;
+        let mut tag_end = None;
+        for (idx, ch) in text.char_indices().skip(1) {
+            match quote {
+                Some(q) if ch == q => quote = None,
+                Some(_) => {}
+                None if ch == '"' || ch == '\'' => quote = Some(ch),
+                None if ch == '>' => {
+                    tag_end = Some(idx + 1);
+                    break;
+                }
+                None => {}
+            }
+        }
+        let len = tag_end?;
+
+        if is_declaration {
+            return Some(HtmlTag {
+                name: "!".to_string(),
+                closing: false,
+                self_closing: true,
+                len,
+            });
+        }
+
+        let name: String = body
+            .chars()
+            .take_while(|c| c.is_ascii_alphanumeric())
+            .collect::<String>()
+            .to_ascii_lowercase();
+
+        Some(HtmlTag {
+            name,
+            closing,
+            self_closing: text[..len].ends_with("/>"),
+            len,
+        })
+    }
+
+    /// Decode an entity at the start of `text`, returning the text and bytes consumed.
+    fn decode_entity(text: &str) -> Option<(String, usize)> {
+        let semi = text.char_indices().take(12).find(|&(_, c)| c == ';')?.0;
+        let entity = &text[1..semi];
+
+        let decoded = match entity {
+            "amp" => '&',
+            "lt" => '<',
+            "gt" => '>',
+            "quot" => '"',
+            "apos" => '\'',
+            "nbsp" => ' ',
+            _ => {
+                let code = if let Some(hex) = entity
+                    .strip_prefix("#x")
+                    .or_else(|| entity.strip_prefix("#X"))
+                {
+                    u32::from_str_radix(hex, 16).ok()?
+                } else {
+                    entity.strip_prefix('#')?.parse().ok()?
+                };
+                char::from_u32(code)?
+            }
+        };
+
+        Some((decoded.to_string(), semi + 1))
+    }
+
+    /// Push the pending text as a block if it contains anything.
+    fn flush_block(
+        blocks: &mut Vec<HtmlBlock>,
+        current: &mut String,
+        start: &mut Option<usize>,
+        end: usize,
+        heading_level: Option<usize>,
+    ) {
+        let trimmed = current.trim();
+        if let (false, Some(block_start)) = (trimmed.is_empty(), *start) {
+            blocks.push(HtmlBlock {
+                text: trimmed.to_string(),
+                heading_level,
+                start: block_start,
+                end,
+            });
+        }
+        current.clear();
+        *start = None;
+    }
+
+    /// Parse HTML into blocks of extracted text.
+    fn parse_blocks(text: &str) -> Vec<HtmlBlock> {
+        let mut blocks = Vec::new();
+        let mut current = String::new();
+        let mut block_start: Option<usize> = None;
+        let mut block_end = 0;
+        let mut heading_level: Option<usize> = None;
+        let mut pre_depth = 0usize;
+        let mut pos = 0;
+
+        while pos < text.len() {
+            let rest = &text[pos..];
+
+            if rest.starts_with('<') {
+                if let Some(tag) = Self::parse_tag(rest) {
+                    pos += tag.len;
+
+                    if RAW_TEXT_TAGS.contains(&tag.name.as_str()) && !tag.closing {
+                        if !tag.self_closing {
+                            // Skip everything up to and including the matching end tag
+                            let close = format!("</{}", tag.name);
+                            pos = match text[pos..].to_ascii_lowercase().find(&close) {
+                                Some(idx) => {
+                                    let after = pos + idx;
+                                    text[after..]
+                                        .find('>')
+                                        .map_or(text.len(), |i| after + i + 1)
+                                }
+                                None => text.len(),
+                            };
+                        }
+                    } else if BLOCK_TAGS.contains(&tag.name.as_str()) {
+                        Self::flush_block(
+                            &mut blocks,
+                            &mut current,
+                            &mut block_start,
+                            block_end,
+                            heading_level,
+                        );
+
+                        let name = tag.name.as_str();
+                        if let Some(level) =
+                            name.strip_prefix('h').and_then(|l| l.parse::<usize>().ok())
+                        {
+                            heading_level = if tag.closing { None } else { Some(level) };
+                        } else if name == "pre" && !tag.self_closing {
+                            if tag.closing {
+                                pre_depth = pre_depth.saturating_sub(1);
+                            } else {
+                                pre_depth += 1;
+                            }
+                        }
+                    }
+                    continue;
+                }
+            }
+
+            if rest.starts_with('&') {
+                if let Some((decoded, consumed)) = Self::decode_entity(rest) {
+                    block_start.get_or_insert(pos);
+                    current.push_str(&decoded);
+                    pos += consumed;
+                    block_end = pos;
+                    continue;
+                }
+            }
+
+            let ch = rest.chars().next().unwrap_or(' ');
+            if ch.is_whitespace() && pre_depth == 0 {
+                // Collapse runs of whitespace outside preformatted text
+                if !current.is_empty() && !current.ends_with(' ') {
+                    current.push(' ');
+                }
+            } else {
+                if !ch.is_whitespace() {
+                    block_start.get_or_insert(pos);
+                    block_end = pos + ch.len_utf8();
+                }
+                current.push(ch);
+            }
+            pos += ch.len_utf8();
+        }
+
+        Self::flush_block(
+            &mut blocks,
+            &mut current,
+            &mut block_start,
+            block_end,
+            heading_level,
+        );
+
+        blocks
+    }
+
+    /// Create a chunk from the accumulated text.
+    fn make_chunk(&self, text: &str, start: usize, end: usize, section: Option<String>) -> Chunk {
+        let metadata = ChunkMetadata {
+            section,
+            ..ChunkMetadata::for_method(self.name())
+        };
+        Chunk::with_uuid(text.to_string(), start, end, metadata)
+    }
+}
+
+impl ChunkAlgorithm for HtmlChunker {
+    fn chunk(&self, text: &str, config: &ChunkConfig) -> Vec<Chunk> {
+        if text.is_empty() {
+            return Vec::new();
+        }
+
+        let blocks = Self::parse_blocks(text);
+        let mut chunks = Vec::new();
+        let mut current_section: Option<String> = None;
+        let mut current_text = String::new();
+        let mut current_start = 0;
+        let mut current_end = 0;
+
+        for block in blocks {
+            if let Some(level) = block.heading_level {
+                // Headings always start a new chunk
+                if !current_text.is_empty() {
+                    chunks.push(self.make_chunk(
+                        &current_text,
+                        current_start,
+                        current_end,
+                        current_section.clone(),
+                    ));
+                }
+
+                current_section = Some(format!("h{}: {}", level, block.text));
+                current_text = block.text;
+                current_start = block.start;
+                current_end = block.end;
+                continue;
+            }
+
+            let potential_len = if current_text.is_empty() {
+                block.text.len()
+            } else {
+                current_text.len() + 2 + block.text.len() // +2 for block separator
+            };
+
+            if potential_len > config.max_size && !current_text.is_empty() {
+                chunks.push(self.make_chunk(
+                    &current_text,
+                    current_start,
+                    current_end,
+                    current_section.clone(),
+                ));
+                current_text.clear();
+            }
+
+            if current_text.is_empty() {
+                current_start = block.start;
+            } else {
+                current_text.push_str("\n\n");
+            }
+            current_text.push_str(&block.text);
+            current_end = block.end;
+        }
+
+        // Flush remaining text
+        if !current_text.is_empty() {
+            chunks.push(self.make_chunk(
+                &current_text,
+                current_start,
+                current_end,
+                current_section,
+            ));
+        }
+
+        Chunk::link_sequence(chunks)
+    }
+
+    fn name(&self) -> &str {
+        "html"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_html_strips_tags() {
+        let chunker = HtmlChunker;
+        let config = ChunkConfig::new(1000);
+        let text = "<div><p>Hello <b>bold</b> world.</p><p>Second   paragraph.</p></div>";
+        let chunks = chunker.chunk(text, &config);
+
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].text, "Hello bold world.\n\nSecond paragraph.");
+        assert_eq!(chunks[0].start, text.find("Hello").unwrap());
+        assert_eq!(chunks[0].end, text.find("</p></div>").unwrap());
+    }
+
+    #[test]
+    fn test_html_heading_sections() {
+        let chunker = HtmlChunker;
+        let config = ChunkConfig::new(1000);
+        let text = "<h1>Intro</h1><p>First.</p><h2>Details</h2><ul><li>One</li><li>Two</li></ul>";
+        let chunks = chunker.chunk(text, &config);
+
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[0].metadata.section, Some("h1: Intro".to_string()));
+        assert_eq!(chunks[0].text, "Intro\n\nFirst.");
+        assert_eq!(chunks[1].metadata.section, Some("h2: Details".to_string()));
+        assert_eq!(chunks[1].text, "Details\n\nOne\n\nTwo");
+    }
+
+    #[test]
+    fn test_html_entities_and_self_closing() {
+        let chunker = HtmlChunker;
+        let config = ChunkConfig::new(1000);
+        let text =
+            "<p>Fish &amp; chips &lt;3 &#39;yum&#x27;<br/>Next line<img src=\"a>b.png\"/></p>";
+        let chunks = chunker.chunk(text, &config);
+
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].text, "Fish & chips <3 'yum'\n\nNext line");
+    }
+
+    #[test]
+    fn test_html_drops_script_and_style() {
+        let chunker = HtmlChunker;
+        let config = ChunkConfig::new(1000);
+        let text = "<style>p { color: red; }</style><p>Visible</p><SCRIPT>var x = '<p>';</SCRIPT><!-- hidden -->";
+        let chunks = chunker.chunk(text, &config);
+
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].text, "Visible");
+    }
+
+    #[test]
+    fn test_html_split_by_size() {
+        let chunker = HtmlChunker;
+        let config = ChunkConfig::new(20);
+        let text = "<p>First paragraph.</p><p>Second paragraph.</p>";
+        let chunks = chunker.chunk(text, &config);
+
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[1].text, "Second paragraph.");
+    }
+
+    #[test]
+    fn test_html_empty() {
+        let chunker = HtmlChunker;
+        let config = ChunkConfig::new(100);
+
+        assert!(chunker.chunk("", &config).is_empty());
+        assert!(chunker.chunk("<div></div>", &config).is_empty());
+    }
+}
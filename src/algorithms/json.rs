@@ -0,0 +1,269 @@
+//! JSON-aware chunking algorithm.
+//!
+//! Keeps top-level values intact:
+//! - Array roots are packed into sub-arrays of whole elements
+//! - Object roots are packed into sub-objects of whole key/value pairs
+//! - Every chunk's text is valid JSON
+
+use crate::chunk::{Chunk, ChunkMetadata};
+use crate::config::ChunkConfig;
+use crate::error::ChunkError;
+use crate::traits::ChunkAlgorithm;
+use serde::de::{Deserialize, Deserializer, MapAccess, Visitor};
+use serde_json::value::RawValue;
+use std::fmt;
+
+/// Top-level object entries in document order.
+struct OrderedEntries<'a>(Vec<(String, &'a RawValue)>);
+
+impl<'de: 'a, 'a> Deserialize<'de> for OrderedEntries<'a> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct EntriesVisitor;
+
+        impl<'de> Visitor<'de> for EntriesVisitor {
+            type Value = OrderedEntries<'de>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a JSON object")
+            }
+
+            fn visit_map<M: MapAccess<'de>>(self, mut map: M) -> Result<Self::Value, M::Error> {
+                let mut entries = Vec::new();
+                while let Some(entry) = map.next_entry::<String, &'de RawValue>()? {
+                    entries.push(entry);
+                }
+                Ok(OrderedEntries(entries))
+            }
+        }
+
+        deserializer.deserialize_map(EntriesVisitor)
+    }
+}
+
+/// A single top-level element with its JSON text and source span.
+struct JsonElement {
+    /// JSON text of the element ("value" or "\"key\":value")
+    text: String,
+    /// Start byte position in the original text
+    start: usize,
+    /// End byte position in the original text
+    end: usize,
+}
+
+/// JSON chunker that never splits a top-level array element or object entry.
+pub struct JsonChunker;
+
+impl JsonChunker {
+    /// Byte offset of a borrowed raw value within the original text.
+    fn offset_of(text: &str, raw: &RawValue) -> usize {
+        raw.get().as_ptr() as usize - text.as_ptr() as usize
+    }
+
+    /// Collect array elements with their source spans.
+    fn array_elements(text: &str, items: Vec<&RawValue>) -> Vec<JsonElement> {
+        items
+            .into_iter()
+            .map(|raw| {
+                let start = Self::offset_of(text, raw);
+                JsonElement {
+                    text: raw.get().to_string(),
+                    start,
+                    end: start + raw.get().len(),
+                }
+            })
+            .collect()
+    }
+
+    /// Collect object entries with their source spans, starting at the key when found.
+    fn object_elements(text: &str, entries: Vec<(String, &RawValue)>) -> Vec<JsonElement> {
+        entries
+            .into_iter()
+            .map(|(key, raw)| {
+                let value_start = Self::offset_of(text, raw);
+                let encoded_key = serde_json::to_string(&key).unwrap_or_default();
+
+                // Locate the key just before the value (`"key" : value`)
+                let before = text[..value_start].trim_end();
+                let start = before
+                    .strip_suffix(':')
+                    .map(str::trim_end)
+                    .filter(|b| b.ends_with(&encoded_key))
+                    .map_or(value_start, |b| b.len() - encoded_key.len());
+
+                JsonElement {
+                    text: format!("{}:{}", encoded_key, raw.get()),
+                    start,
+                    end: value_start + raw.get().len(),
+                }
+            })
+            .collect()
+    }
+
+    /// Pack whole elements into bracketed JSON chunks up to max_size.
+    fn pack(
+        &self,
+        elements: Vec<JsonElement>,
+        open: char,
+        close: char,
+        config: &ChunkConfig,
+    ) -> Vec<Chunk> {
+        let mut chunks = Vec::new();
+        let mut current: Vec<JsonElement> = Vec::new();
+        let mut current_len = 2; // brackets
+
+        for element in elements {
+            let separator = usize::from(!current.is_empty()); // comma
+            if current_len + separator + element.text.len() > config.max_size && !current.is_empty()
+            {
+                chunks.push(self.make_chunk(&current, open, close));
+                current.clear();
+                current_len = 2;
+            }
+
+            current_len += usize::from(!current.is_empty()) + element.text.len();
+            current.push(element);
+        }
+
+        if !current.is_empty() {
+            chunks.push(self.make_chunk(&current, open, close));
+        }
+
+        chunks
+    }
+
+    /// Build a chunk from a group of elements.
+    fn make_chunk(&self, elements: &[JsonElement], open: char, close: char) -> Chunk {
+        let body: Vec<&str> = elements.iter().map(|e| e.text.as_str()).collect();
+        let text = format!("{}{}{}", open, body.join(","), close);
+
+        let metadata = ChunkMetadata::for_method(self.name());
+        Chunk::with_uuid(
+            text,
+            elements.first().map_or(0, |e| e.start),
+            elements.last().map_or(0, |e| e.end),
+            metadata,
+        )
+    }
+}
+
+impl ChunkAlgorithm for JsonChunker {
+    /// Chunk JSON text. Invalid documents produce no chunks; use
+    /// [`try_chunk`](Self::try_chunk) to get the parse error instead.
+    fn chunk(&self, text: &str, config: &ChunkConfig) -> Vec<Chunk> {
+        self.try_chunk(text, config).unwrap_or_default()
+    }
+
+    /// Chunk JSON, returning an error if the document is invalid.
+    fn try_chunk(&self, text: &str, config: &ChunkConfig) -> Result<Vec<Chunk>, ChunkError> {
+        if text.trim().is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let invalid =
+            |e: serde_json::Error| ChunkError::ProcessingError(format!("invalid JSON: {}", e));
+        let root: &RawValue = serde_json::from_str(text).map_err(invalid)?;
+
+        let chunks = match root.get().as_bytes().first() {
+            Some(b'[') => {
+                let items: Vec<&RawValue> = serde_json::from_str(text).map_err(invalid)?;
+                self.pack(Self::array_elements(text, items), '[', ']', config)
+            }
+            Some(b'{') => {
+                let entries: OrderedEntries = serde_json::from_str(text).map_err(invalid)?;
+                self.pack(Self::object_elements(text, entries.0), '{', '}', config)
+            }
+            _ => {
+                // Scalar root: nothing to split
+                let start = Self::offset_of(text, root);
+                let metadata = ChunkMetadata::for_method(self.name());
+                vec![Chunk::with_uuid(
+                    root.get().to_string(),
+                    start,
+                    start + root.get().len(),
+                    metadata,
+                )]
+            }
+        };
+        Ok(Chunk::link_sequence(chunks))
+    }
+
+    fn name(&self) -> &str {
+        "json"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+
+    #[test]
+    fn test_json_array_packing() {
+        let chunker = JsonChunker;
+        let config = ChunkConfig::new(100);
+        let items: Vec<String> = (0..50)
+            .map(|i| format!(r#"{{"id": {}, "name": "item {}"}}"#, i, i))
+            .collect();
+        let text = format!("[\n  {}\n]", items.join(",\n  "));
+        let chunks = chunker.chunk(&text, &config);
+
+        assert!(chunks.len() > 1);
+        let mut ids = Vec::new();
+        for chunk in &chunks {
+            assert!(chunk.text.len() <= 100);
+            let value: Value = serde_json::from_str(&chunk.text).unwrap();
+            for item in value.as_array().unwrap() {
+                ids.push(item["id"].as_u64().unwrap());
+            }
+            assert!(text[chunk.start..chunk.end].starts_with("{\"id\""));
+            assert!(text[chunk.start..chunk.end].ends_with('}'));
+        }
+        assert_eq!(ids, (0..50).collect::<Vec<u64>>());
+    }
+
+    #[test]
+    fn test_json_oversized_element_alone() {
+        let chunker = JsonChunker;
+        let config = ChunkConfig::new(10);
+        let chunks = chunker.chunk(r#"[1, "a long string element", 2]"#, &config);
+
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(chunks[1].text, r#"["a long string element"]"#);
+    }
+
+    #[test]
+    fn test_json_object_key_groups() {
+        let chunker = JsonChunker;
+        let config = ChunkConfig::new(30);
+        let text = r#"{"b": [1, 2], "a": {"x": 1}, "c": "third"}"#;
+        let chunks = chunker.chunk(text, &config);
+
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[0].text, r#"{"b":[1, 2],"a":{"x": 1}}"#);
+        assert_eq!(chunks[1].text, r#"{"c":"third"}"#);
+        assert_eq!(&text[chunks[1].start..chunks[1].end], r#""c": "third""#);
+        for chunk in &chunks {
+            assert!(serde_json::from_str::<Value>(&chunk.text)
+                .unwrap()
+                .is_object());
+        }
+    }
+
+    #[test]
+    fn test_json_invalid() {
+        let chunker = JsonChunker;
+        let config = ChunkConfig::new(100);
+
+        assert!(chunker.try_chunk("[1, 2", &config).is_err());
+        assert!(chunker.chunk("[1, 2", &config).is_empty());
+    }
+
+    #[test]
+    fn test_json_empty() {
+        let chunker = JsonChunker;
+        let config = ChunkConfig::new(100);
+
+        assert!(chunker.chunk("", &config).is_empty());
+        assert!(chunker.chunk("[]", &config).is_empty());
+    }
+}
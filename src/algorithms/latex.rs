@@ -0,0 +1,483 @@
+//! LaTeX-aware chunking algorithm.
+//!
+//! Splits text at sectioning commands and packs paragraphs up to max_size:
+//! - `\part` through `\subparagraph` (starred or not) start a new chunk
+//! - Math, float and verbatim environments are never split
+//! - `%` comments are optionally stripped (`\%` stays a literal percent sign)
+
+use crate::chunk::{Chunk, ChunkMetadata};
+use crate::config::ChunkConfig;
+use crate::error::ChunkError;
+use crate::traits::{parse_options, ChunkAlgorithm};
+use regex::Regex;
+use serde::Deserialize;
+use serde_json::Value;
+use std::sync::OnceLock;
+
+/// Environments kept whole in a single chunk.
+const ATOMIC_ENVIRONMENTS: &[&str] = &[
+    "equation",
+    "align",
+    "alignat",
+    "gather",
+    "multline",
+    "eqnarray",
+    "displaymath",
+    "math",
+    "figure",
+    "table",
+    "tabular",
+    "algorithm",
+    "tikzpicture",
+    "verbatim",
+    "lstlisting",
+    "minted",
+];
+
+/// Environments whose body is literal: comments are kept and nested
+/// `\begin` commands are not counted.
+const VERBATIM_ENVIRONMENTS: &[&str] = &["verbatim", "lstlisting", "minted"];
+
+/// Sectioning command at the start of a line, up to its opening brace.
+fn section_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(
+            r"^\\(part|chapter|section|subsection|subsubsection|paragraph|subparagraph)\*?\s*(\[[^\]]*\])?\s*\{",
+        )
+        .unwrap()
+    })
+}
+
+/// `\begin{name}` at the start of a line.
+fn begin_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"^\\begin\{([A-Za-z]+\*?)\}").unwrap())
+}
+
+/// A parsed block of LaTeX source.
+#[derive(Debug)]
+struct LatexBlock {
+    /// The block text (comments removed when stripping)
+    text: String,
+    /// The sectioning command if this block is a section heading
+    section: Option<String>,
+    /// The environment name if this block is an atomic environment
+    environment: Option<String>,
+    /// Start byte position
+    start: usize,
+    /// End byte position
+    end: usize,
+}
+
+/// LaTeX-aware chunker that splits at sectioning commands.
+pub struct LatexChunker {
+    /// Whether `%` comments are removed from the chunk text
+    pub strip_comments: bool,
+}
+
+impl Default for LatexChunker {
+    fn default() -> Self {
+        Self {
+            strip_comments: true,
+        }
+    }
+}
+
+impl LatexChunker {
+    /// Create a new LatexChunker.
+    pub fn new(strip_comments: bool) -> Self {
+        Self { strip_comments }
+    }
+
+    /// The part of `line` before its first unescaped `%`.
+    fn strip_comment(line: &str) -> &str {
+        let mut backslashes = 0usize;
+        for (idx, ch) in line.char_indices() {
+            if ch == '%' && backslashes.is_multiple_of(2) {
+                return &line[..idx];
+            }
+            backslashes = if ch == '\\' { backslashes + 1 } else { 0 };
+        }
+        line
+    }
+
+    /// The sectioning command at the start of `line` through its closing brace.
+    fn section_command(line: &str) -> Option<&str> {
+        let open = section_re().find(line)?.end();
+        let mut depth = 1;
+        for (idx, ch) in line[open..].char_indices() {
+            match ch {
+                '{' => depth += 1,
+                '}' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Some(&line[..open + idx + 1]);
+                    }
+                }
+                _ => {}
+            }
+        }
+        Some(line)
+    }
+
+    /// Byte offset just past the `\end{name}` matching the `\begin{name}` at `from`.
+    ///
+    /// Returns the text length if the environment is never closed.
+    fn environment_end(text: &str, from: usize, name: &str, verbatim: bool) -> usize {
+        let begin = format!("\\begin{{{}}}", name);
+        let end = format!("\\end{{{}}}", name);
+        let mut pos = from + begin.len();
+        let mut depth = 1;
+
+        loop {
+            let rest = &text[pos..];
+            let Some(next_end) = rest.find(&end) else {
+                return text.len();
+            };
+            match rest.find(&begin) {
+                Some(next_begin) if !verbatim && next_begin < next_end => {
+                    depth += 1;
+                    pos += next_begin + begin.len();
+                }
+                _ => {
+                    depth -= 1;
+                    pos += next_end + end.len();
+                    if depth == 0 {
+                        return pos;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Push the pending paragraph as a block if it contains anything.
+    fn flush_paragraph(
+        blocks: &mut Vec<LatexBlock>,
+        paragraph: &mut String,
+        start: usize,
+        end: usize,
+    ) {
+        if !paragraph.is_empty() {
+            blocks.push(LatexBlock {
+                text: std::mem::take(paragraph),
+                section: None,
+                environment: None,
+                start,
+                end,
+            });
+        }
+    }
+
+    /// Parse LaTeX into section headings, atomic environments and paragraphs.
+    fn parse_blocks(&self, text: &str) -> Vec<LatexBlock> {
+        let mut blocks = Vec::new();
+        let mut paragraph = String::new();
+        let (mut para_start, mut para_end) = (0, 0);
+        let mut pos = 0;
+
+        while pos < text.len() {
+            let line_end = text[pos..].find('\n').map_or(text.len(), |i| pos + i);
+            let line = &text[pos..line_end];
+            let trimmed = line.trim_start();
+            let content_start = pos + (line.len() - trimmed.len());
+            pos = line_end + 1;
+
+            if let Some(caps) = begin_re().captures(trimmed) {
+                let name = &caps[1];
+                let base = name.trim_end_matches('*');
+                if ATOMIC_ENVIRONMENTS.contains(&base) {
+                    Self::flush_paragraph(&mut blocks, &mut paragraph, para_start, para_end);
+
+                    // The block runs to the end of the line holding `\end{name}`
+                    let verbatim = VERBATIM_ENVIRONMENTS.contains(&base);
+                    let env_end = Self::environment_end(text, content_start, name, verbatim);
+                    let block_end = text[env_end..]
+                        .find('\n')
+                        .map_or(text.len(), |i| env_end + i);
+                    let source = text[content_start..block_end].trim_end();
+                    let block_text = if self.strip_comments && !verbatim {
+                        source
+                            .lines()
+                            .filter(|l| !l.trim_start().starts_with('%'))
+                            .map(|l| Self::strip_comment(l).trim_end())
+                            .collect::<Vec<_>>()
+                            .join("\n")
+                    } else {
+                        source.to_string()
+                    };
+
+                    blocks.push(LatexBlock {
+                        text: block_text,
+                        section: None,
+                        environment: Some(base.to_string()),
+                        start: content_start,
+                        end: content_start + source.len(),
+                    });
+                    pos = block_end + 1;
+                    continue;
+                }
+            }
+
+            let content = if self.strip_comments {
+                // A comment-only line is dropped without ending the paragraph
+                if trimmed.starts_with('%') {
+                    continue;
+                }
+                Self::strip_comment(trimmed)
+            } else {
+                trimmed
+            };
+            let content = content.trim_end();
+
+            if content.is_empty() {
+                Self::flush_paragraph(&mut blocks, &mut paragraph, para_start, para_end);
+                continue;
+            }
+
+            if let Some(command) = Self::section_command(content) {
+                Self::flush_paragraph(&mut blocks, &mut paragraph, para_start, para_end);
+                blocks.push(LatexBlock {
+                    text: content.to_string(),
+                    section: Some(command.to_string()),
+                    environment: None,
+                    start: content_start,
+                    end: content_start + content.len(),
+                });
+                continue;
+            }
+
+            if paragraph.is_empty() {
+                para_start = content_start;
+            } else {
+                paragraph.push('\n');
+            }
+            paragraph.push_str(content);
+            para_end = content_start + content.len();
+        }
+
+        Self::flush_paragraph(&mut blocks, &mut paragraph, para_start, para_end);
+        blocks
+    }
+
+    /// Create a chunk from the accumulated text.
+    fn make_chunk(
+        &self,
+        text: &str,
+        start: usize,
+        end: usize,
+        section: Option<String>,
+        block_type: Option<String>,
+    ) -> Chunk {
+        let metadata = ChunkMetadata {
+            section,
+            block_type,
+            ..ChunkMetadata::for_method(self.name())
+        };
+        Chunk::with_uuid(text.to_string(), start, end, metadata)
+    }
+}
+
+/// Options accepted by [`LatexChunker::with_options`](ChunkAlgorithm::with_options).
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct LatexOptions {
+    strip_comments: Option<bool>,
+}
+
+impl ChunkAlgorithm for LatexChunker {
+    fn chunk(&self, text: &str, config: &ChunkConfig) -> Vec<Chunk> {
+        if text.is_empty() {
+            return Vec::new();
+        }
+
+        let blocks = self.parse_blocks(text);
+        let mut chunks = Vec::new();
+        let mut current_section: Option<String> = None;
+        let mut current_block_type: Option<String> = None;
+        let mut current_text = String::new();
+        let mut current_start = 0;
+        let mut current_end = 0;
+
+        for block in blocks {
+            if let Some(command) = block.section {
+                // Sectioning commands always start a new chunk
+                if !current_text.is_empty() {
+                    chunks.push(self.make_chunk(
+                        &current_text,
+                        current_start,
+                        current_end,
+                        current_section.clone(),
+                        current_block_type.take(),
+                    ));
+                }
+
+                current_section = Some(command);
+                current_block_type = None;
+                current_text = block.text;
+                current_start = block.start;
+                current_end = block.end;
+                continue;
+            }
+
+            let potential_len = if current_text.is_empty() {
+                block.text.len()
+            } else {
+                current_text.len() + 2 + block.text.len() // +2 for block separator
+            };
+
+            if potential_len > config.max_size && !current_text.is_empty() {
+                chunks.push(self.make_chunk(
+                    &current_text,
+                    current_start,
+                    current_end,
+                    current_section.clone(),
+                    current_block_type.take(),
+                ));
+                current_text.clear();
+            }
+
+            // The block type is only kept while the chunk holds a single environment
+            if current_text.is_empty() {
+                current_start = block.start;
+                current_block_type = block.environment;
+            } else {
+                current_text.push_str("\n\n");
+                current_block_type = None;
+            }
+            current_text.push_str(&block.text);
+            current_end = block.end;
+        }
+
+        // Flush remaining text
+        if !current_text.is_empty() {
+            chunks.push(self.make_chunk(
+                &current_text,
+                current_start,
+                current_end,
+                current_section,
+                current_block_type,
+            ));
+        }
+
+        Chunk::link_sequence(chunks)
+    }
+
+    fn name(&self) -> &str {
+        "latex"
+    }
+
+    fn with_options(&self, options: &Value) -> Result<Box<dyn ChunkAlgorithm>, ChunkError> {
+        let options: LatexOptions = parse_options(self.name(), options)?;
+        Ok(Box::new(Self::new(
+            options.strip_comments.unwrap_or(self.strip_comments),
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_latex_sections() {
+        let chunker = LatexChunker::default();
+        let config = ChunkConfig::new(1500);
+        let text = "\\section{Introduction}\nWe study chunking.\n\n\\subsection*[Short]{Related {Work}}\nPrior art.";
+        let chunks = chunker.chunk(text, &config);
+
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(
+            chunks[0].metadata.section,
+            Some("\\section{Introduction}".to_string())
+        );
+        assert_eq!(
+            chunks[0].text,
+            "\\section{Introduction}\n\nWe study chunking."
+        );
+        assert_eq!(
+            chunks[1].metadata.section,
+            Some("\\subsection*[Short]{Related {Work}}".to_string())
+        );
+        assert_eq!(chunks[1].start, text.find("\\subsection").unwrap());
+        assert_eq!(chunks[1].end, text.len());
+    }
+
+    #[test]
+    fn test_latex_atomic_environment() {
+        let chunker = LatexChunker::default();
+        let config = ChunkConfig::new(30);
+        let text = "Energy is conserved.\n\n\\begin{equation}\n  E = mc^2 + \\frac{1}{2}mv^2\n\\end{equation}\n\nDone.";
+        let chunks = chunker.chunk(text, &config);
+
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(chunks[1].metadata.block_type, Some("equation".to_string()));
+        assert!(chunks[1].text.starts_with("\\begin{equation}"));
+        assert!(chunks[1].text.ends_with("\\end{equation}"));
+        assert_eq!(&text[chunks[1].start..chunks[1].end], chunks[1].text);
+        assert_eq!(chunks[0].metadata.block_type, None);
+
+        // Packed together with a paragraph, the chunk is no longer a single block
+        let chunks = chunker.chunk(text, &ChunkConfig::new(1500));
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].metadata.block_type, None);
+    }
+
+    #[test]
+    fn test_latex_nested_environments() {
+        let chunker = LatexChunker::default();
+        let config = ChunkConfig::new(10);
+        let text = "\\begin{figure}[h]\n\\begin{tabular}{c}\n\\begin{tabular}{c}\nx\n\\end{tabular} \\\\\ny\n\\end{tabular}\n\\caption{Nested}\n\\end{figure}\nAfter.";
+        let chunks = chunker.chunk(text, &config);
+
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[0].metadata.block_type, Some("figure".to_string()));
+        assert!(chunks[0].text.ends_with("\\caption{Nested}\n\\end{figure}"));
+        assert_eq!(chunks[1].text, "After.");
+
+        // Same-name nesting is matched by depth
+        let text = "\\begin{tabular}{c}\n\\begin{tabular}{c}\nx\n\\end{tabular}\ny\n\\end{tabular}\nAfter.";
+        let chunks = chunker.chunk(text, &config);
+        assert_eq!(chunks.len(), 2);
+        assert!(chunks[0].text.ends_with("y\n\\end{tabular}"));
+    }
+
+    #[test]
+    fn test_latex_unclosed_environment() {
+        let chunker = LatexChunker::default();
+        let config = ChunkConfig::new(10);
+        let text = "Intro.\n\n\\begin{align}\na &= b \\\\\nc &= d\n\n\\section{Lost}\n";
+        let chunks = chunker.chunk(text, &config);
+
+        // An unclosed environment runs to the end of the text
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[1].metadata.block_type, Some("align".to_string()));
+        assert!(chunks[1].text.ends_with("\\section{Lost}"));
+        assert_eq!(chunks[1].end, text.trim_end().len());
+    }
+
+    #[test]
+    fn test_latex_comments() {
+        let config = ChunkConfig::new(1500);
+        let text = "% preamble note\nWe are 100\\% sure. % really\n% aside\nStill one paragraph.\n\n\\begin{verbatim}\nkeep % this\n\\end{verbatim}";
+
+        let chunks = LatexChunker::default().chunk(text, &config);
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(
+            chunks[0].text,
+            "We are 100\\% sure.\nStill one paragraph.\n\n\\begin{verbatim}\nkeep % this\n\\end{verbatim}"
+        );
+        assert_eq!(chunks[0].start, text.find("We are").unwrap());
+
+        let chunks = LatexChunker::new(false).chunk(text, &config);
+        assert!(chunks[0]
+            .text
+            .starts_with("% preamble note\nWe are 100\\% sure. % really"));
+    }
+
+    #[test]
+    fn test_latex_empty() {
+        let chunker = LatexChunker::default();
+        assert!(chunker.chunk("", &ChunkConfig::new(100)).is_empty());
+    }
+}
@@ -0,0 +1,451 @@
+//! Line-based chunking algorithm.
+//!
+//! Packs whole lines into chunks for logs and CSV-like data:
+//! - Lines keep their original terminators (`\n` or `\r\n`)
+//! - A line is only split when it alone exceeds max_size
+//! - Optional overlap is measured in whole lines (`config.overlap_lines`)
+//!
+//! [`LineMode::Count`] (or [`LineChunker::chunk_by_count`]) instead groups a
+//! fixed number of lines per chunk, for deterministic windows over log files.
+
+use crate::algorithms::FixedSizeChunker;
+use crate::chunk::{Chunk, ChunkMetadata};
+use crate::config::ChunkConfig;
+use crate::error::ChunkError;
+use crate::traits::{parse_options, ChunkAlgorithm};
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::{HashMap, VecDeque};
+
+/// How [`LineChunker`] groups lines.
+///
+/// Named `"pack"` and `"count"` in algorithm options.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LineMode {
+    /// Pack whole lines into chunks of up to `max_size` characters
+    #[default]
+    Pack,
+    /// Group every `max_size` lines, with `overlap` counted in lines
+    Count,
+}
+
+/// Line chunker that never splits a line unless it is oversized.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LineChunker {
+    /// Whether lines are packed by size or grouped by count
+    pub mode: LineMode,
+}
+
+impl LineChunker {
+    /// Create a new LineChunker with the given mode.
+    pub fn new(mode: LineMode) -> Self {
+        Self { mode }
+    }
+
+    /// Find (start, end, char count) spans of each line, including its terminator.
+    fn lines(text: &str) -> Vec<(usize, usize, usize)> {
+        let mut offset = 0;
+        text.split_inclusive('\n')
+            .map(|line| {
+                let span = (offset, offset + line.len(), line.chars().count());
+                offset += line.len();
+                span
+            })
+            .collect()
+    }
+
+    /// Create a chunk from a run of whole lines.
+    fn make_chunk(&self, text: &str, lines: &[(usize, usize, usize)], carried: usize) -> Chunk {
+        let start = lines[0].0;
+        let end = lines[lines.len() - 1].1;
+        let overlap_chars = (carried > 0).then(|| lines[..carried].iter().map(|l| l.2).sum());
+
+        let metadata = ChunkMetadata {
+            overlap_chars,
+            ..ChunkMetadata::for_method(self.name())
+        };
+        Chunk::with_uuid(text[start..end].to_string(), start, end, metadata)
+    }
+
+    /// Group text into windows of at most `config.max_size` lines, repeating
+    /// the last `config.overlap` lines at the start of the next window.
+    ///
+    /// Line terminators are preserved verbatim, so `start`/`end` are exact
+    /// byte offsets. `config.overlap` must be less than `config.max_size`;
+    /// see [`check_count`](Self::check_count).
+    pub fn chunk_by_count(&self, text: &str, config: &ChunkConfig) -> Vec<Chunk> {
+        let lines = Self::lines(text);
+        let lines_per_chunk = config.max_size.max(1);
+        let overlap = config.overlap.min(lines_per_chunk - 1);
+        let step = lines_per_chunk - overlap;
+
+        let mut chunks = Vec::new();
+        let mut first = 0;
+        while first < lines.len() {
+            let last = (first + lines_per_chunk).min(lines.len());
+            let carried = if first == 0 { 0 } else { overlap };
+            chunks.push(self.make_chunk(text, &lines[first..last], carried));
+            if last == lines.len() {
+                break;
+            }
+            first += step;
+        }
+
+        Chunk::link_sequence(chunks)
+    }
+
+    /// Check that the line overlap of [`chunk_by_count`](Self::chunk_by_count)
+    /// leaves room for new lines in every window.
+    pub fn check_count(config: &ChunkConfig) -> Result<(), ChunkError> {
+        if config.overlap >= config.max_size {
+            return Err(ChunkError::InvalidConfig(format!(
+                "overlap ({} lines) must be less than lines_per_chunk ({})",
+                config.overlap, config.max_size
+            )));
+        }
+        Ok(())
+    }
+
+    /// Cut the next fixed-size piece of an oversized line ending at `line_end`.
+    fn line_piece(
+        &self,
+        text: &str,
+        start: usize,
+        line_end: usize,
+        config: &ChunkConfig,
+    ) -> Option<Chunk> {
+        FixedSizeChunker
+            .chunk_iter(&text[start..line_end], config)
+            .next()
+            .map(|piece| {
+                let metadata = ChunkMetadata {
+                    extra: HashMap::from([("split_line".to_string(), "true".to_string())]),
+                    ..ChunkMetadata::for_method(self.name())
+                };
+                Chunk::with_uuid(piece.text, start + piece.start, start + piece.end, metadata)
+            })
+    }
+}
+
+impl LineChunker {
+    /// Lazily yield line-packed chunks, reading lines on demand.
+    ///
+    /// `text` may be borrowed (`&str`) or owned (`String`).
+    pub fn chunk_iter<S: AsRef<str>>(&self, text: S, config: &ChunkConfig) -> LineIter<S> {
+        LineIter {
+            text,
+            config: config.clone(),
+            pos: 0,
+            current: Vec::new(),
+            current_len: 0,
+            carried: 0,
+            pending: VecDeque::new(),
+            split_end: None,
+        }
+    }
+}
+
+/// Iterator returned by [`LineChunker::chunk_iter`].
+pub struct LineIter<S> {
+    text: S,
+    config: ChunkConfig,
+    pos: usize,
+    current: Vec<(usize, usize, usize)>,
+    current_len: usize,
+    carried: usize, // leading lines repeated from the previous chunk
+    pending: VecDeque<Chunk>,
+    split_end: Option<usize>, // end of the oversized line being split at `pos`
+}
+
+impl<S: AsRef<str>> Iterator for LineIter<S> {
+    type Item = Chunk;
+
+    fn next(&mut self) -> Option<Chunk> {
+        let max_size = self.config.max_size;
+
+        while self.pending.is_empty() {
+            let text = self.text.as_ref();
+
+            // Oversized lines are cut into pieces one at a time
+            if let Some(line_end) = self.split_end {
+                match LineChunker::default().line_piece(text, self.pos, line_end, &self.config) {
+                    Some(piece) => {
+                        self.pos = piece.end;
+                        return Some(piece);
+                    }
+                    _ => {
+                        self.pos = line_end;
+                        self.split_end = None;
+                    }
+                }
+            }
+
+            let Some(line) = text[self.pos..].split_inclusive('\n').next() else {
+                // End of text: emit whatever is left once
+                if self.current.len() > self.carried {
+                    self.pending.push_back(LineChunker::default().make_chunk(
+                        text,
+                        &self.current,
+                        self.carried,
+                    ));
+                }
+                self.current.clear();
+                self.carried = 0;
+                break;
+            };
+            // Counting stops past max_size, so huge lines are not scanned twice
+            let chars = line.chars().take(max_size.saturating_add(1)).count();
+            let line = (self.pos, self.pos + line.len(), chars);
+
+            if line.2 > max_size {
+                // Oversized line: flush pending lines and split it on its own
+                if self.current.len() > self.carried {
+                    self.pending.push_back(LineChunker::default().make_chunk(
+                        text,
+                        &self.current,
+                        self.carried,
+                    ));
+                }
+                self.split_end = Some(line.1);
+                self.current.clear();
+                self.current_len = 0;
+                self.carried = 0;
+                continue;
+            }
+            self.pos = line.1;
+
+            let current = &mut self.current;
+            if self.current_len + line.2 > max_size && current.len() > self.carried {
+                self.pending.push_back(LineChunker::default().make_chunk(
+                    text,
+                    current,
+                    self.carried,
+                ));
+
+                // Carry trailing lines as overlap, keeping room for the new line
+                let mut keep = self.config.overlap_lines.min(current.len() - 1);
+                while keep > 0
+                    && current[current.len() - keep..]
+                        .iter()
+                        .map(|l| l.2)
+                        .sum::<usize>()
+                        + line.2
+                        > max_size
+                {
+                    keep -= 1;
+                }
+                current.drain(..current.len() - keep);
+                self.current_len = current.iter().map(|l| l.2).sum();
+                self.carried = keep;
+            }
+
+            self.current_len += line.2;
+            current.push(line);
+        }
+
+        self.pending.pop_front()
+    }
+}
+
+/// Options accepted by [`LineChunker::with_options`](ChunkAlgorithm::with_options).
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct LineOptions {
+    mode: Option<LineMode>,
+}
+
+impl ChunkAlgorithm for LineChunker {
+    fn chunk(&self, text: &str, config: &ChunkConfig) -> Vec<Chunk> {
+        match self.mode {
+            LineMode::Pack => Chunk::link_sequence(self.chunk_iter(text, config).collect()),
+            LineMode::Count => self.chunk_by_count(text, config),
+        }
+    }
+
+    fn try_chunk(&self, text: &str, config: &ChunkConfig) -> Result<Vec<Chunk>, ChunkError> {
+        if self.mode == LineMode::Count {
+            Self::check_count(config)?;
+        }
+        Ok(self.chunk(text, config))
+    }
+
+    fn chunk_finalized_iter(
+        &self,
+        text: String,
+        config: &ChunkConfig,
+    ) -> Result<Box<dyn Iterator<Item = Chunk> + Send + Sync>, ChunkError> {
+        if self.mode == LineMode::Count {
+            return Ok(Box::new(self.chunk_finalized(&text, config)?.into_iter()));
+        }
+        Ok(Box::new(Chunk::finalize_iter(
+            self.chunk_iter(text, config),
+            config.clone(),
+        )))
+    }
+
+    fn name(&self) -> &str {
+        "line"
+    }
+
+    fn with_options(&self, options: &Value) -> Result<Box<dyn ChunkAlgorithm>, ChunkError> {
+        let options: LineOptions = parse_options(self.name(), options)?;
+        Ok(Box::new(Self::new(options.mode.unwrap_or(self.mode))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_packing() {
+        let chunker = LineChunker::default();
+        let config = ChunkConfig::new(12);
+        let text = "line one\nline two\nline three";
+        let chunks = chunker.chunk(text, &config);
+
+        let texts: Vec<&str> = chunks.iter().map(|c| c.text.as_str()).collect();
+        assert_eq!(texts, vec!["line one\n", "line two\n", "line three"]);
+        assert_eq!(chunks[2].end, text.len());
+    }
+
+    #[test]
+    fn test_line_crlf() {
+        let chunker = LineChunker::default();
+        let config = ChunkConfig::new(21);
+        let text = "a,b,c\r\n1,2,3\r\n4,5,6\r\n7,8,9";
+        let chunks = chunker.chunk(text, &config);
+
+        let texts: Vec<&str> = chunks.iter().map(|c| c.text.as_str()).collect();
+        assert_eq!(texts, vec!["a,b,c\r\n1,2,3\r\n4,5,6\r\n", "7,8,9"]);
+        for chunk in &chunks {
+            assert_eq!(&text[chunk.start..chunk.end], chunk.text);
+        }
+    }
+
+    #[test]
+    fn test_line_overlap() {
+        let chunker = LineChunker::default();
+        let config = ChunkConfig::new(12).with_overlap_lines(1);
+        let text = "aaa\nbbb\nccc\nddd\neee\n";
+        let chunks = chunker.chunk(text, &config);
+
+        let texts: Vec<&str> = chunks.iter().map(|c| c.text.as_str()).collect();
+        assert_eq!(texts, vec!["aaa\nbbb\nccc\n", "ccc\nddd\neee\n"]);
+        assert_eq!(chunks[1].metadata.overlap_chars, Some(4));
+    }
+
+    #[test]
+    fn test_line_oversized() {
+        let chunker = LineChunker::default();
+        let config = ChunkConfig::new(5);
+        let text = "ok\nthis line is long\nend";
+        let chunks = chunker.chunk(text, &config);
+
+        assert_eq!(chunks[0].text, "ok\n");
+        assert_eq!(chunks[1].text, "this ");
+        assert_eq!(
+            chunks[1].metadata.extra.get("split_line"),
+            Some(&"true".to_string())
+        );
+        assert_eq!(chunks.last().unwrap().text, "end");
+        assert!(chunks.last().unwrap().metadata.extra.is_empty());
+    }
+
+    #[test]
+    fn test_line_document_extra() {
+        let config = ChunkConfig::new(5).with_extra([("source", "app.log"), ("split_line", "no")]);
+        let chunks = Chunk::finalize(
+            LineChunker::default().chunk("ok\nthis line is long", &config),
+            &config,
+        );
+
+        assert!(chunks
+            .iter()
+            .all(|c| c.metadata.extra.get("source") == Some(&"app.log".to_string())));
+        // Keys set by the algorithm win over document metadata
+        assert_eq!(chunks[0].metadata.extra["split_line"], "no");
+        assert_eq!(chunks[1].metadata.extra["split_line"], "true");
+    }
+
+    #[test]
+    fn test_line_by_count() {
+        let chunker = LineChunker::default();
+        let config = ChunkConfig::new(2).with_overlap(1);
+        let text = "one\r\ntwo\r\nthree\nfour";
+        let chunks = chunker.chunk_by_count(text, &config);
+
+        let texts: Vec<&str> = chunks.iter().map(|c| c.text.as_str()).collect();
+        assert_eq!(
+            texts,
+            vec!["one\r\ntwo\r\n", "two\r\nthree\n", "three\nfour"]
+        );
+        for chunk in &chunks {
+            assert_eq!(&text[chunk.start..chunk.end], chunk.text);
+        }
+        assert_eq!(chunks[0].metadata.overlap_chars, None);
+        assert_eq!(chunks[1].metadata.overlap_chars, Some(5));
+        assert_eq!(chunks[2].end, text.len());
+    }
+
+    #[test]
+    fn test_line_by_count_no_overlap() {
+        let chunker = LineChunker::default();
+        let config = ChunkConfig::new(3);
+        let chunks = chunker.chunk_by_count("a\nb\nc\nd\n", &config);
+
+        let texts: Vec<&str> = chunks.iter().map(|c| c.text.as_str()).collect();
+        assert_eq!(texts, vec!["a\nb\nc\n", "d\n"]);
+        assert!(chunker.chunk_by_count("", &config).is_empty());
+    }
+
+    #[test]
+    fn test_line_count_mode_options() {
+        use crate::registry::AlgorithmRegistry;
+
+        let registry = AlgorithmRegistry::new();
+        let config = ChunkConfig::new(2).with_overlap(1);
+        let chunks = registry
+            .chunk_with("line", "a\nb\nc\n", &config, r#"{"mode": "count"}"#)
+            .unwrap();
+        let texts: Vec<&str> = chunks.iter().map(|c| c.text.as_str()).collect();
+        assert_eq!(texts, vec!["a\nb\n", "b\nc\n"]);
+
+        // The overlap is reported in lines
+        let config = ChunkConfig::new(2).with_overlap(2);
+        let err = LineChunker::new(LineMode::Count)
+            .try_chunk("a\nb\nc\n", &config)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid configuration: overlap (2 lines) must be less than lines_per_chunk (2)"
+        );
+        assert!(registry
+            .chunk_with("line", "a", &config, r#"{"mode": "lines"}"#)
+            .is_err());
+    }
+
+    #[test]
+    fn test_line_empty() {
+        let chunker = LineChunker::default();
+        let config = ChunkConfig::new(10);
+
+        assert!(chunker.chunk("", &config).is_empty());
+    }
+
+    #[test]
+    fn test_line_iter_matches_chunk() {
+        let chunker = LineChunker::default();
+        let config = ChunkConfig::new(12).with_overlap_lines(1);
+        let text = "aaa\nbbb\nthis line is very long\nccc\nddd\neee";
+        let eager = chunker.chunk(text, &config);
+        let lazy: Vec<Chunk> = chunker.chunk_iter(text, &config).collect();
+
+        assert_eq!(eager.len(), lazy.len());
+        for (a, b) in eager.iter().zip(lazy.iter()) {
+            assert_eq!((&a.text, a.start, a.end), (&b.text, b.start, b.end));
+            assert_eq!(a.metadata.overlap_chars, b.metadata.overlap_chars);
+        }
+    }
+}
@@ -2,13 +2,145 @@
 //!
 //! Parses markdown structure and preserves:
 //! - Code blocks (fenced with ```) as atomic units
+//! - Math blocks (delimited by $$) as atomic units
+//! - Balanced raw HTML blocks as atomic units (opt-in)
+//! - Lists and pipe tables as atomic units
 //! - Headings for section boundaries
-//! - Lists and block quotes
+//!
+//! Leading YAML (`---`) or TOML (`+++`) front matter is left out of the chunks.
 
+use crate::algorithms::heading::check_levels;
+use crate::algorithms::{FixedSizeChunker, SentenceChunker};
 use crate::chunk::{Chunk, ChunkMetadata};
 use crate::config::ChunkConfig;
-use crate::traits::ChunkAlgorithm;
+use crate::error::ChunkError;
+use crate::traits::{parse_options, ChunkAlgorithm};
 use regex::Regex;
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+/// Opening or closing code fence (``` or ~~~) with an optional language.
+fn code_fence_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"^(`{3,}|~{3,})(\w*)\s*$").unwrap())
+}
+
+/// ATX heading line: `#` to `######` followed by the title.
+fn heading_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"^(#{1,6})\s+(.+)$").unwrap())
+}
+
+/// Opening tag of a raw HTML block at the start of a line.
+fn html_open_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"^<([A-Za-z][A-Za-z0-9-]*)[\s/>]").unwrap())
+}
+
+/// Any opening, closing or self-closing HTML tag, with its name.
+fn html_tag_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"<(/?)([A-Za-z][A-Za-z0-9-]*)[^>]*?(/?)>").unwrap())
+}
+
+/// Bullet (`-`, `*`, `+`) or ordered (`1.`, `1)`) list item.
+fn list_item_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"^\s*([-*+]|\d{1,9}[.)])\s+\S").unwrap())
+}
+
+/// GFM table delimiter row such as `|---|:---:|` or `--- | ---:`.
+fn table_delimiter_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"^ {0,3}\|?\s*:?-+:?\s*(\|\s*:?-+:?\s*)*\|?\s*$").unwrap())
+}
+
+/// Number of cells in a table row, ignoring outer pipes and escaped `\|`.
+fn table_cells(line: &str) -> usize {
+    let row = line.trim();
+    let row = row.strip_prefix('|').unwrap_or(row);
+    let row = match row.strip_suffix('|') {
+        Some(inner) if !inner.ends_with('\\') => inner,
+        _ => row,
+    };
+    row.replace("\\|", "").matches('|').count() + 1
+}
+
+/// Level of a setext heading underline: `===` for h1, `---` for h2.
+///
+/// The underline may be indented by up to three spaces and have trailing
+/// whitespace.
+pub(crate) fn setext_level(line: &str) -> Option<usize> {
+    let underline = line.trim_end();
+    let marks = underline.trim_start_matches(' ');
+    if underline.len() - marks.len() > 3 || marks.is_empty() {
+        return None;
+    }
+    if marks.bytes().all(|b| b == b'=') {
+        Some(1)
+    } else if marks.bytes().all(|b| b == b'-') {
+        Some(2)
+    } else {
+        None
+    }
+}
+
+/// Narrow `start..end` to the bytes of `text` left after trimming whitespace.
+///
+/// Chunks built from several blocks have their text trimmed, so their span
+/// runs from the first to the last retained byte rather than over the blank
+/// lines around them.
+pub(crate) fn trimmed_span(text: &str, start: usize, end: usize) -> (usize, usize) {
+    let span = &text[start..end];
+    let leading = span.len() - span.trim_start().len();
+    if leading == span.len() {
+        return (start, start);
+    }
+    (start + leading, start + span.trim_end().len())
+}
+
+/// Prefix each chunk under a heading with its innermost section title.
+///
+/// The prefix ("Title:\n") is stored in `metadata.extra["section_prefix"]`
+/// so it can be told apart from the source text; `start` and `end` are left
+/// as they are.
+pub(crate) fn prepend_sections(chunks: &mut [Chunk]) {
+    for chunk in chunks {
+        let Some(title) = chunk
+            .metadata
+            .section_path
+            .as_ref()
+            .and_then(|path| path.last())
+        else {
+            continue;
+        };
+        let prefix = format!("{}:\n", title);
+        chunk.text.insert_str(0, &prefix);
+        chunk
+            .metadata
+            .extra
+            .insert("section_prefix".to_string(), prefix);
+    }
+}
+
+/// Lines of `text` with their byte offsets, split like `str::lines`.
+///
+/// Yields `(start, line, next)`: the line without its `\n` or `\r\n`
+/// terminator and the offset where the following line begins, so offsets
+/// stay exact whatever the line ending.
+pub(crate) fn line_spans(text: &str) -> impl Iterator<Item = (usize, &str, usize)> {
+    let mut pos = 0;
+    text.split_inclusive('\n').map(move |raw| {
+        let start = pos;
+        pos += raw.len();
+        let line = raw
+            .strip_suffix('\n')
+            .map_or(raw, |line| line.strip_suffix('\r').unwrap_or(line));
+        (start, line, pos)
+    })
+}
 
 /// Represents a parsed markdown block.
 #[derive(Debug, Clone)]
@@ -21,6 +153,36 @@ enum MarkdownBlock {
         start: usize,
         end: usize,
     },
+    /// A math block delimited by $$ lines
+    MathBlock {
+        content: String,
+        start: usize,
+        end: usize,
+    },
+    /// A balanced raw HTML block (<div>...</div>)
+    HtmlBlock {
+        content: String,
+        start: usize,
+        end: usize,
+    },
+    /// A list, including nested items and continuation lines
+    List {
+        content: String,
+        start: usize,
+        end: usize,
+    },
+    /// A blockquote: consecutive lines starting with >
+    BlockQuote {
+        content: String,
+        start: usize,
+        end: usize,
+    },
+    /// A GFM pipe table (header row, delimiter row and body rows)
+    Table {
+        content: String,
+        start: usize,
+        end: usize,
+    },
     /// A heading (# ## ### etc.)
     Heading {
         content: String,
@@ -38,28 +200,217 @@ enum MarkdownBlock {
     },
 }
 
+impl MarkdownBlock {
+    /// Block type recorded in chunk metadata for atomic blocks.
+    fn kind(&self) -> Option<&'static str> {
+        match self {
+            MarkdownBlock::CodeBlock { .. } => Some("code"),
+            MarkdownBlock::MathBlock { .. } => Some("math"),
+            MarkdownBlock::HtmlBlock { .. } => Some("html"),
+            MarkdownBlock::List { .. } => Some("list"),
+            MarkdownBlock::BlockQuote { .. } => Some("blockquote"),
+            MarkdownBlock::Table { .. } => Some("table"),
+            MarkdownBlock::Heading { .. } | MarkdownBlock::Text { .. } => None,
+        }
+    }
+}
+
+/// A heading in a document outline, with the sections nested under it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OutlineEntry {
+    /// Heading level (1-6).
+    pub level: usize,
+    /// Heading text without the leading `#` markers.
+    pub title: String,
+    /// Section label in the form used by `ChunkMetadata::section` ("h2: Title").
+    pub section: String,
+    /// Start byte of the heading line.
+    pub start: usize,
+    /// End byte of the section: the next heading at the same or a higher level, or the end of the text.
+    pub end: usize,
+    /// Headings nested under this one.
+    pub children: Vec<OutlineEntry>,
+}
+
+/// Front matter fenced at the very start of a markdown document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FrontMatter<'t> {
+    /// "yaml" for `---` fences, "toml" for `+++` fences.
+    pub format: &'static str,
+    /// Text between the fences, without the final line ending.
+    pub content: &'t str,
+    /// Byte offset just past the closing fence line, where the body begins.
+    pub end: usize,
+}
+
 /// Markdown-aware chunker that preserves code blocks and splits at headings.
-pub struct MarkdownChunker;
+///
+/// Chunk text is rebuilt from the parsed blocks and trimmed; `start..end`
+/// spans the source from its first to its last retained byte.
+pub struct MarkdownChunker {
+    /// Heading levels that start a new chunk (default: all, 1 to 6). Other
+    /// headings stay inline in the current chunk and leave its section as is.
+    pub split_levels: Vec<usize>,
+}
+
+impl Default for MarkdownChunker {
+    fn default() -> Self {
+        Self::new((1..=6).collect())
+    }
+}
 
 impl MarkdownChunker {
+    /// Create a new MarkdownChunker that splits at the given heading levels.
+    pub fn new(split_levels: Vec<usize>) -> Self {
+        Self { split_levels }
+    }
+
+    /// Create a new MarkdownChunker, rejecting an empty level list or levels outside 1..=6.
+    pub fn try_new(split_levels: Vec<usize>) -> Result<Self, ChunkError> {
+        check_levels(&split_levels)?;
+        Ok(Self::new(split_levels))
+    }
+
+    /// Find YAML or TOML front matter at the start of `text`.
+    ///
+    /// The opening fence must be the first line. YAML front matter may also
+    /// close with `...`. Returns `None` when there is no closing fence.
+    pub fn front_matter<'t>(&self, text: &'t str) -> Option<FrontMatter<'t>> {
+        let mut lines = line_spans(text);
+        let (_, fence, content_start) = lines.next()?;
+        let format = match fence.trim_end() {
+            "---" => "yaml",
+            "+++" => "toml",
+            _ => return None,
+        };
+
+        lines
+            .find(|(_, line, _)| {
+                let line = line.trim_end();
+                line == fence.trim_end() || (format == "yaml" && line == "...")
+            })
+            .map(|(close_start, _, end)| {
+                let content = &text[content_start..close_start];
+                let content = content
+                    .strip_suffix('\n')
+                    .map_or(content, |c| c.strip_suffix('\r').unwrap_or(c));
+                FrontMatter {
+                    format,
+                    content,
+                    end,
+                }
+            })
+    }
+
+    /// Build the heading tree of `text` without chunking it.
+    ///
+    /// Headings are ATX-style (`#` to `######`) or setext-style (underlined
+    /// with `===` or `---`); lines inside code, math and other atomic blocks
+    /// are not headings. A heading nests under the
+    /// closest preceding heading with a lower level, so skipped levels
+    /// (h1 followed by h3) still nest.
+    pub fn outline(&self, text: &str) -> Vec<OutlineEntry> {
+        let mut roots = Vec::new();
+        let mut open: Vec<OutlineEntry> = Vec::new();
+
+        // Close the innermost open entry, attaching it to its parent
+        fn close(open: &mut Vec<OutlineEntry>, roots: &mut Vec<OutlineEntry>, end: usize) {
+            if let Some(mut entry) = open.pop() {
+                entry.end = end;
+                match open.last_mut() {
+                    Some(parent) => parent.children.push(entry),
+                    None => roots.push(entry),
+                }
+            }
+        }
+
+        for block in self.parse_blocks(text, &ChunkConfig::default()) {
+            if let MarkdownBlock::Heading {
+                content,
+                level,
+                start,
+                ..
+            } = block
+            {
+                while open.last().is_some_and(|entry| entry.level >= level) {
+                    close(&mut open, &mut roots, start);
+                }
+                open.push(OutlineEntry {
+                    level,
+                    section: format!("h{}: {}", level, content),
+                    title: content,
+                    start,
+                    end: text.len(),
+                    children: Vec::new(),
+                });
+            }
+        }
+        while !open.is_empty() {
+            close(&mut open, &mut roots, text.len());
+        }
+
+        roots
+    }
+
     /// Parse markdown text into blocks.
-    fn parse_blocks(text: &str) -> Vec<MarkdownBlock> {
+    fn parse_blocks(&self, text: &str, config: &ChunkConfig) -> Vec<MarkdownBlock> {
         let mut blocks = Vec::new();
-        let mut current_pos = 0;
         let mut in_code_block = false;
+        let mut in_math_block = false;
+        let mut math_block_start = 0;
+        // Front matter is skipped like an already consumed block
+        let mut skip_until = self.front_matter(text).map_or(0, |fm| fm.end);
         let mut code_block_start = 0;
         let mut code_block_lang: Option<String> = None;
         let mut pending_text_start: Option<usize> = None;
         let mut pending_text = String::new();
 
-        let code_fence_re = Regex::new(r"^(`{3,}|~{3,})(\w*)\s*$").unwrap();
-        let heading_re = Regex::new(r"^(#{1,6})\s+(.+)$").unwrap();
+        for (line_start, line, next_line_start) in line_spans(text) {
+            let line_end = line_start + line.len();
 
-        for line in text.lines() {
-            let line_start = current_pos;
-            let line_end = current_pos + line.len();
+            // Lines already consumed by a raw HTML block
+            if line_start < skip_until {
+                continue;
+            }
+
+            let html_block_end = if config.preserve_html_blocks && !in_code_block && !in_math_block
+            {
+                html_open_re()
+                    .captures(line)
+                    .and_then(|caps| Self::html_block_end(text, line_start, &caps[1]))
+            } else {
+                None
+            };
+
+            let container_end = if in_code_block || in_math_block {
+                None
+            } else if list_item_re().is_match(line) {
+                Some((Self::list_block_end(text, line_start), "list"))
+            } else if let Some(end) = Self::blockquote_block_end(text, line_start) {
+                Some((end, "blockquote"))
+            } else {
+                Self::table_block_end(text, line_start).map(|end| (end, "table"))
+            };
+
+            if let Some(html_end) = html_block_end {
+                // Raw HTML block - flush pending text first
+                if !pending_text.is_empty() {
+                    blocks.push(MarkdownBlock::Text {
+                        content: pending_text.clone(),
+                        start: pending_text_start.unwrap_or(line_start),
+                        end: line_start,
+                    });
+                    pending_text.clear();
+                    pending_text_start = None;
+                }
 
-            if let Some(caps) = code_fence_re.captures(line) {
+                blocks.push(MarkdownBlock::HtmlBlock {
+                    content: text[line_start..html_end].to_string(),
+                    start: line_start,
+                    end: html_end,
+                });
+                skip_until = html_end;
+            } else if let Some(caps) = code_fence_re().captures(line) {
                 if !in_code_block {
                     // Start of code block - flush pending text first
                     if !pending_text.is_empty() {
@@ -91,7 +442,72 @@ impl MarkdownChunker {
                 }
             } else if in_code_block {
                 // Inside code block, continue
-            } else if let Some(caps) = heading_re.captures(line) {
+            } else if in_math_block {
+                if line.trim_end().ends_with("$$") {
+                    // End of math block
+                    in_math_block = false;
+                    blocks.push(MarkdownBlock::MathBlock {
+                        content: text[math_block_start..line_end].to_string(),
+                        start: math_block_start,
+                        end: line_end,
+                    });
+                }
+            } else if config.treat_math_as_atomic && line.starts_with("$$") {
+                // Start of math block - flush pending text first
+                if !pending_text.is_empty() {
+                    blocks.push(MarkdownBlock::Text {
+                        content: pending_text.clone(),
+                        start: pending_text_start.unwrap_or(line_start),
+                        end: line_start,
+                    });
+                    pending_text.clear();
+                    pending_text_start = None;
+                }
+
+                let trimmed = line.trim_end();
+                if trimmed.len() > 2 && trimmed.ends_with("$$") {
+                    // Single-line math block ($$ x $$)
+                    blocks.push(MarkdownBlock::MathBlock {
+                        content: line.to_string(),
+                        start: line_start,
+                        end: line_end,
+                    });
+                } else {
+                    in_math_block = true;
+                    math_block_start = line_start;
+                }
+            } else if let Some((block_end, kind)) = container_end {
+                // List, blockquote or table - flush pending text first
+                if !pending_text.is_empty() {
+                    blocks.push(MarkdownBlock::Text {
+                        content: pending_text.clone(),
+                        start: pending_text_start.unwrap_or(line_start),
+                        end: line_start,
+                    });
+                    pending_text.clear();
+                    pending_text_start = None;
+                }
+
+                let content = text[line_start..block_end].to_string();
+                blocks.push(match kind {
+                    "list" => MarkdownBlock::List {
+                        content,
+                        start: line_start,
+                        end: block_end,
+                    },
+                    "blockquote" => MarkdownBlock::BlockQuote {
+                        content,
+                        start: line_start,
+                        end: block_end,
+                    },
+                    _ => MarkdownBlock::Table {
+                        content,
+                        start: line_start,
+                        end: block_end,
+                    },
+                });
+                skip_until = block_end;
+            } else if let Some(caps) = heading_re().captures(line) {
                 // Flush pending text
                 if !pending_text.is_empty() {
                     blocks.push(MarkdownBlock::Text {
@@ -111,8 +527,37 @@ impl MarkdownChunker {
                     start: line_start,
                     end: line_end,
                 });
+            } else if let Some((level, underline_end)) = Self::setext_heading(
+                text,
+                line,
+                next_line_start,
+                pending_text
+                    .rsplit('\n')
+                    .next()
+                    .is_none_or(|l| l.trim().is_empty()),
+            ) {
+                // Setext heading: a paragraph's first line underlined with === or ---
+                if !pending_text.is_empty() {
+                    blocks.push(MarkdownBlock::Text {
+                        content: pending_text.clone(),
+                        start: pending_text_start.unwrap_or(line_start),
+                        end: line_start,
+                    });
+                    pending_text.clear();
+                    pending_text_start = None;
+                }
+                blocks.push(MarkdownBlock::Heading {
+                    content: line.trim().to_string(),
+                    level,
+                    start: line_start,
+                    end: underline_end,
+                });
+                skip_until = underline_end;
             } else {
-                // Regular text
+                // Regular text; blank lines before a paragraph are not part of it
+                if pending_text.is_empty() && line.trim().is_empty() {
+                    continue;
+                }
                 if pending_text_start.is_none() {
                     pending_text_start = Some(line_start);
                 }
@@ -121,9 +566,6 @@ impl MarkdownChunker {
                 }
                 pending_text.push_str(line);
             }
-
-            // Move past line + newline character
-            current_pos = line_end + 1; // +1 for \n
         }
 
         // Handle unclosed code block
@@ -135,6 +577,12 @@ impl MarkdownChunker {
                 start: code_block_start,
                 end: text.len(),
             });
+        } else if in_math_block {
+            blocks.push(MarkdownBlock::MathBlock {
+                content: text[math_block_start..].to_string(),
+                start: math_block_start,
+                end: text.len(),
+            });
         } else if !pending_text.is_empty() {
             // Flush remaining text
             blocks.push(MarkdownBlock::Text {
@@ -146,6 +594,232 @@ impl MarkdownChunker {
 
         blocks
     }
+
+    /// Check whether `line`, followed by a line starting at byte `next_line_start`, is a setext heading.
+    ///
+    /// Only the first line of a paragraph can be one, so a `---` after a
+    /// blank line stays a thematic break. Returns the level and the end of
+    /// the underline line.
+    fn setext_heading(
+        text: &str,
+        line: &str,
+        next_line_start: usize,
+        starts_paragraph: bool,
+    ) -> Option<(usize, usize)> {
+        if !starts_paragraph || line.trim().is_empty() || setext_level(line).is_some() {
+            return None;
+        }
+        let underline = text.get(next_line_start..)?.lines().next()?;
+        setext_level(underline).map(|level| (level, next_line_start + underline.len()))
+    }
+
+    /// Find the end of the line where a raw HTML element opened at `start` is balanced.
+    ///
+    /// Returns `None` if the element is never closed, so it is treated as text.
+    fn html_block_end(text: &str, start: usize, tag: &str) -> Option<usize> {
+        let mut depth = 0usize;
+
+        for caps in html_tag_re().captures_iter(&text[start..]) {
+            if !caps[2].eq_ignore_ascii_case(tag) {
+                continue;
+            }
+            let is_close = !caps[1].is_empty();
+            let is_self_closing = !caps[3].is_empty();
+
+            if is_close {
+                depth = depth.saturating_sub(1);
+            } else if !is_self_closing {
+                depth += 1;
+            }
+
+            if depth == 0 {
+                let match_end = start + caps.get(0).map_or(0, |m| m.end());
+                let line_end = text[match_end..]
+                    .find('\n')
+                    .map_or(text.len(), |i| match_end + i);
+                return Some(if text[..line_end].ends_with('\r') {
+                    line_end - 1
+                } else {
+                    line_end
+                });
+            }
+        }
+
+        None
+    }
+
+    /// Find the end of a list that starts at `start`.
+    ///
+    /// Item lines and indented lines (nested items, continuations) extend the
+    /// list, even across blank lines. A non-indented line directly after an
+    /// item is a lazy continuation unless it opens another block. Anything
+    /// else after a blank line ends the list.
+    fn list_block_end(text: &str, start: usize) -> usize {
+        let mut end = start;
+        let mut after_blank = false;
+
+        for (offset, line, _) in line_spans(&text[start..]) {
+            let line_start = start + offset;
+
+            if line.trim().is_empty() {
+                after_blank = true;
+                continue;
+            }
+
+            let trimmed = line.trim_start();
+            let indented = line.len() != trimmed.len();
+            let opens_block = ["#", "```", "~~~", "$$", "|", "<", ">"]
+                .iter()
+                .any(|prefix| trimmed.starts_with(prefix));
+            if list_item_re().is_match(line) || indented || (!after_blank && !opens_block) {
+                end = line_start + line.len();
+                after_blank = false;
+            } else {
+                break;
+            }
+        }
+
+        end
+    }
+
+    /// Find the end of a blockquote starting at `start`.
+    ///
+    /// Returns `None` unless the line at `start` starts with `>` (after up to
+    /// three spaces). The quote runs over every following line that does too,
+    /// so nested quotes and quoted lists stay inside it.
+    fn blockquote_block_end(text: &str, start: usize) -> Option<usize> {
+        let mut end = None;
+
+        for (offset, line, _) in line_spans(&text[start..]) {
+            let quoted = line.trim_start_matches(' ');
+            if line.len() - quoted.len() > 3 || !quoted.starts_with('>') {
+                break;
+            }
+            end = Some(start + offset + line.len());
+        }
+
+        end
+    }
+
+    /// Find the end of a GFM pipe table starting at `start`.
+    ///
+    /// Returns `None` unless the line at `start` is a header row followed by
+    /// a delimiter row (`|---|---|`) with the same number of cells. Outer
+    /// pipes are optional. Body rows run until a blank line or a line
+    /// without a `|`.
+    fn table_block_end(text: &str, start: usize) -> Option<usize> {
+        let mut lines = line_spans(&text[start..]);
+        let (_, header, _) = lines.next()?;
+        let (offset, delimiter, _) = lines.next()?;
+        if !header.contains('|')
+            || !delimiter.contains('|')
+            || !table_delimiter_re().is_match(delimiter)
+            || table_cells(header) != table_cells(delimiter)
+        {
+            return None;
+        }
+
+        let mut end = start + offset + delimiter.len();
+        for (offset, line, _) in lines {
+            if line.trim().is_empty() || !line.contains('|') {
+                break;
+            }
+            end = start + offset + line.len();
+        }
+
+        Some(end)
+    }
+
+    /// Split an oversized text block into (start, end, text) pieces that fit max_size.
+    ///
+    /// Splits at sentence boundaries first, falling back to fixed-size pieces for
+    /// sentences that are still too long. Offsets are relative to `content`.
+    fn split_oversized_text(content: &str, config: &ChunkConfig) -> Vec<(usize, usize, String)> {
+        let mut pieces = Vec::new();
+        let sentence_config = config.clone().with_preserve_separators(false);
+
+        for sentence_chunk in SentenceChunker.chunk(content, &sentence_config) {
+            if config.measure(&sentence_chunk.text) <= config.size_limit() {
+                pieces.push((
+                    sentence_chunk.start,
+                    sentence_chunk.end,
+                    sentence_chunk.text,
+                ));
+                continue;
+            }
+
+            let base = sentence_chunk.start
+                + content[sentence_chunk.start..]
+                    .find(&sentence_chunk.text)
+                    .unwrap_or(0);
+            for piece in FixedSizeChunker.chunk(&sentence_chunk.text, config) {
+                pieces.push((base + piece.start, base + piece.end, piece.text));
+            }
+        }
+
+        pieces
+    }
+
+    /// Find runs of backticks in a line as (byte offset, run length) pairs.
+    fn backtick_runs(line: &str) -> Vec<(usize, usize)> {
+        let mut runs = Vec::new();
+        let mut run_start: Option<usize> = None;
+
+        for (idx, byte) in line.bytes().enumerate() {
+            if byte == b'`' {
+                run_start.get_or_insert(idx);
+            } else if let Some(run) = run_start.take() {
+                runs.push((run, idx - run));
+            }
+        }
+        if let Some(run) = run_start {
+            runs.push((run, line.len() - run));
+        }
+
+        runs
+    }
+
+    /// Return the backtick run length of an inline code span left open at the end of `text`.
+    fn unclosed_inline_code(text: &str) -> Option<usize> {
+        let mut in_fence = false;
+        let mut open: Option<usize> = None;
+
+        for line in text.lines() {
+            let trimmed = line.trim_start();
+            if open.is_none() && (trimmed.starts_with("```") || trimmed.starts_with("~~~")) {
+                in_fence = !in_fence;
+                continue;
+            }
+            if in_fence {
+                continue;
+            }
+
+            for (_, len) in Self::backtick_runs(line) {
+                match open {
+                    None => open = Some(len),
+                    Some(n) if n == len => open = None,
+                    _ => {}
+                }
+            }
+        }
+
+        open
+    }
+
+    /// Find the byte offset just past the backtick run that closes an open inline code span.
+    fn inline_code_close(text: &str, run_len: usize) -> Option<usize> {
+        Self::backtick_runs(text)
+            .into_iter()
+            .find(|&(_, len)| len == run_len)
+            .map(|(idx, len)| idx + len)
+    }
+}
+
+/// Options accepted by [`MarkdownChunker::with_options`](ChunkAlgorithm::with_options).
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct MarkdownOptions {
+    split_levels: Option<Vec<usize>>,
 }
 
 impl ChunkAlgorithm for MarkdownChunker {
@@ -154,15 +828,36 @@ impl ChunkAlgorithm for MarkdownChunker {
             return Vec::new();
         }
 
-        let blocks = Self::parse_blocks(text);
+        let blocks = self.parse_blocks(text, config);
         let mut chunks = Vec::new();
         let mut current_section: Option<String> = None;
+        let mut current_path: Option<Vec<String>> = None;
+        let mut heading_stack: Vec<(usize, String)> = Vec::new();
         let mut current_text = String::new();
+        let mut current_block_type: Option<&str> = None;
         let mut current_start = 0;
         let mut chunk_start_set = false;
 
         for block in blocks {
+            let kind = block.kind();
             match block {
+                MarkdownBlock::Heading {
+                    content,
+                    level,
+                    start,
+                    ..
+                } if !self.split_levels.contains(&level) => {
+                    // Heading outside the split levels - keep it inline
+                    if !chunk_start_set {
+                        current_start = start;
+                        chunk_start_set = true;
+                    }
+                    current_block_type = None;
+                    current_text.push_str(&"#".repeat(level));
+                    current_text.push(' ');
+                    current_text.push_str(&content);
+                    current_text.push('\n');
+                }
                 MarkdownBlock::Heading {
                     content,
                     level,
@@ -172,15 +867,16 @@ impl ChunkAlgorithm for MarkdownChunker {
                     // Flush current chunk before new section
                     if !current_text.is_empty() {
                         let metadata = ChunkMetadata {
-                            method: self.name().to_string(),
                             section: current_section.clone(),
-                            overlap_chars: None,
-                            parent_chunk_id: None,
+                            section_path: current_path.clone(),
+                            block_type: current_block_type.take().map(String::from),
+                            ..ChunkMetadata::for_method(self.name())
                         };
+                        let (span_start, span_end) = trimmed_span(text, current_start, start);
                         chunks.push(Chunk::with_uuid(
                             current_text.trim().to_string(),
-                            current_start,
-                            start,
+                            span_start,
+                            span_end,
                             metadata,
                         ));
                         current_text.clear();
@@ -189,12 +885,16 @@ impl ChunkAlgorithm for MarkdownChunker {
 
                     // Update current section
                     current_section = Some(format!("h{}: {}", level, content));
+                    heading_stack.retain(|(l, _)| *l < level);
+                    heading_stack.push((level, content.clone()));
+                    current_path = Some(heading_stack.iter().map(|(_, t)| t.clone()).collect());
 
                     // Add heading to next chunk
                     if !chunk_start_set {
                         current_start = start;
                         chunk_start_set = true;
                     }
+                    current_block_type = None;
                     current_text.push_str(&"#".repeat(level));
                     current_text.push(' ');
                     current_text.push_str(&content);
@@ -205,22 +905,43 @@ impl ChunkAlgorithm for MarkdownChunker {
                     start,
                     end,
                     ..
+                }
+                | MarkdownBlock::HtmlBlock {
+                    content,
+                    start,
+                    end,
+                }
+                | MarkdownBlock::List {
+                    content,
+                    start,
+                    end,
+                }
+                | MarkdownBlock::BlockQuote {
+                    content,
+                    start,
+                    end,
+                }
+                | MarkdownBlock::Table {
+                    content,
+                    start,
+                    end,
                 } => {
-                    // Code blocks are atomic - check if we need to flush first
-                    let potential_len = current_text.len() + content.len();
+                    // Code, HTML, list, quote and table blocks are atomic - check if we need to flush first
+                    let potential_len = config.measure(&current_text) + config.measure(&content);
 
-                    if potential_len > config.max_size && !current_text.is_empty() {
+                    if potential_len > config.size_limit() && !current_text.is_empty() {
                         // Flush current chunk
                         let metadata = ChunkMetadata {
-                            method: self.name().to_string(),
                             section: current_section.clone(),
-                            overlap_chars: None,
-                            parent_chunk_id: None,
+                            section_path: current_path.clone(),
+                            block_type: current_block_type.take().map(String::from),
+                            ..ChunkMetadata::for_method(self.name())
                         };
+                        let (span_start, span_end) = trimmed_span(text, current_start, start);
                         chunks.push(Chunk::with_uuid(
                             current_text.trim().to_string(),
-                            current_start,
-                            start,
+                            span_start,
+                            span_end,
                             metadata,
                         ));
                         current_text.clear();
@@ -232,66 +953,152 @@ impl ChunkAlgorithm for MarkdownChunker {
                         chunk_start_set = true;
                     }
 
-                    // If code block alone exceeds max_size, it becomes its own chunk
-                    if content.len() > config.max_size {
+                    // If the block alone exceeds max_size, it becomes its own chunk
+                    if config.measure(&content) > config.size_limit() {
                         if !current_text.is_empty() {
                             let metadata = ChunkMetadata {
-                                method: self.name().to_string(),
                                 section: current_section.clone(),
-                                overlap_chars: None,
-                                parent_chunk_id: None,
+                                section_path: current_path.clone(),
+                                block_type: current_block_type.take().map(String::from),
+                                ..ChunkMetadata::for_method(self.name())
                             };
+                            let (span_start, span_end) = trimmed_span(text, current_start, start);
                             chunks.push(Chunk::with_uuid(
                                 current_text.trim().to_string(),
-                                current_start,
-                                start,
+                                span_start,
+                                span_end,
                                 metadata,
                             ));
                             current_text.clear();
                         }
 
                         let metadata = ChunkMetadata {
-                            method: self.name().to_string(),
                             section: current_section.clone(),
-                            overlap_chars: None,
-                            parent_chunk_id: None,
+                            section_path: current_path.clone(),
+                            block_type: kind.map(String::from),
+                            ..ChunkMetadata::for_method(self.name())
                         };
                         chunks.push(Chunk::with_uuid(content, start, end, metadata));
                         chunk_start_set = false;
                     } else {
+                        current_block_type = if current_text.is_empty() { kind } else { None };
                         current_text.push_str(&content);
                         current_text.push('\n');
                     }
                 }
-                MarkdownBlock::Text {
+                MarkdownBlock::MathBlock {
                     content,
                     start,
+                    end,
+                } => {
+                    // Math blocks are atomic - flush first if they would not fit
+                    let potential_len = config.measure(&current_text) + config.measure(&content);
+
+                    if potential_len > config.size_limit() && !current_text.is_empty() {
+                        let metadata = ChunkMetadata {
+                            section: current_section.clone(),
+                            section_path: current_path.clone(),
+                            block_type: current_block_type.take().map(String::from),
+                            ..ChunkMetadata::for_method(self.name())
+                        };
+                        let (span_start, span_end) = trimmed_span(text, current_start, start);
+                        chunks.push(Chunk::with_uuid(
+                            current_text.trim().to_string(),
+                            span_start,
+                            span_end,
+                            metadata,
+                        ));
+                        current_text.clear();
+                        chunk_start_set = false;
+                    }
+
+                    if config.measure(&content) > config.size_limit() {
+                        // Oversized math block becomes its own chunk
+                        let metadata = ChunkMetadata {
+                            section: current_section.clone(),
+                            section_path: current_path.clone(),
+                            block_type: kind.map(String::from),
+                            extra: HashMap::from([("block_type".to_string(), "math".to_string())]),
+                            ..ChunkMetadata::for_method(self.name())
+                        };
+                        chunks.push(Chunk::with_uuid(content, start, end, metadata));
+                        continue;
+                    }
+
+                    if !chunk_start_set {
+                        current_start = start;
+                        chunk_start_set = true;
+                    }
+                    current_block_type = if current_text.is_empty() { kind } else { None };
+                    current_text.push_str(&content);
+                    current_text.push('\n');
+                }
+                MarkdownBlock::Text {
+                    mut content,
+                    mut start,
                     end: _,
                 } => {
-                    let potential_len = current_text.len() + content.len();
+                    let potential_len = config.measure(&current_text) + config.measure(&content);
+
+                    if potential_len > config.size_limit() && !current_text.is_empty() {
+                        // Carry the rest of a straddling inline code span into this chunk
+                        if !config.split_inside_inline_code {
+                            if let Some(close) = Self::unclosed_inline_code(&current_text)
+                                .and_then(|run| Self::inline_code_close(&content, run))
+                            {
+                                current_text.push_str(&content[..close]);
+                                content = content[close..].to_string();
+                                start += close;
+                            }
+                        }
 
-                    if potential_len > config.max_size && !current_text.is_empty() {
                         // Flush current chunk
                         let metadata = ChunkMetadata {
-                            method: self.name().to_string(),
                             section: current_section.clone(),
-                            overlap_chars: None,
-                            parent_chunk_id: None,
+                            section_path: current_path.clone(),
+                            block_type: current_block_type.take().map(String::from),
+                            ..ChunkMetadata::for_method(self.name())
                         };
+                        let (span_start, span_end) = trimmed_span(text, current_start, start);
                         chunks.push(Chunk::with_uuid(
                             current_text.trim().to_string(),
-                            current_start,
-                            start,
+                            span_start,
+                            span_end,
                             metadata,
                         ));
                         current_text.clear();
                         chunk_start_set = false;
                     }
 
+                    if content.trim().is_empty() {
+                        continue;
+                    }
+
+                    // Oversized text is sub-split rather than emitted as one huge chunk
+                    if config.measure(&content) > config.size_limit() {
+                        for (piece_start, piece_end, piece) in
+                            Self::split_oversized_text(&content, config)
+                        {
+                            let metadata = ChunkMetadata {
+                                section: current_section.clone(),
+                                section_path: current_path.clone(),
+                                ..ChunkMetadata::for_method(self.name())
+                            };
+                            chunks.push(Chunk::with_uuid(
+                                piece,
+                                start + piece_start,
+                                start + piece_end,
+                                metadata,
+                            ));
+                        }
+                        continue;
+                    }
+
                     if !chunk_start_set {
                         current_start = start;
                         chunk_start_set = true;
                     }
+                    current_block_type = None;
                     current_text.push_str(&content);
                     current_text.push('\n');
                 }
@@ -301,25 +1108,37 @@ impl ChunkAlgorithm for MarkdownChunker {
         // Flush remaining content
         if !current_text.is_empty() {
             let metadata = ChunkMetadata {
-                method: self.name().to_string(),
                 section: current_section,
-                overlap_chars: None,
-                parent_chunk_id: None,
+                section_path: current_path,
+                block_type: current_block_type.take().map(String::from),
+                ..ChunkMetadata::for_method(self.name())
             };
+            let (span_start, span_end) = trimmed_span(text, current_start, text.len());
             chunks.push(Chunk::with_uuid(
                 current_text.trim().to_string(),
-                current_start,
-                text.len(),
+                span_start,
+                span_end,
                 metadata,
             ));
         }
 
-        chunks
+        if config.prepend_section {
+            prepend_sections(&mut chunks);
+        }
+        Chunk::link_sequence(chunks)
     }
 
     fn name(&self) -> &str {
         "markdown"
     }
+
+    fn with_options(&self, options: &Value) -> Result<Box<dyn ChunkAlgorithm>, ChunkError> {
+        let options: MarkdownOptions = parse_options(self.name(), options)?;
+        let split_levels = options
+            .split_levels
+            .unwrap_or_else(|| self.split_levels.clone());
+        Ok(Box::new(Self::try_new(split_levels)?))
+    }
 }
 
 #[cfg(test)]
@@ -328,7 +1147,7 @@ mod tests {
 
     #[test]
     fn test_markdown_code_block_preserved() {
-        let chunker = MarkdownChunker;
+        let chunker = MarkdownChunker::default();
         let config = ChunkConfig::new(1000);
         let text = r#"# Introduction
 
@@ -350,7 +1169,7 @@ More text after code.
 
     #[test]
     fn test_markdown_split_at_heading() {
-        let chunker = MarkdownChunker;
+        let chunker = MarkdownChunker::default();
         let config = ChunkConfig::new(50);
         let text = r#"# First Section
 
@@ -373,7 +1192,7 @@ Content of second section.
 
     #[test]
     fn test_markdown_empty() {
-        let chunker = MarkdownChunker;
+        let chunker = MarkdownChunker::default();
         let config = ChunkConfig::new(100);
         let chunks = chunker.chunk("", &config);
 
@@ -382,7 +1201,7 @@ Content of second section.
 
     #[test]
     fn test_markdown_section_tracking() {
-        let chunker = MarkdownChunker;
+        let chunker = MarkdownChunker::default();
         let config = ChunkConfig::new(1000);
         let text = "## My Section\n\nSome content here.";
         let chunks = chunker.chunk(text, &config);
@@ -393,4 +1212,502 @@ Content of second section.
             Some("h2: My Section".to_string())
         );
     }
+
+    #[test]
+    fn test_markdown_prepend_section() {
+        let text =
+            "Preamble.\n\n## Installation\n\nFirst step here. Second step here. Third step here.";
+        let config = ChunkConfig::new(20).with_prepend_section(true);
+        let chunks = MarkdownChunker::default().chunk(text, &config);
+
+        assert!(chunks.len() >= 4);
+        assert_eq!(chunks[0].text, "Preamble.");
+        assert!(!chunks[0].metadata.extra.contains_key("section_prefix"));
+        for chunk in &chunks[1..] {
+            assert!(
+                chunk.text.starts_with("Installation:\n"),
+                "{:?}",
+                chunk.text
+            );
+            assert_eq!(chunk.metadata.extra["section_prefix"], "Installation:\n");
+            // Spans still point at the source without the prefix
+            assert_eq!(
+                &text[chunk.start..chunk.end],
+                &chunk.text["Installation:\n".len()..]
+            );
+        }
+        assert_eq!(chunks[2].text, "Installation:\nFirst step here.");
+        assert_eq!(chunks[3].text, "Installation:\nSecond step here.");
+    }
+
+    #[test]
+    fn test_markdown_section_path() {
+        let chunker = MarkdownChunker::default();
+        let config = ChunkConfig::new(1000);
+        let text = "# Guide\n\nIntro.\n\n## Setup\n\n### Linux\n\nSteps.\n\n## Usage\n\nRun.";
+        let chunks = chunker.chunk(text, &config);
+
+        let paths: Vec<Vec<String>> = chunks
+            .iter()
+            .map(|c| c.metadata.section_path.clone().unwrap())
+            .collect();
+        assert_eq!(
+            paths,
+            vec![
+                vec!["Guide".to_string()],
+                vec!["Guide".to_string(), "Setup".to_string()],
+                vec![
+                    "Guide".to_string(),
+                    "Setup".to_string(),
+                    "Linux".to_string()
+                ],
+                vec!["Guide".to_string(), "Usage".to_string()],
+            ]
+        );
+        assert_eq!(chunks[2].metadata.section, Some("h3: Linux".to_string()));
+    }
+
+    #[test]
+    fn test_markdown_spans_match_trimmed_text() {
+        let text = "\n# Intro\n\nHello there.\n\n\n## Usage\n\nRun it.\n\n```\nx\n```\n\nDone.\n\n";
+        let chunks = MarkdownChunker::default().chunk(text, &ChunkConfig::new(1000));
+        assert_eq!(chunks.len(), 2);
+
+        // Spans cover exactly the retained text, without surrounding blank lines
+        assert_eq!(
+            &text[chunks[0].start..chunks[0].end],
+            "# Intro\n\nHello there."
+        );
+        for chunk in &chunks {
+            let span = &text[chunk.start..chunk.end];
+            assert_eq!(span, span.trim());
+            assert_eq!(span.lines().next(), chunk.text.lines().next());
+            assert_eq!(span.lines().last(), chunk.text.lines().last());
+        }
+        assert_eq!(chunks[1].end, text.trim_end().len());
+    }
+
+    #[test]
+    fn test_markdown_inline_code_not_split() {
+        let chunker = MarkdownChunker::default();
+        let config = ChunkConfig::new(40);
+        let text = "Intro with `open\n```\ncode\n```\nclose` span and more text here.";
+        let chunks = chunker.chunk(text, &config);
+
+        assert_eq!(chunks.len(), 2);
+        assert!(chunks[0].text.ends_with("close`"));
+        assert_eq!(chunks[1].text, "span and more text here.");
+        assert_eq!(&text[chunks[1].start..chunks[1].end], chunks[1].text);
+    }
+
+    #[test]
+    fn test_markdown_split_inside_inline_code_opt_out() {
+        let chunker = MarkdownChunker::default();
+        let config = ChunkConfig::new(40).with_split_inside_inline_code(true);
+        let text = "Intro with `open\n```\ncode\n```\nclose` span and more text here.";
+        let chunks = chunker.chunk(text, &config);
+
+        assert_eq!(chunks.len(), 2);
+        assert!(!chunks[0].text.contains("close`"));
+        assert!(chunks[1].text.starts_with("close`"));
+    }
+
+    #[test]
+    fn test_unclosed_inline_code_detection() {
+        assert_eq!(MarkdownChunker::unclosed_inline_code("a `b` c"), None);
+        assert_eq!(MarkdownChunker::unclosed_inline_code("a ``b"), Some(2));
+        assert_eq!(
+            MarkdownChunker::unclosed_inline_code("```\nlet `x\n```\n"),
+            None
+        );
+        assert_eq!(MarkdownChunker::inline_code_close("x`` y ` z", 1), Some(7));
+    }
+
+    #[test]
+    fn test_markdown_oversized_text_split() {
+        let chunker = MarkdownChunker::default();
+        let config = ChunkConfig::new(1000);
+        let sentence = "This sentence is part of one very long paragraph. ";
+        let mut text = String::from("## Long Section\n");
+        while text.len() < 5000 {
+            text.push_str(sentence);
+        }
+        let chunks = chunker.chunk(&text, &config);
+
+        assert!(chunks.len() >= 5);
+        for chunk in &chunks {
+            assert!(chunk.text.len() <= 1000);
+            assert_eq!(chunk.metadata.section, Some("h2: Long Section".to_string()));
+        }
+        assert!(text[chunks[1].start..].starts_with(&chunks[1].text));
+    }
+
+    #[test]
+    fn test_markdown_oversized_sentence_falls_back_to_fixed() {
+        let chunker = MarkdownChunker::default();
+        let config = ChunkConfig::new(100);
+        let text = "word ".repeat(100);
+        let chunks = chunker.chunk(&text, &config);
+
+        assert!(chunks.len() >= 5);
+        for chunk in &chunks {
+            assert!(chunk.text.len() <= 100);
+            assert_eq!(&text[chunk.start..chunk.end], chunk.text);
+        }
+    }
+
+    #[test]
+    fn test_markdown_math_block_atomic() {
+        let chunker = MarkdownChunker::default();
+        let config = ChunkConfig::new(40);
+        let text = "Euler's identity:\n$$\ne^{i\\pi} + 1 = 0\n$$\nAnd a long math block:\n$$\n\\sum_{n=1}^{\\infty} \\frac{1}{n^2} = \\frac{\\pi^2}{6}\n$$";
+        let chunks = chunker.chunk(text, &config);
+
+        assert_eq!(chunks.len(), 3);
+        assert!(chunks[0].text.contains("$$\ne^{i\\pi} + 1 = 0\n$$"));
+        assert!(chunks[0].metadata.extra.is_empty());
+        assert_eq!(chunks[1].text, "And a long math block:");
+        assert!(chunks[2].text.starts_with("$$") && chunks[2].text.ends_with("$$"));
+        assert_eq!(
+            chunks[2].metadata.extra.get("block_type"),
+            Some(&"math".to_string())
+        );
+        assert_eq!(&text[chunks[2].start..chunks[2].end], chunks[2].text);
+    }
+
+    #[test]
+    fn test_markdown_math_block_type() {
+        let text = "$$\n\\sum_{n=1}^{\\infty} \\frac{1}{n^2} = \\frac{\\pi^2}{6}\n$$";
+        let config = ChunkConfig::new(30);
+        let chunks = MarkdownChunker::default().chunk(text, &config);
+
+        // Oversized math blocks carry both the field and the `extra` key
+        assert_eq!(chunks[0].metadata.block_type.as_deref(), Some("math"));
+        let extra = chunks[0].metadata.extra.get("block_type");
+        assert_eq!(extra.map(String::as_str), Some("math"));
+
+        // Math blocks that fit only set the field
+        let chunks = MarkdownChunker::default().chunk("$$\nx = 1\n$$", &config);
+        assert_eq!(chunks[0].metadata.block_type.as_deref(), Some("math"));
+        assert!(chunks[0].metadata.extra.is_empty());
+    }
+
+    #[test]
+    fn test_markdown_math_not_atomic() {
+        let chunker = MarkdownChunker::default();
+        let config = ChunkConfig::new(20).with_treat_math_as_atomic(false);
+        let text = "$$\n\\sum_{n=1}^{\\infty} \\frac{1}{n^2} = \\frac{\\pi^2}{6}\n$$";
+        let chunks = chunker.chunk(text, &config);
+
+        assert!(chunks.len() > 1);
+        assert!(chunks.iter().all(|c| c.metadata.extra.is_empty()));
+    }
+
+    #[test]
+    fn test_markdown_html_block_atomic() {
+        let chunker = MarkdownChunker::default();
+        let config = ChunkConfig::new(60).with_preserve_html_blocks(true);
+        let text = "Intro text.\n\n<table>\n  <tr><td>A</td><td>B</td></tr>\n  <tr><td>C</td><td>D</td></tr>\n</table>\n\nAfter the table.";
+        let chunks = chunker.chunk(text, &config);
+
+        let table = &text[text.find("<table>").unwrap()..text.find("</table>").unwrap() + 8];
+        let holding: Vec<_> = chunks.iter().filter(|c| c.text.contains("<tr>")).collect();
+        assert_eq!(holding.len(), 1);
+        assert!(holding[0].text.contains(table));
+        assert!(chunks.last().unwrap().text.contains("After the table."));
+    }
+
+    #[test]
+    fn test_markdown_html_block_nested_tags() {
+        let chunker = MarkdownChunker::default();
+        let config = ChunkConfig::new(1000).with_preserve_html_blocks(true);
+        // Nesting and case are matched per tag name; other tags are ignored
+        let text = "<DIV class=\"a\">\n<div><br/><details>x</details></div>\n<divider>\n</Div>\n# Heading\n\nBody.";
+        let blocks = chunker.parse_blocks(text, &config);
+
+        let end = text.find("</Div>").unwrap() + 6;
+        assert!(matches!(
+            blocks[0],
+            MarkdownBlock::HtmlBlock { start: 0, end: e, .. } if e == end
+        ));
+        assert!(matches!(blocks[1], MarkdownBlock::Heading { .. }));
+    }
+
+    #[test]
+    fn test_markdown_unbalanced_html_is_text() {
+        let chunker = MarkdownChunker::default();
+        let config = ChunkConfig::new(1000).with_preserve_html_blocks(true);
+        let text = "<div>\nnever closed\n\n# Heading\n\nBody.";
+        let chunks = chunker.chunk(text, &config);
+
+        assert_eq!(chunks.len(), 2);
+        assert!(chunks[0].text.starts_with("<div>"));
+        assert_eq!(chunks[1].metadata.section, Some("h1: Heading".to_string()));
+    }
+
+    #[test]
+    fn test_markdown_table_atomic() {
+        let chunker = MarkdownChunker::default();
+        let config = ChunkConfig::new(40);
+        let text =
+            "Results:\n\n| name | score |\n|------|-------|\n| alice | 10 |\n| bob | 7 |\n\nDone.";
+        let chunks = chunker.chunk(text, &config);
+
+        let table = &text[text.find("| name").unwrap()..text.find("\n\nDone").unwrap()];
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(chunks[1].text, table);
+        assert_eq!(chunks[1].metadata.block_type, Some("table".to_string()));
+        assert_eq!(&text[chunks[1].start..chunks[1].end], table);
+        assert_eq!(chunks[2].text, "Done.");
+        assert_eq!(chunks[2].metadata.block_type, None);
+    }
+
+    #[test]
+    fn test_markdown_gfm_table_in_prose() {
+        let chunker = MarkdownChunker::default();
+        let config = ChunkConfig::new(30);
+        let table = "name | role | level\n:--- | :---: | ---:\nalice | admin | 3\nbob | dev | 2\ncarol | ops | 1";
+        let text =
+            format!("Team members are listed below.\n{table}\nThat is everyone on the team.");
+        let chunks = chunker.chunk(&text, &config);
+
+        // The table is larger than max_size but stays whole in its own chunk
+        let tables: Vec<_> = chunks
+            .iter()
+            .filter(|c| c.metadata.block_type.as_deref() == Some("table"))
+            .collect();
+        assert_eq!(tables.len(), 1);
+        assert_eq!(tables[0].text, table);
+        assert_eq!(&text[tables[0].start..tables[0].end], table);
+        assert_eq!(
+            chunks.first().unwrap().text,
+            "Team members are listed below."
+        );
+        assert_eq!(chunks.last().unwrap().text, "That is everyone on the team.");
+    }
+
+    #[test]
+    fn test_markdown_pipes_without_delimiter_row() {
+        let chunker = MarkdownChunker::default();
+        let config = ChunkConfig::new(1000);
+        let text = "| not | a table |\n| just | pipes |\n\na | b\n--- | --- | ---\n1 | 2";
+        let chunks = chunker.chunk(text, &config);
+
+        // No delimiter row, and a delimiter row with the wrong cell count
+        assert!(chunks.iter().all(|c| c.metadata.block_type.is_none()));
+    }
+
+    #[test]
+    fn test_markdown_blockquote_atomic() {
+        let chunker = MarkdownChunker::default();
+        let config = ChunkConfig::new(40);
+        let quote =
+            "> First line of the quote.\n> Second line.\n>\n> > Nested reply.\n> - quoted item";
+        let text = format!("Before the quote.\n\n{quote}\n\nAfter the quote.");
+        let chunks = chunker.chunk(&text, &config);
+
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(chunks[1].text, quote);
+        assert_eq!(
+            chunks[1].metadata.block_type,
+            Some("blockquote".to_string())
+        );
+        assert_eq!(&text[chunks[1].start..chunks[1].end], quote);
+        assert_eq!(chunks[2].text, "After the quote.");
+    }
+
+    #[test]
+    fn test_markdown_split_levels() {
+        let config = ChunkConfig::new(1000);
+        let text = "# Guide\n\nIntro.\n\n## Install\n\nSteps.\n\n### Linux\n\napt.\n\n### macOS\n\nbrew.\n\n## Usage\n\nRun.";
+
+        assert_eq!(MarkdownChunker::default().chunk(text, &config).len(), 5);
+
+        let chunks = MarkdownChunker::new(vec![1, 2]).chunk(text, &config);
+        assert_eq!(chunks.len(), 3);
+        // The h3s stay inside their h2 chunk, which keeps the h2 section
+        assert_eq!(
+            chunks[1].text,
+            "## Install\nSteps.\n\n### Linux\napt.\n\n### macOS\nbrew."
+        );
+        assert_eq!(chunks[1].metadata.section, Some("h2: Install".to_string()));
+        assert_eq!(
+            chunks[1].metadata.section_path,
+            Some(vec!["Guide".to_string(), "Install".to_string()])
+        );
+        assert_eq!(chunks[2].metadata.section, Some("h2: Usage".to_string()));
+
+        assert!(MarkdownChunker::try_new(vec![]).is_err());
+        assert!(MarkdownChunker::try_new(vec![7]).is_err());
+    }
+
+    #[test]
+    fn test_markdown_nested_list_atomic() {
+        let chunker = MarkdownChunker::default();
+        let config = ChunkConfig::new(30);
+        let text = "Steps:\n- install\n  - download\n  - unpack\n- run it\n  lazily\n1. numbered\n\nAfter.";
+        let chunks = chunker.chunk(text, &config);
+
+        let list = chunks
+            .iter()
+            .find(|c| c.metadata.block_type.as_deref() == Some("list"))
+            .unwrap();
+        assert!(list.text.starts_with("- install"));
+        assert!(list.text.contains("  - unpack"));
+        assert!(list.text.ends_with("1. numbered"));
+        assert_eq!(chunks.last().unwrap().text, "After.");
+    }
+
+    #[test]
+    fn test_markdown_list_blank_line() {
+        let chunker = MarkdownChunker::default();
+        let config = ChunkConfig::new(1000);
+
+        // A blank line between items keeps one (loose) list
+        let loose = "- one\n\n- two\n\nParagraph after.";
+        let chunks = chunker.chunk(loose, &ChunkConfig::new(20));
+        assert_eq!(chunks[0].text, "- one\n\n- two");
+        assert_eq!(chunks[0].metadata.block_type, Some("list".to_string()));
+        assert_eq!(chunks[1].text, "Paragraph after.");
+
+        // A paragraph after a blank line ends the list
+        let text = "- one\n- two\n\nNot in the list.";
+        let chunks = chunker.chunk(text, &config);
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].metadata.block_type, None);
+        let blocks = MarkdownChunker::default().parse_blocks(text, &config);
+        assert!(
+            matches!(&blocks[0], MarkdownBlock::List { content, .. } if content == "- one\n- two")
+        );
+        assert!(matches!(&blocks[1], MarkdownBlock::Text { .. }));
+    }
+
+    #[test]
+    fn test_markdown_repeated_calls_stable() {
+        let chunker = MarkdownChunker::default();
+        let config = ChunkConfig::new(40);
+        let text = "# Title\n\nFirst sentence. Second one!\n\n```rust\nfn main() {}\n```\n\n- a\n- b\n\n## Next\n\nDone?";
+        let spans = |chunks: Vec<Chunk>| {
+            chunks
+                .into_iter()
+                .map(|c| (c.text, c.start, c.end, c.metadata.section))
+                .collect::<Vec<_>>()
+        };
+        let expected = spans(chunker.chunk(text, &config));
+
+        for _ in 0..10_000 {
+            assert_eq!(spans(chunker.chunk(text, &config)), expected);
+        }
+    }
+
+    #[test]
+    fn test_markdown_outline() {
+        let text = "Intro.\n\n# Guide\n\n### Deep\n\nText.\n\n## Setup\n\n```\n# not a heading\n```\n\n# Guide\n\nAgain.";
+        let outline = MarkdownChunker::default().outline(text);
+
+        // Duplicate titles stay separate entries
+        assert_eq!(outline.len(), 2);
+        assert_eq!(outline[0].title, "Guide");
+        assert_eq!(outline[1].title, "Guide");
+        assert_eq!(outline[0].end, outline[1].start);
+        assert_eq!(outline[1].end, text.len());
+
+        // h3 directly under h1 still nests, and ends at the following h2
+        let children = &outline[0].children;
+        assert_eq!(children.len(), 2);
+        assert_eq!((children[0].level, children[0].title.as_str()), (3, "Deep"));
+        assert_eq!(children[0].end, children[1].start);
+        assert_eq!(children[1].section, "h2: Setup");
+        assert!(children[1].children.is_empty());
+        assert!(text[children[1].start..children[1].end].contains("# not a heading"));
+
+        // Sections join back to chunk metadata by label and offset
+        let chunks = MarkdownChunker::default().chunk(text, &ChunkConfig::new(1000));
+        let setup = chunks
+            .iter()
+            .find(|c| c.metadata.section.as_deref() == Some("h2: Setup"))
+            .unwrap();
+        assert_eq!(setup.start, children[1].start);
+
+        assert!(MarkdownChunker::default()
+            .outline("No headings here.")
+            .is_empty());
+    }
+
+    #[test]
+    fn test_markdown_setext_headings() {
+        let text = "Title\n=====\n\nIntro text.\n\nSub\n---\nBody.\n\n---\n\nAfter break.";
+        let chunks = MarkdownChunker::default().chunk(text, &ChunkConfig::new(1000));
+
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[0].metadata.section, Some("h1: Title".to_string()));
+        assert_eq!(chunks[0].text, "# Title\nIntro text.");
+        assert_eq!(chunks[1].metadata.section, Some("h2: Sub".to_string()));
+        assert_eq!(chunks[1].start, text.find("Sub").unwrap());
+        // The underline is not body text; the thematic break after a blank line is
+        assert!(!chunks[1].text.contains("Sub\n---"));
+        assert!(chunks[1].text.contains("---\n\nAfter break."));
+
+        let outline = MarkdownChunker::default().outline(text);
+        assert_eq!(outline[0].children[0].section, "h2: Sub");
+    }
+
+    #[test]
+    fn test_markdown_setext_requires_paragraph_start() {
+        let text = "First line\nsecond line\n---\n\nMore.";
+        let chunks = MarkdownChunker::default().chunk(text, &ChunkConfig::new(1000));
+
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].metadata.section, None);
+        assert!(MarkdownChunker::default().outline(text).is_empty());
+    }
+
+    #[test]
+    fn test_markdown_crlf_offsets() {
+        let lf = "# Title\n\nIntro text.\n\n```rust\nfn x() {}\n```\n\nSetext\n------\n\n- a\n- b\n\n| a | b |\n| - | - |\n\n<div>\nraw\n</div>\n\n## Next\n\nBody.";
+        let crlf = lf.replace('\n', "\r\n");
+        let config = ChunkConfig::new(20).with_preserve_html_blocks(true);
+        let lf_chunks = MarkdownChunker::default().chunk(lf, &config);
+        let crlf_chunks = MarkdownChunker::default().chunk(&crlf, &config);
+
+        // Each LF offset moves right by one byte per preceding line break
+        let to_crlf = |pos: usize| pos + lf[..pos].matches('\n').count();
+        assert_eq!(lf_chunks.len(), crlf_chunks.len());
+        for (a, b) in lf_chunks.iter().zip(&crlf_chunks) {
+            assert_eq!((to_crlf(a.start), to_crlf(a.end)), (b.start, b.end));
+        }
+        assert!(crlf[crlf_chunks.last().unwrap().start..].starts_with("## Next"));
+    }
+
+    #[test]
+    fn test_markdown_front_matter() {
+        let text = "---\ntitle: Guide\ntags: [a, b]\n---\n# Heading\n\nBody text.";
+        let front_matter = MarkdownChunker::default().front_matter(text).unwrap();
+        assert_eq!(front_matter.format, "yaml");
+        assert_eq!(front_matter.content, "title: Guide\ntags: [a, b]");
+        assert_eq!(&text[front_matter.end..], "# Heading\n\nBody text.");
+
+        // Not mistaken for a setext heading or body text
+        let chunks = MarkdownChunker::default().chunk(text, &ChunkConfig::new(1000));
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].text, "# Heading\nBody text.");
+        assert_eq!(chunks[0].start, front_matter.end);
+        assert_eq!(chunks[0].metadata.section, Some("h1: Heading".to_string()));
+        assert_eq!(MarkdownChunker::default().outline(text).len(), 1);
+
+        let toml = "+++\r\ntitle = \"Guide\"\r\n+++\r\nBody.";
+        let front_matter = MarkdownChunker::default().front_matter(toml).unwrap();
+        assert_eq!(
+            (front_matter.format, front_matter.content),
+            ("toml", "title = \"Guide\"")
+        );
+
+        // Unclosed or not at the very start: no front matter
+        assert!(MarkdownChunker::default()
+            .front_matter("---\ntitle: x\n")
+            .is_none());
+        assert!(MarkdownChunker::default()
+            .front_matter("Intro\n---\nx\n---")
+            .is_none());
+    }
 }
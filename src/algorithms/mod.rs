@@ -1,17 +1,41 @@
 //! Chunking algorithms module.
 
+mod balance;
+mod delimiter;
 mod fixed_size;
 mod heading;
+mod html;
+mod json;
+mod latex;
+mod line;
 mod markdown;
 mod paragraph;
+mod paragraph_sentence;
 mod recursive;
+mod regex;
 mod sentence;
 mod sliding_window;
+mod word;
+#[cfg(feature = "xml")]
+mod xml;
 
-pub use fixed_size::FixedSizeChunker;
+pub use delimiter::DelimiterChunker;
+pub use fixed_size::{FixedSizeChunker, FixedSizeIter};
 pub use heading::HeadingChunker;
-pub use markdown::MarkdownChunker;
+pub use html::HtmlChunker;
+pub use json::JsonChunker;
+pub use latex::LatexChunker;
+pub use line::{LineChunker, LineIter, LineMode};
+pub use markdown::{FrontMatter, MarkdownChunker, OutlineEntry};
 pub use paragraph::ParagraphChunker;
+pub use paragraph_sentence::ParagraphSentenceChunker;
 pub use recursive::{RecursiveChunker, RecursiveStrategy};
-pub use sentence::SentenceChunker;
-pub use sliding_window::SlidingWindowChunker;
+pub use regex::{KeepDelimiter, RegexChunker};
+pub use sentence::{SentenceChunker, SentenceIter};
+pub use sliding_window::{SlidingWindowChunker, SlidingWindowIter};
+pub use word::WordChunker;
+#[cfg(feature = "xml")]
+pub use xml::XmlChunker;
+
+pub(crate) use balance::balanced_partition;
+pub(crate) use regex::compile_pattern;
@@ -1,14 +1,67 @@
 //! Paragraph-based chunking algorithm.
+//!
+//! Packs whole paragraphs up to max_size. A single paragraph larger than
+//! max_size is split at sentence boundaries, and a sentence that is still too
+//! large is split by size, unless `config.strict_paragraphs` is set.
 
+use crate::algorithms::markdown::line_spans;
+use crate::algorithms::{balanced_partition, FixedSizeChunker, SentenceChunker};
 use crate::chunk::{Chunk, ChunkMetadata};
-use crate::config::ChunkConfig;
+use crate::config::{ChunkConfig, DEFAULT_PARAGRAPH_SEPARATOR};
 use crate::traits::ChunkAlgorithm;
+use std::collections::VecDeque;
 
-/// Paragraph-based chunker that splits on double newlines.
+/// Paragraph-based chunker that splits on blank lines, or on
+/// `config.paragraph_separator` when it is set to something else.
 pub struct ParagraphChunker;
 
-impl ChunkAlgorithm for ParagraphChunker {
-    fn chunk(&self, text: &str, config: &ChunkConfig) -> Vec<Chunk> {
+/// Find trimmed, non-empty paragraph spans separated by `separator`.
+///
+/// The default separator matches any blank line: one that is empty or
+/// whitespace-only and may end in `\n` or `\r\n`. Any other separator is
+/// matched literally.
+pub(crate) fn paragraph_spans(text: &str, separator: &str) -> Vec<(usize, usize)> {
+    let mut spans = Vec::new();
+    let mut push = |(start, end): (usize, usize)| {
+        let part = &text[start..end];
+        if !part.trim().is_empty() {
+            let start = start + part.len() - part.trim_start().len();
+            spans.push((start, start + part.trim().len()));
+        }
+    };
+
+    if separator != DEFAULT_PARAGRAPH_SEPARATOR {
+        let mut start = 0;
+        for (idx, sep) in text.match_indices(separator) {
+            push((start, idx));
+            start = idx + sep.len();
+        }
+        push((start, text.len()));
+        return spans;
+    }
+
+    let mut current: Option<(usize, usize)> = None;
+
+    for (line_start, line, _) in line_spans(text) {
+        if line.trim().is_empty() {
+            if let Some(span) = current.take() {
+                push(span);
+            }
+        } else {
+            let first = current.map_or(line_start, |(start, _)| start);
+            current = Some((first, line_start + line.len()));
+        }
+    }
+    if let Some(span) = current {
+        push(span);
+    }
+
+    spans
+}
+
+impl ParagraphChunker {
+    /// Pack whole paragraphs up to max_size, never splitting a paragraph.
+    pub(crate) fn chunk_strict(&self, text: &str, config: &ChunkConfig) -> Vec<Chunk> {
         if text.is_empty() {
             return Vec::new();
         }
@@ -16,38 +69,52 @@ impl ChunkAlgorithm for ParagraphChunker {
         let mut chunks = Vec::new();
         let mut current_text = String::new();
         let mut current_start = 0;
-        let mut byte_offset = 0;
         let mut chunk_start_set = false;
+        let mut current_end = 0;
+        let mut current_count = 0;
+        let mut last_para_end = 0;
+        let separator = config.paragraph_separator.as_str();
 
-        // Split on double newlines (paragraph boundaries)
-        for part in text.split("\n\n") {
-            let trimmed = part.trim();
-            if trimmed.is_empty() {
-                byte_offset += part.len() + 2; // +2 for the \n\n
-                continue;
-            }
+        // Paragraphs separated by too many blank lines are never merged
+        let mut paragraphs = Vec::new();
+        for (para_start, para_end) in paragraph_spans(text, separator) {
+            let blank_lines = text[last_para_end..para_start]
+                .matches('\n')
+                .count()
+                .saturating_sub(1);
+            let gap_too_large = config
+                .max_merge_gap
+                .is_some_and(|max_gap| blank_lines > max_gap);
+            last_para_end = para_end;
+            paragraphs.push((para_start, para_end, gap_too_large));
+        }
+        let mut plan = config
+            .balanced
+            .then(|| Self::balanced_plan(text, &paragraphs, config));
 
-            let para_start = byte_offset + part.find(trimmed).unwrap_or(0);
+        for (para_start, para_end, gap_too_large) in paragraphs {
+            let trimmed = &text[para_start..para_end];
 
             // Check if adding this paragraph would exceed max_size
-            let potential_len = if current_text.is_empty() {
-                trimmed.len()
-            } else {
-                current_text.len() + 2 + trimmed.len() // +2 for paragraph separator
+            let full = match &plan {
+                Some(plan) => plan.front().is_some_and(|&len| current_count >= len),
+                None => {
+                    let potential_len = if current_text.is_empty() {
+                        config.measure(trimmed)
+                    } else {
+                        config.joined_size(&current_text, separator, trimmed)
+                    };
+                    potential_len > config.size_limit()
+                }
             };
 
-            if potential_len > config.max_size && !current_text.is_empty() {
+            if (full || gap_too_large) && !current_text.is_empty() {
                 // Flush current chunk
-                let metadata = ChunkMetadata {
-                    method: self.name().to_string(),
-                    section: None,
-                    overlap_chars: None,
-                    parent_chunk_id: None,
-                };
+                let metadata = ChunkMetadata::for_method(self.name());
                 chunks.push(Chunk::with_uuid(
                     current_text.clone(),
                     current_start,
-                    current_start + current_text.len(),
+                    current_end,
                     metadata,
                 ));
 
@@ -55,6 +122,10 @@ impl ChunkAlgorithm for ParagraphChunker {
                 current_text = trimmed.to_string();
                 current_start = para_start;
                 chunk_start_set = true;
+                current_count = 0;
+                if let Some(plan) = &mut plan {
+                    plan.pop_front();
+                }
             } else {
                 if !chunk_start_set {
                     current_start = para_start;
@@ -63,26 +134,21 @@ impl ChunkAlgorithm for ParagraphChunker {
                 if current_text.is_empty() {
                     current_text = trimmed.to_string();
                 } else {
-                    current_text.push_str("\n\n");
+                    current_text.push_str(separator);
                     current_text.push_str(trimmed);
                 }
             }
-
-            byte_offset += part.len() + 2; // +2 for the \n\n separator
+            current_end = para_end;
+            current_count += 1;
         }
 
         // Flush remaining text
         if !current_text.is_empty() {
-            let metadata = ChunkMetadata {
-                method: self.name().to_string(),
-                section: None,
-                overlap_chars: None,
-                parent_chunk_id: None,
-            };
+            let metadata = ChunkMetadata::for_method(self.name());
             chunks.push(Chunk::with_uuid(
                 current_text.clone(),
                 current_start,
-                current_start + current_text.len(),
+                current_end,
                 metadata,
             ));
         }
@@ -90,6 +156,103 @@ impl ChunkAlgorithm for ParagraphChunker {
         chunks
     }
 
+    /// Plan how many paragraphs go in each chunk so chunk sizes are balanced.
+    ///
+    /// Each run of paragraphs between gaps wider than `max_merge_gap` is
+    /// balanced on its own, since chunks never span such a gap.
+    fn balanced_plan(
+        text: &str,
+        paragraphs: &[(usize, usize, bool)],
+        config: &ChunkConfig,
+    ) -> VecDeque<usize> {
+        let separator = config.measure(&config.paragraph_separator);
+        let mut plan = VecDeque::new();
+        let mut sizes = Vec::new();
+        for &(start, end, gap_too_large) in paragraphs {
+            if gap_too_large && !sizes.is_empty() {
+                plan.extend(balanced_partition(&sizes, separator, config.size_limit()));
+                sizes.clear();
+            }
+            sizes.push(config.measure(&text[start..end]));
+        }
+        plan.extend(balanced_partition(&sizes, separator, config.size_limit()));
+        plan
+    }
+
+    /// Split a chunk holding one oversized paragraph into sentence-packed pieces.
+    ///
+    /// Sentences longer than max_size are split by size. The pieces keep
+    /// document offsets and share a synthetic `parent_chunk_id`.
+    fn split_oversized(&self, paragraph: Chunk, config: &ChunkConfig) -> Vec<Chunk> {
+        let parent_id = uuid::Uuid::new_v4().to_string();
+        let mut pieces = Vec::new();
+
+        for sentence in SentenceChunker.chunk(&paragraph.text, config) {
+            let base = paragraph.start + sentence.start;
+            let parts = if config.measure(&sentence.text) > config.size_limit() {
+                FixedSizeChunker
+                    .chunk(&paragraph.text[sentence.start..sentence.end], config)
+                    .into_iter()
+                    .map(|part| (part.text, part.start, part.end))
+                    .collect()
+            } else {
+                vec![(sentence.text, 0, sentence.end - sentence.start)]
+            };
+
+            for (text, start, end) in parts {
+                let metadata = ChunkMetadata {
+                    parent_chunk_id: Some(parent_id.clone()),
+                    ..ChunkMetadata::for_method(self.name())
+                };
+                pieces.push(Chunk::with_uuid(text, base + start, base + end, metadata));
+            }
+        }
+
+        pieces
+    }
+}
+
+/// Widen each chunk over the text up to the next one and use the source slice as its text.
+///
+/// The first chunk also takes any text before it and the last one any
+/// text after it, so the chunk texts concatenate to `text`.
+fn preserve_separators(text: &str, mut chunks: Vec<Chunk>) -> Vec<Chunk> {
+    let starts: Vec<usize> = chunks.iter().skip(1).map(|c| c.start).collect();
+    let mut start = 0;
+    for (chunk, end) in chunks
+        .iter_mut()
+        .zip(starts.into_iter().chain([text.len()]))
+    {
+        chunk.start = start;
+        chunk.end = end;
+        chunk.text = text[start..end].to_string();
+        start = end;
+    }
+    chunks
+}
+
+impl ChunkAlgorithm for ParagraphChunker {
+    fn chunk(&self, text: &str, config: &ChunkConfig) -> Vec<Chunk> {
+        let mut chunks = self.chunk_strict(text, config);
+        if !config.strict_paragraphs {
+            // Only a chunk holding a single paragraph can exceed max_size
+            chunks = chunks
+                .into_iter()
+                .flat_map(|chunk| {
+                    if config.measure(&chunk.text) > config.size_limit() {
+                        self.split_oversized(chunk, config)
+                    } else {
+                        vec![chunk]
+                    }
+                })
+                .collect();
+        }
+        if config.preserve_separators {
+            chunks = preserve_separators(text, chunks);
+        }
+        Chunk::link_sequence(chunks)
+    }
+
     fn name(&self) -> &str {
         "paragraph"
     }
@@ -151,4 +314,208 @@ mod tests {
 
         assert!(chunks.is_empty());
     }
+
+    #[test]
+    fn test_paragraph_max_merge_gap() {
+        let chunker = ParagraphChunker;
+        let text = "Topic A.\n\nStill A.\n\n\n\n\nTopic B.";
+
+        let merged = chunker.chunk(text, &ChunkConfig::new(1000));
+        assert_eq!(merged.len(), 1);
+
+        let config = ChunkConfig::new(1000).with_max_merge_gap(2);
+        let chunks = chunker.chunk(text, &config);
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[0].text, "Topic A.\n\nStill A.");
+        assert_eq!(chunks[1].text, "Topic B.");
+        assert_eq!(chunks[1].start, text.find("Topic B").unwrap());
+
+        // A gap equal to the limit still merges
+        let config = ChunkConfig::new(1000).with_max_merge_gap(4);
+        assert_eq!(chunker.chunk(text, &config).len(), 1);
+    }
+
+    #[test]
+    fn test_paragraph_splits_oversized() {
+        let chunker = ParagraphChunker;
+        let config = ChunkConfig::new(30);
+        let text = "Intro.\n\nFirst long sentence here. Second long sentence. Supercalifragilisticexpialidocious-and-then-some.";
+        let chunks = chunker.chunk(text, &config);
+
+        let texts: Vec<&str> = chunks.iter().map(|c| c.text.as_str()).collect();
+        assert_eq!(
+            texts,
+            vec![
+                "Intro.",
+                "First long sentence here.",
+                "Second long sentence.",
+                "Supercalifragilisticexpialidoc",
+                "ious-and-then-some.",
+            ][..]
+        );
+        assert!(chunks.iter().all(|c| c.text.len() <= 30));
+        for chunk in &chunks {
+            assert_eq!(&text[chunk.start..chunk.end], chunk.text);
+        }
+        // Pieces of the oversized paragraph share a synthetic parent
+        assert_eq!(chunks[0].metadata.parent_chunk_id, None);
+        assert!(chunks[1].metadata.parent_chunk_id.is_some());
+        assert!(chunks[1..]
+            .iter()
+            .all(|c| c.metadata.parent_chunk_id == chunks[1].metadata.parent_chunk_id));
+    }
+
+    #[test]
+    fn test_paragraph_strict() {
+        let chunker = ParagraphChunker;
+        let config = ChunkConfig::new(20).with_strict_paragraphs(true);
+        let text = "Short one.\n\nA much longer paragraph. With two sentences.";
+        let chunks = chunker.chunk(text, &config);
+
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(
+            chunks[1].text,
+            "A much longer paragraph. With two sentences."
+        );
+        assert_eq!(chunks[1].metadata.parent_chunk_id, None);
+    }
+
+    #[test]
+    fn test_paragraph_crlf_and_whitespace_lines() {
+        let lf = "First para,\nsecond line.\n\nSecond para.\n  \t\nThird para.\n\n\n\nFourth.";
+        let crlf = lf.replace('\n', "\r\n");
+        let config = ChunkConfig::new(12).with_strict_paragraphs(true);
+
+        let lf_chunks = ParagraphChunker.chunk(lf, &config);
+        let texts: Vec<&str> = lf_chunks.iter().map(|c| c.text.as_str()).collect();
+        // The whitespace-only line separates paragraphs too
+        assert_eq!(
+            texts,
+            vec![
+                "First para,\nsecond line.",
+                "Second para.",
+                "Third para.",
+                "Fourth."
+            ][..]
+        );
+
+        let crlf_chunks = ParagraphChunker.chunk(&crlf, &config);
+        let to_crlf = |pos: usize| pos + lf[..pos].matches('\n').count();
+        assert_eq!(lf_chunks.len(), crlf_chunks.len());
+        for (a, b) in lf_chunks.iter().zip(&crlf_chunks) {
+            assert_eq!((to_crlf(a.start), to_crlf(a.end)), (b.start, b.end));
+            assert_eq!(&crlf[b.start..b.end], b.text);
+        }
+    }
+
+    #[test]
+    fn test_paragraph_separator() {
+        let text = "Alpha one.\nBeta two.\n\nGamma three.\nDelta.";
+
+        // Default: only the blank line separates paragraphs
+        let chunks = ParagraphChunker.chunk(text, &ChunkConfig::new(30));
+        let texts: Vec<&str> = chunks.iter().map(|c| c.text.as_str()).collect();
+        assert_eq!(texts, vec!["Alpha one.\nBeta two.", "Gamma three.\nDelta."]);
+
+        // Single newlines: each line is a paragraph, packed with "\n"
+        let config = ChunkConfig::new(22).with_paragraph_separator("\n");
+        let chunks = ParagraphChunker.chunk(text, &config);
+        let texts: Vec<&str> = chunks.iter().map(|c| c.text.as_str()).collect();
+        assert_eq!(texts, vec!["Alpha one.\nBeta two.", "Gamma three.\nDelta."]);
+        assert_eq!(&text[chunks[0].start..chunks[0].end], chunks[0].text);
+        assert_eq!(&text[chunks[1].start..chunks[1].end], chunks[1].text);
+
+        // The separator counts toward max_size: "Gamma three.\nDelta." is 19 bytes
+        let config = ChunkConfig::new(18).with_paragraph_separator("\n");
+        assert_eq!(ParagraphChunker.chunk(text, &config).len(), 4);
+    }
+
+    #[test]
+    fn test_paragraph_end_is_source_offset() {
+        let text = "First.\n\n\n\nSecond one.\n  \n\nThird.";
+        let chunks = ParagraphChunker.chunk(text, &ChunkConfig::new(1000));
+        assert_eq!(chunks[0].text, "First.\n\nSecond one.\n\nThird.");
+        assert_eq!((chunks[0].start, chunks[0].end), (0, text.len()));
+
+        let chunks = ParagraphChunker.chunk(text, &ChunkConfig::new(20));
+        assert_eq!(chunks.len(), 2);
+        for chunk in &chunks {
+            let source = &text[chunk.start..chunk.end];
+            assert!(chunk.text.split("\n\n").all(|para| source.contains(para)));
+            assert!(source.ends_with(chunk.text.rsplit("\n\n").next().unwrap()));
+        }
+    }
+
+    #[test]
+    fn test_paragraph_offsets_with_irregular_blank_lines() {
+        let text = "\n\n\nAlpha one.\nAlpha two.\n\n\n\nBeta.\n\nGamma para.\n\n\n\n\nDelta.\n\n\n";
+        for max_size in [5, 12, 25, 1000] {
+            for strict in [false, true] {
+                let config = ChunkConfig::new(max_size).with_strict_paragraphs(strict);
+                let chunks = ParagraphChunker.chunk(text, &config);
+                assert!(!chunks.is_empty());
+                for chunk in &chunks {
+                    let first_line = chunk.text.lines().next().unwrap();
+                    assert!(
+                        text[chunk.start..].starts_with(first_line),
+                        "chunk {:?} starts at {}",
+                        chunk.text,
+                        chunk.start
+                    );
+                    let last_line = chunk.text.lines().last().unwrap();
+                    assert!(text[..chunk.end].ends_with(last_line));
+                }
+            }
+        }
+        let chunks = ParagraphChunker.chunk(text, &ChunkConfig::new(1000));
+        assert_eq!((chunks[0].start, chunks[0].end), (3, text.len() - 3));
+    }
+
+    #[test]
+    fn test_paragraph_preserve_separators() {
+        let text = "\n  First para,\nline two.\n\n\n\nSecond para.  \n \nA much longer third paragraph. It splits.\n";
+        for strict in [true, false] {
+            let config = ChunkConfig::new(30)
+                .with_strict_paragraphs(strict)
+                .with_preserve_separators(true);
+            let chunks = ParagraphChunker.chunk(text, &config);
+            assert!(chunks.len() >= 3);
+            assert_eq!(
+                chunks.iter().map(|c| c.text.as_str()).collect::<String>(),
+                text
+            );
+            for chunk in &chunks {
+                assert_eq!(&text[chunk.start..chunk.end], chunk.text);
+            }
+        }
+        let config = ChunkConfig::new(30).with_preserve_separators(true);
+        assert_eq!(
+            ParagraphChunker.chunk(text, &config)[0].text,
+            "\n  First para,\nline two.\n\n\n\n"
+        );
+    }
+
+    #[test]
+    fn test_paragraph_balanced() {
+        let text = "Alpha one.\n\nBeta two.\n\nGamma three.\n\nDelta four.\n\nOmega.";
+        let greedy = ParagraphChunker.chunk(text, &ChunkConfig::new(40));
+        let balanced = ParagraphChunker.chunk(text, &ChunkConfig::new(40).with_balanced(true));
+
+        let sizes = |chunks: &[Chunk]| chunks.iter().map(|c| c.text.len()).collect::<Vec<_>>();
+        assert_eq!(sizes(&greedy), vec![35, 19]);
+        assert_eq!(sizes(&balanced), vec![21, 33]);
+        assert_eq!(balanced[1].text, "Gamma three.\n\nDelta four.\n\nOmega.");
+
+        // Chunks still never span a gap wider than max_merge_gap
+        let text = "A.\n\nB.\n\n\n\nC.\n\nD.\n\nE.";
+        let config = ChunkConfig::new(100)
+            .with_balanced(true)
+            .with_max_merge_gap(1);
+        let texts: Vec<String> = ParagraphChunker
+            .chunk(text, &config)
+            .into_iter()
+            .map(|c| c.text)
+            .collect();
+        assert_eq!(texts, vec!["A.\n\nB.", "C.\n\nD.\n\nE."]);
+    }
 }
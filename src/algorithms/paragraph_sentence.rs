@@ -0,0 +1,242 @@
+//! Paragraph-first chunking with sentence fallback.
+//!
+//! Packs whole paragraphs up to max_size. A paragraph that is too large on
+//! its own is split at sentence boundaries instead:
+//! - Pieces overlap by whole trailing sentences, up to `config.overlap` characters
+//! - Pieces of the same paragraph share a `parent_chunk_id`
+
+use crate::algorithms::paragraph::paragraph_spans;
+use crate::algorithms::SentenceChunker;
+use crate::chunk::{Chunk, ChunkMetadata};
+use crate::config::ChunkConfig;
+use crate::traits::ChunkAlgorithm;
+
+/// Paragraph chunker that only splits oversized paragraphs by sentence.
+pub struct ParagraphSentenceChunker;
+
+impl ParagraphSentenceChunker {
+    /// Create a chunk with the given text and metadata fields.
+    fn make_chunk(
+        &self,
+        text: String,
+        start: usize,
+        end: usize,
+        overlap_chars: Option<usize>,
+        parent_chunk_id: Option<String>,
+    ) -> Chunk {
+        let metadata = ChunkMetadata {
+            overlap_chars,
+            parent_chunk_id,
+            ..ChunkMetadata::for_method(self.name())
+        };
+        Chunk::with_uuid(text, start, end, metadata)
+    }
+
+    /// Split an oversized paragraph into sentence-aligned, overlapping pieces.
+    fn split_paragraph(
+        &self,
+        text: &str,
+        (para_start, para_end): (usize, usize),
+        config: &ChunkConfig,
+    ) -> Vec<Chunk> {
+        let paragraph = &text[para_start..para_end];
+        let parent_id = uuid::Uuid::new_v4().to_string();
+        let sentences: Vec<(usize, usize)> = SentenceChunker::split(paragraph, config)
+            .into_iter()
+            .map(|(start, sentence)| {
+                let start = para_start + start + paragraph[start..].find(sentence).unwrap_or(0);
+                (start, start + sentence.len())
+            })
+            .collect();
+
+        let mut pieces = Vec::new();
+        let mut current: Vec<(usize, usize)> = Vec::new();
+        let mut carried = 0; // leading sentences repeated from the previous piece
+
+        let mut flush = |current: &[(usize, usize)], carried: usize| {
+            let start = current[0].0;
+            let end = current[current.len() - 1].1;
+            let overlap =
+                (carried > 0).then(|| text[start..current[carried - 1].1].chars().count());
+            pieces.push(self.make_chunk(
+                text[start..end].to_string(),
+                start,
+                end,
+                overlap,
+                Some(parent_id.clone()),
+            ));
+        };
+
+        for (start, end) in sentences {
+            if current
+                .first()
+                .is_some_and(|first| end - first.0 > config.max_size)
+            {
+                if current.len() > carried {
+                    flush(&current, carried);
+
+                    // Carry whole trailing sentences that fit in the overlap budget
+                    let mut keep = 0;
+                    while keep + 1 < current.len() {
+                        let from = current[current.len() - keep - 1].0;
+                        let to = current[current.len() - 1].1;
+                        if text[from..to].chars().count() > config.overlap {
+                            break;
+                        }
+                        keep += 1;
+                    }
+                    current.drain(..current.len() - keep);
+                    carried = keep;
+                }
+
+                // Drop overlap that would leave no room for the next sentence
+                while current
+                    .first()
+                    .is_some_and(|first| end - first.0 > config.max_size)
+                {
+                    current.remove(0);
+                    carried -= 1;
+                }
+            }
+            current.push((start, end));
+        }
+
+        if current.len() > carried {
+            flush(&current, carried);
+        }
+
+        pieces
+    }
+}
+
+impl ChunkAlgorithm for ParagraphSentenceChunker {
+    fn chunk(&self, text: &str, config: &ChunkConfig) -> Vec<Chunk> {
+        if text.is_empty() {
+            return Vec::new();
+        }
+
+        let mut chunks = Vec::new();
+        let mut current: Vec<(usize, usize)> = Vec::new();
+        let mut current_len = 0;
+        let separator = config.paragraph_separator.as_str();
+
+        let flush = |current: &mut Vec<(usize, usize)>, chunks: &mut Vec<Chunk>| {
+            if current.is_empty() {
+                return;
+            }
+            let joined = current
+                .iter()
+                .map(|&(s, e)| &text[s..e])
+                .collect::<Vec<_>>()
+                .join(separator);
+            let start = current[0].0;
+            let end = current[current.len() - 1].1;
+            chunks.push(self.make_chunk(joined, start, end, None, None));
+            current.clear();
+        };
+
+        for (start, end) in paragraph_spans(text, separator) {
+            let len = end - start;
+
+            if len > config.max_size {
+                // Oversized paragraph: emit pending paragraphs, then sentence pieces
+                flush(&mut current, &mut chunks);
+                chunks.extend(self.split_paragraph(text, (start, end), config));
+                current_len = 0;
+                continue;
+            }
+
+            let potential_len = if current.is_empty() {
+                len
+            } else {
+                current_len + separator.len() + len
+            };
+
+            if potential_len > config.max_size {
+                flush(&mut current, &mut chunks);
+                current_len = len;
+            } else {
+                current_len = potential_len;
+            }
+            current.push((start, end));
+        }
+
+        flush(&mut current, &mut chunks);
+        Chunk::link_sequence(chunks)
+    }
+
+    fn name(&self) -> &str {
+        "paragraph_sentence"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_small_paragraphs_packed() {
+        let chunker = ParagraphSentenceChunker;
+        let config = ChunkConfig::new(40);
+        let text = "First paragraph.\n\nSecond one.\n\nThird paragraph here.";
+        let chunks = chunker.chunk(text, &config);
+
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[0].text, "First paragraph.\n\nSecond one.");
+        assert_eq!(chunks[1].text, "Third paragraph here.");
+        assert!(chunks.iter().all(|c| c.metadata.parent_chunk_id.is_none()));
+    }
+
+    #[test]
+    fn test_mixed_paragraph_sizes() {
+        let chunker = ParagraphSentenceChunker;
+        let config = ChunkConfig::new(40).with_overlap(15);
+        let text = "Short intro.\n\n\
+                    One alpha. Two bravo. Three charlie. Four delta. Five echo.\n\n\
+                    Short outro.";
+        let chunks = chunker.chunk(text, &config);
+
+        let texts: Vec<&str> = chunks.iter().map(|c| c.text.as_str()).collect();
+        assert_eq!(
+            texts,
+            vec![
+                "Short intro.",
+                "One alpha. Two bravo. Three charlie.",
+                "Three charlie. Four delta. Five echo.",
+                "Short outro.",
+            ]
+        );
+
+        // Pieces of the oversized paragraph share a parent and overlap by a sentence
+        let parent = chunks[1].metadata.parent_chunk_id.clone();
+        assert!(parent.is_some());
+        assert_eq!(chunks[2].metadata.parent_chunk_id, parent);
+        assert_eq!(chunks[1].metadata.overlap_chars, None);
+        assert_eq!(chunks[2].metadata.overlap_chars, Some(14));
+        assert!(chunks[3].metadata.parent_chunk_id.is_none());
+
+        for chunk in &chunks[1..3] {
+            assert_eq!(&text[chunk.start..chunk.end], chunk.text);
+        }
+    }
+
+    #[test]
+    fn test_no_overlap_by_default() {
+        let chunker = ParagraphSentenceChunker;
+        let config = ChunkConfig::new(25);
+        let text = "One alpha. Two bravo. Three charlie.";
+        let chunks = chunker.chunk(text, &config);
+
+        let texts: Vec<&str> = chunks.iter().map(|c| c.text.as_str()).collect();
+        assert_eq!(texts, vec!["One alpha. Two bravo.", "Three charlie."]);
+    }
+
+    #[test]
+    fn test_empty() {
+        let chunker = ParagraphSentenceChunker;
+        let config = ChunkConfig::new(100);
+
+        assert!(chunker.chunk("", &config).is_empty());
+        assert!(chunker.chunk("\n\n\n\n", &config).is_empty());
+    }
+}
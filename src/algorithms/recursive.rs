@@ -1,28 +1,57 @@
 //! Recursive chunking algorithm.
 //!
 //! Multi-level chunking with fallback strategies:
-//! 1. Try paragraph boundaries
+//! 1. Try paragraph boundaries (or markdown structure first)
 //! 2. Fall back to sentence boundaries
 //! 3. Fall back to fixed-size
 
-use crate::algorithms::{FixedSizeChunker, ParagraphChunker, SentenceChunker};
+use crate::algorithms::{FixedSizeChunker, MarkdownChunker, ParagraphChunker, SentenceChunker};
 use crate::chunk::{Chunk, ChunkMetadata};
 use crate::config::ChunkConfig;
-use crate::traits::ChunkAlgorithm;
+use crate::error::ChunkError;
+use crate::traits::{parse_options, ChunkAlgorithm};
+use serde::Deserialize;
+use serde_json::Value;
+use std::str::FromStr;
 
 /// Strategy for recursive chunking.
-#[derive(Debug, Clone, Copy, Default)]
+///
+/// Named `"paragraph_first"`, `"sentence_first"` and `"markdown_first"` in
+/// algorithm options, and `"paragraph"`, `"sentence"` and `"markdown"` in Python.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum RecursiveStrategy {
     /// Try paragraph first, then sentence, then fixed
     #[default]
     ParagraphFirst,
     /// Try sentence first, then fixed
     SentenceFirst,
+    /// Split markdown at headings (keeping code blocks whole), then
+    /// paragraphs, then sentences, then fixed. Deeper chunks keep the
+    /// section of the markdown chunk they came from.
+    MarkdownFirst,
+}
+
+impl FromStr for RecursiveStrategy {
+    type Err = ChunkError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "paragraph" => Ok(RecursiveStrategy::ParagraphFirst),
+            "sentence" => Ok(RecursiveStrategy::SentenceFirst),
+            "markdown" => Ok(RecursiveStrategy::MarkdownFirst),
+            _ => Err(ChunkError::InvalidConfig(format!(
+                "recursive strategy must be 'paragraph', 'sentence' or 'markdown', got {:?}",
+                s
+            ))),
+        }
+    }
 }
 
 /// Recursive chunker that applies multiple strategies.
 pub struct RecursiveChunker {
     strategy: RecursiveStrategy,
+    markdown_chunker: MarkdownChunker,
     paragraph_chunker: ParagraphChunker,
     sentence_chunker: SentenceChunker,
     fixed_chunker: FixedSizeChunker,
@@ -39,16 +68,66 @@ impl RecursiveChunker {
     pub fn new(strategy: RecursiveStrategy) -> Self {
         Self {
             strategy,
+            markdown_chunker: MarkdownChunker::default(),
             paragraph_chunker: ParagraphChunker,
             sentence_chunker: SentenceChunker,
             fixed_chunker: FixedSizeChunker,
         }
     }
 
+    /// Chunk text into approximately `target_chunks` chunks.
+    ///
+    /// Binary-searches for an effective `max_size` so that structural
+    /// boundaries are still used wherever they fit. Returns the chunks along
+    /// with the effective `max_size` that produced them.
+    pub fn chunk_to_target(
+        &self,
+        text: &str,
+        config: &ChunkConfig,
+        target_chunks: usize,
+    ) -> (Vec<Chunk>, usize) {
+        let target = target_chunks.max(1);
+        let run = |max_size: usize| {
+            let mut sized = config.clone();
+            sized.max_size = max_size;
+            self.chunk(text, &sized)
+        };
+
+        if text.is_empty() {
+            return (Vec::new(), config.max_size);
+        }
+
+        // Smallest max_size producing at most `target` chunks
+        let (mut low, mut high) = (1, text.len());
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if run(mid).len() <= target {
+                high = mid;
+            } else {
+                low = mid + 1;
+            }
+        }
+
+        let at_most = run(low);
+        if low > 1 && at_most.len() < target {
+            // One size smaller may land closer to the target from above
+            let above = run(low - 1);
+            if above.len() - target < target - at_most.len() {
+                return (above, low - 1);
+            }
+        }
+
+        (at_most, low)
+    }
+
     /// Recursively chunk a piece of text that exceeds max_size.
+    ///
+    /// `base` is the byte offset of `text` in the original document; every
+    /// returned chunk's `start`/`end` is rebased onto document coordinates.
     fn chunk_recursive(
         &self,
         text: &str,
+        base: usize,
         config: &ChunkConfig,
         parent_id: Option<String>,
         level: usize,
@@ -60,18 +139,21 @@ impl RecursiveChunker {
         // If text fits, return as single chunk
         if text.len() <= config.max_size {
             let metadata = ChunkMetadata {
-                method: format!("recursive_l{}", level),
-                section: None,
-                overlap_chars: None,
                 parent_chunk_id: parent_id,
+                ..ChunkMetadata::for_method(format!("recursive_l{}", level))
             };
-            return vec![Chunk::with_uuid(text.to_string(), 0, text.len(), metadata)];
+            return vec![Chunk::with_uuid(
+                text.to_string(),
+                base,
+                base + text.len(),
+                metadata,
+            )];
         }
 
         // Try chunking strategies based on strategy enum
         let initial_chunks = match self.strategy {
             RecursiveStrategy::ParagraphFirst if level == 0 => {
-                self.paragraph_chunker.chunk(text, config)
+                self.paragraph_chunker.chunk_strict(text, config)
             }
             RecursiveStrategy::ParagraphFirst if level == 1 => {
                 self.sentence_chunker.chunk(text, config)
@@ -79,6 +161,15 @@ impl RecursiveChunker {
             RecursiveStrategy::SentenceFirst if level == 0 => {
                 self.sentence_chunker.chunk(text, config)
             }
+            RecursiveStrategy::MarkdownFirst if level == 0 => {
+                self.markdown_chunker.chunk(text, config)
+            }
+            RecursiveStrategy::MarkdownFirst if level == 1 => {
+                self.paragraph_chunker.chunk_strict(text, config)
+            }
+            RecursiveStrategy::MarkdownFirst if level == 2 => {
+                self.sentence_chunker.chunk(text, config)
+            }
             _ => {
                 // Final fallback: fixed-size
                 self.fixed_chunker.chunk(text, config)
@@ -92,8 +183,20 @@ impl RecursiveChunker {
             if chunk.text.len() > config.max_size {
                 // Need to split further
                 let parent_chunk_id = chunk.id.clone();
-                let sub_chunks =
-                    self.chunk_recursive(&chunk.text, config, Some(parent_chunk_id), level + 1);
+                let mut sub_chunks = self.chunk_recursive(
+                    &chunk.text,
+                    base + chunk.start,
+                    config,
+                    Some(parent_chunk_id),
+                    level + 1,
+                );
+                // Pieces of a markdown section stay in that section
+                for sub_chunk in &mut sub_chunks {
+                    if sub_chunk.metadata.section.is_none() {
+                        sub_chunk.metadata.section = chunk.metadata.section.clone();
+                        sub_chunk.metadata.section_path = chunk.metadata.section_path.clone();
+                    }
+                }
                 result.extend(sub_chunks);
             } else {
                 // Chunk fits, add with proper metadata
@@ -103,8 +206,8 @@ impl RecursiveChunker {
                 result.push(Chunk {
                     id: chunk.id,
                     text: chunk.text,
-                    start: chunk.start,
-                    end: chunk.end,
+                    start: base + chunk.start,
+                    end: base + chunk.end,
                     metadata: new_metadata,
                 });
             }
@@ -114,19 +217,39 @@ impl RecursiveChunker {
     }
 }
 
+/// Options accepted by [`RecursiveChunker::with_options`](ChunkAlgorithm::with_options).
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RecursiveOptions {
+    strategy: Option<RecursiveStrategy>,
+}
+
 impl ChunkAlgorithm for RecursiveChunker {
     fn chunk(&self, text: &str, config: &ChunkConfig) -> Vec<Chunk> {
-        self.chunk_recursive(text, config, None, 0)
+        if config.preserve_separators {
+            // Only the standalone paragraph and sentence chunkers keep separators
+            let config = config.clone().with_preserve_separators(false);
+            return Chunk::link_sequence(self.chunk_recursive(text, 0, &config, None, 0));
+        }
+        Chunk::link_sequence(self.chunk_recursive(text, 0, config, None, 0))
     }
 
     fn name(&self) -> &str {
         "recursive"
     }
+
+    fn with_options(&self, options: &Value) -> Result<Box<dyn ChunkAlgorithm>, ChunkError> {
+        let options: RecursiveOptions = parse_options(self.name(), options)?;
+        Ok(Box::new(Self::new(
+            options.strategy.unwrap_or(self.strategy),
+        )))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::IdStrategy;
 
     #[test]
     fn test_recursive_small_text() {
@@ -162,6 +285,116 @@ mod tests {
         assert!(within_limit > 0);
     }
 
+    #[test]
+    fn test_recursive_document_offsets() {
+        let chunker = RecursiveChunker::default();
+        let config = ChunkConfig::new(40);
+        let text = "Short intro paragraph.\n\n\
+                    First long sentence goes here. Second long sentence follows it.\n\n\
+                    Closing words.";
+        let chunks = chunker.chunk(text, &config);
+
+        // The middle paragraph is split into its two sentences
+        assert_eq!(chunks.len(), 4);
+        assert_eq!(chunks[2].text, "Second long sentence follows it.");
+        assert_eq!(chunks[2].metadata.method, "recursive_l1");
+        for chunk in &chunks {
+            assert!(
+                text[chunk.start..chunk.end].contains(&chunk.text),
+                "{:?} at {}..{}",
+                chunk.text,
+                chunk.start,
+                chunk.end
+            );
+        }
+    }
+
+    #[test]
+    fn test_recursive_numbers_final_output() {
+        let chunker = RecursiveChunker::default();
+        let config = ChunkConfig::new(40);
+        let text = "Short intro paragraph.\n\n\
+                    First long sentence goes here. Second long sentence follows it.\n\n\
+                    Closing words.";
+        let chunks = chunker.chunk(text, &config);
+
+        // The split middle paragraph's sentences are numbered within the whole document
+        assert_eq!(chunks.len(), 4);
+        for (index, chunk) in chunks.iter().enumerate() {
+            assert_eq!(chunk.metadata.chunk_index, Some(index));
+            assert_eq!(chunk.metadata.total_chunks, Some(4));
+        }
+        assert_eq!(chunks[0].metadata.prev_chunk_id, None);
+        assert_eq!(
+            chunks[1].metadata.prev_chunk_id.as_ref(),
+            Some(&chunks[0].id)
+        );
+        assert_eq!(
+            chunks[2].metadata.next_chunk_id.as_ref(),
+            Some(&chunks[3].id)
+        );
+        assert_eq!(chunks[3].metadata.next_chunk_id, None);
+
+        // Links follow the IDs assigned by the configured strategy
+        let config = config.with_id_strategy(IdStrategy::Sequential);
+        let chunks = Chunk::finalize(chunker.chunk(text, &config), &config);
+        assert_eq!(chunks[1].metadata.prev_chunk_id.as_deref(), Some("doc-0"));
+        assert_eq!(chunks[1].metadata.next_chunk_id.as_deref(), Some("doc-2"));
+    }
+
+    #[test]
+    fn test_recursive_markdown_first() {
+        let items: Vec<String> = (0..4)
+            .map(|i| {
+                format!(
+                    "- Item {} has a first sentence here. Then a second one follows it.",
+                    i
+                )
+            })
+            .collect();
+        let text = format!(
+            "# Guide\n\nIntro.\n\n## Installation\n\n{}\n\n## Usage\n\nRun it.",
+            items.join("\n\n")
+        );
+        let chunker = RecursiveChunker::new(RecursiveStrategy::MarkdownFirst);
+        let chunks = chunker.chunk(&text, &ChunkConfig::new(60));
+
+        assert_eq!(chunks[0].metadata.section.as_deref(), Some("h1: Guide"));
+        assert_eq!(
+            chunks.last().unwrap().metadata.section.as_deref(),
+            Some("h2: Usage")
+        );
+        // The oversized list goes through paragraphs down to sentences
+        let deep: Vec<&Chunk> = chunks
+            .iter()
+            .filter(|c| c.metadata.method == "recursive_l2")
+            .collect();
+        assert_eq!(deep.len(), 8);
+        assert_eq!(deep[1].text, "Then a second one follows it.");
+        for chunk in deep {
+            assert_eq!(chunk.metadata.section.as_deref(), Some("h2: Installation"));
+            assert_eq!(
+                chunk.metadata.section_path,
+                Some(vec!["Guide".to_string(), "Installation".to_string()])
+            );
+            assert!(chunk.metadata.parent_chunk_id.is_some());
+            assert_eq!(&text[chunk.start..chunk.end], chunk.text);
+            assert!(chunk.text.len() <= 60);
+        }
+
+        let options = serde_json::json!({ "strategy": "markdown_first" });
+        let from_options = chunker.with_options(&options).unwrap();
+        assert_eq!(
+            from_options.chunk(&text, &ChunkConfig::new(60)).len(),
+            chunks.len()
+        );
+        assert_eq!(
+            "markdown".parse::<RecursiveStrategy>().unwrap(),
+            RecursiveStrategy::MarkdownFirst
+        );
+        assert!("markdown_first".parse::<RecursiveStrategy>().is_err());
+    }
+
     #[test]
     fn test_recursive_empty() {
         let chunker = RecursiveChunker::default();
@@ -181,4 +414,92 @@ mod tests {
         // Check that method contains level info
         assert!(chunks[0].metadata.method.starts_with("recursive_l"));
     }
+
+    #[test]
+    fn test_recursive_deterministic_ids() {
+        use crate::config::IdStrategy;
+
+        let chunker = RecursiveChunker::default();
+        let config = ChunkConfig::new(10).with_id_strategy(IdStrategy::ContentHash);
+        let text = "This is a long sentence without any paragraph breaks.";
+        let run = || {
+            let mut chunks = chunker.chunk(text, &config);
+            Chunk::assign_ids(&mut chunks, &config);
+            chunks
+        };
+        let (first, second) = (run(), run());
+
+        let ids: Vec<&str> = first.iter().map(|c| c.id.as_str()).collect();
+        assert_eq!(
+            ids,
+            second.iter().map(|c| c.id.as_str()).collect::<Vec<_>>()
+        );
+        assert_eq!(ids.len(), first.len());
+        assert_eq!(first[0].id.len(), 32);
+        // Siblings split from the same dropped parent still share one stable parent ID
+        assert!(first[0].metadata.parent_chunk_id.is_some());
+        assert_eq!(
+            first[0].metadata.parent_chunk_id,
+            second[0].metadata.parent_chunk_id
+        );
+        assert_eq!(
+            first[0].metadata.parent_chunk_id,
+            first[1].metadata.parent_chunk_id
+        );
+    }
+
+    #[test]
+    fn test_recursive_sequential_ids() {
+        use crate::config::IdStrategy;
+
+        let chunker = RecursiveChunker::default();
+        let config = ChunkConfig::new(20)
+            .with_id_strategy(IdStrategy::Sequential)
+            .with_doc_id("report");
+        let mut chunks = chunker.chunk("Para one.\n\nPara two which is a bit longer.", &config);
+        Chunk::assign_ids(&mut chunks, &config);
+
+        assert_eq!(chunks[0].id, "report-0");
+        assert_eq!(chunks[1].id, "report-1");
+    }
+
+    #[test]
+    fn test_recursive_chunk_to_target() {
+        let chunker = RecursiveChunker::default();
+        let config = ChunkConfig::new(10_000);
+        let text = (1..=8)
+            .map(|i| format!("Paragraph number {} has some words.", i))
+            .collect::<Vec<_>>()
+            .join("\n\n");
+        let (chunks, size) = chunker.chunk_to_target(&text, &config, 4);
+
+        assert_eq!(chunks.len(), 4);
+        assert!(size < 10_000);
+        // Paragraph boundaries are respected
+        for chunk in &chunks {
+            assert!(chunk.text.starts_with("Paragraph"));
+            assert!(chunk.text.ends_with("words."));
+            assert!(chunk.text.len() <= size);
+        }
+    }
+
+    #[test]
+    fn test_recursive_chunk_to_target_near() {
+        let chunker = RecursiveChunker::default();
+        let config = ChunkConfig::new(100);
+        let text = "Short. ".repeat(50);
+        let (chunks, _) = chunker.chunk_to_target(&text, &config, 7);
+
+        assert!(chunks.len().abs_diff(7) <= 1);
+    }
+
+    #[test]
+    fn test_recursive_chunk_to_target_empty() {
+        let chunker = RecursiveChunker::default();
+        let config = ChunkConfig::new(100);
+        let (chunks, size) = chunker.chunk_to_target("", &config, 3);
+
+        assert!(chunks.is_empty());
+        assert_eq!(size, 100);
+    }
 }
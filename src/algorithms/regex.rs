@@ -0,0 +1,338 @@
+//! Regex-based chunking algorithm.
+//!
+//! Splits text at each match of a user-supplied pattern (e.g. `\n#{1,6}\s`,
+//! a speaker tag or `Article \d+\.`) and packs the resulting segments up to
+//! max_size. By default matches begin the segment that follows them, so
+//! markers such as headings or timestamps stay attached to the text they
+//! introduce; [`KeepDelimiter`] can attach them to the previous segment or
+//! drop them instead.
+
+use crate::chunk::{Chunk, ChunkMetadata};
+use crate::config::ChunkConfig;
+use crate::error::ChunkError;
+use crate::traits::{parse_options, ChunkAlgorithm};
+use regex::{Regex, RegexBuilder};
+use serde::Deserialize;
+use serde_json::Value;
+use std::str::FromStr;
+
+/// Compiled size limit for user patterns, so a pattern such as `(a{1000}){1000}`
+/// fails fast instead of building a huge automaton.
+const PATTERN_SIZE_LIMIT: usize = 1 << 20;
+
+/// Separator placed between segments when delimiters are dropped.
+const DROPPED_DELIMITER_JOIN: &str = "\n\n";
+
+/// Compile a user-supplied pattern under [`PATTERN_SIZE_LIMIT`].
+pub(crate) fn compile_pattern(pattern: &str) -> Result<Regex, ChunkError> {
+    RegexBuilder::new(pattern)
+        .size_limit(PATTERN_SIZE_LIMIT)
+        .build()
+        .map_err(|e| ChunkError::InvalidConfig(format!("invalid pattern {:?}: {}", pattern, e)))
+}
+
+/// Where the text matched by the pattern goes.
+///
+/// Named `"start"`, `"end"` and `"drop"` in Python and algorithm options.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum KeepDelimiter {
+    /// Begin the segment after the match with it
+    #[default]
+    Start,
+    /// End the segment before the match with it
+    End,
+    /// Leave the match out of every chunk
+    Drop,
+}
+
+impl FromStr for KeepDelimiter {
+    type Err = ChunkError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "start" => Ok(KeepDelimiter::Start),
+            "end" => Ok(KeepDelimiter::End),
+            "drop" => Ok(KeepDelimiter::Drop),
+            _ => Err(ChunkError::InvalidConfig(format!(
+                "keep_delimiter must be 'start', 'end' or 'drop', got {:?}",
+                s
+            ))),
+        }
+    }
+}
+
+/// Regex chunker that splits at matches of `config.pattern`.
+#[derive(Default)]
+pub struct RegexChunker {
+    /// Where each match goes (default: the start of the following segment)
+    pub keep_delimiter: KeepDelimiter,
+}
+
+impl RegexChunker {
+    /// Create a new RegexChunker with the given delimiter placement.
+    pub fn new(keep_delimiter: KeepDelimiter) -> Self {
+        Self { keep_delimiter }
+    }
+
+    /// Compile the configured pattern.
+    fn compile(config: &ChunkConfig) -> Result<Regex, ChunkError> {
+        let pattern = config.pattern.as_deref().ok_or_else(|| {
+            ChunkError::InvalidConfig("pattern is required for regex chunking".to_string())
+        })?;
+        compile_pattern(pattern)
+    }
+
+    /// Split text into trimmed segment spans at each match.
+    ///
+    /// Adjacent matches and matches at either end of the text leave empty
+    /// segments, which are skipped when `skip_empty` is set and kept as
+    /// empty spans otherwise.
+    fn segments(&self, text: &str, re: &Regex, skip_empty: bool) -> Vec<(usize, usize)> {
+        let mut parts: Vec<(usize, usize)> = Vec::new();
+        let mut part_start = 0;
+        for m in re.find_iter(text) {
+            let (end, next) = match self.keep_delimiter {
+                KeepDelimiter::Start => (m.start(), m.start()),
+                KeepDelimiter::End => (m.end(), m.end()),
+                KeepDelimiter::Drop => (m.start(), m.end()),
+            };
+            parts.push((part_start, end));
+            part_start = next;
+        }
+        parts.push((part_start, text.len()));
+
+        parts
+            .into_iter()
+            .filter_map(|(start, end)| {
+                let part = &text[start..end];
+                let trimmed = part.trim();
+                if trimmed.is_empty() {
+                    return (!skip_empty).then_some((start, start));
+                }
+                let start = start + (part.len() - part.trim_start().len());
+                Some((start, start + trimmed.len()))
+            })
+            .collect()
+    }
+
+    /// Create a chunk from packed segment spans.
+    ///
+    /// The text is the contiguous source span, or the segments joined by a
+    /// blank line when delimiters are dropped.
+    fn make_chunk(&self, text: &str, spans: &[(usize, usize)]) -> Chunk {
+        let start = spans.first().map_or(0, |s| s.0);
+        let end = spans.last().map_or(0, |s| s.1);
+        let chunk_text = if self.keep_delimiter == KeepDelimiter::Drop {
+            spans
+                .iter()
+                .map(|&(s, e)| &text[s..e])
+                .collect::<Vec<_>>()
+                .join(DROPPED_DELIMITER_JOIN)
+        } else {
+            text[start..end].to_string()
+        };
+
+        let metadata = ChunkMetadata::for_method(self.name());
+        Chunk::with_uuid(chunk_text, start, end, metadata)
+    }
+}
+
+impl ChunkAlgorithm for RegexChunker {
+    /// Chunk text on the configured pattern. A missing or invalid pattern
+    /// produces no chunks; use [`try_chunk`](Self::try_chunk) to get the error.
+    fn chunk(&self, text: &str, config: &ChunkConfig) -> Vec<Chunk> {
+        self.try_chunk(text, config).unwrap_or_default()
+    }
+
+    /// Chunk text, returning an error if the pattern is missing or invalid.
+    fn try_chunk(&self, text: &str, config: &ChunkConfig) -> Result<Vec<Chunk>, ChunkError> {
+        let re = Self::compile(config)?;
+        if text.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut chunks = Vec::new();
+        let mut current: Vec<(usize, usize)> = Vec::new();
+        let mut current_len = 0;
+
+        for (start, end) in self.segments(text, &re, config.skip_empty_segments) {
+            let potential_len = match current.first() {
+                None => end - start,
+                Some(_) if self.keep_delimiter == KeepDelimiter::Drop => {
+                    current_len + DROPPED_DELIMITER_JOIN.len() + (end - start)
+                }
+                // Segments are packed as one contiguous span of the original text
+                Some(&(chunk_start, _)) => end - chunk_start,
+            };
+
+            if potential_len > config.max_size && !current.is_empty() {
+                chunks.push(self.make_chunk(text, &current));
+                current.clear();
+                current_len = end - start;
+            } else {
+                current_len = potential_len;
+            }
+            current.push((start, end));
+        }
+
+        if !current.is_empty() {
+            chunks.push(self.make_chunk(text, &current));
+        }
+
+        Ok(Chunk::link_sequence(chunks))
+    }
+
+    fn name(&self) -> &str {
+        "regex"
+    }
+
+    fn with_options(&self, options: &Value) -> Result<Box<dyn ChunkAlgorithm>, ChunkError> {
+        let options: RegexOptions = parse_options(self.name(), options)?;
+        Ok(Box::new(Self::new(
+            options.keep_delimiter.unwrap_or(self.keep_delimiter),
+        )))
+    }
+}
+
+/// Options accepted by [`RegexChunker::with_options`](ChunkAlgorithm::with_options).
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RegexOptions {
+    keep_delimiter: Option<KeepDelimiter>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_regex_headings() {
+        let chunker = RegexChunker::default();
+        let config = ChunkConfig::new(20).with_pattern(r"\n#{1,6}\s");
+        let text = "Intro.\n# One\nBody one.\n## Two\nBody two.";
+        let chunks = chunker.chunk(text, &config);
+
+        let texts: Vec<&str> = chunks.iter().map(|c| c.text.as_str()).collect();
+        assert_eq!(
+            texts,
+            vec!["Intro.", "# One\nBody one.", "## Two\nBody two."]
+        );
+        for chunk in &chunks {
+            assert_eq!(&text[chunk.start..chunk.end], chunk.text);
+        }
+    }
+
+    #[test]
+    fn test_regex_packs_segments() {
+        let chunker = RegexChunker::default();
+        let config = ChunkConfig::new(30).with_pattern(r"\[\d{2}:\d{2}\]");
+        let text = "[09:00] hi\n[09:01] hello\n[09:02] how are you doing today?";
+        let chunks = chunker.chunk(text, &config);
+
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[0].text, "[09:00] hi\n[09:01] hello");
+        assert_eq!(chunks[1].text, "[09:02] how are you doing today?");
+    }
+
+    #[test]
+    fn test_regex_invalid_pattern() {
+        let chunker = RegexChunker::default();
+        let config = ChunkConfig::new(100).with_pattern("(unclosed");
+
+        let err = chunker.try_chunk("text", &config).unwrap_err();
+        assert!(matches!(err, ChunkError::InvalidConfig(_)));
+        assert!(config.validate().is_err());
+        assert!(chunker.chunk("text", &config).is_empty());
+    }
+
+    #[test]
+    fn test_regex_no_match_single_chunk() {
+        let chunker = RegexChunker::default();
+        let config = ChunkConfig::new(100).with_pattern(r"Article \d+\.");
+        let text = "  Nothing here matches the pattern.  ";
+        let chunks = chunker.chunk(text, &config);
+
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].text, text.trim());
+        assert_eq!(&text[chunks[0].start..chunks[0].end], chunks[0].text);
+    }
+
+    #[test]
+    fn test_regex_keep_delimiter() {
+        let config = ChunkConfig::new(1).with_pattern(r"\[Speaker \d+\]:");
+        // Adjacent matches and a leading match leave empty segments
+        let text = "[Speaker 1]: Hi.[Speaker 2]:[Speaker 3]: Hello.";
+        let texts = |keep| {
+            RegexChunker::new(keep)
+                .chunk(text, &config)
+                .into_iter()
+                .map(|c| c.text)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            texts(KeepDelimiter::Start),
+            vec!["[Speaker 1]: Hi.", "[Speaker 2]:", "[Speaker 3]: Hello."]
+        );
+        assert_eq!(
+            texts(KeepDelimiter::End),
+            vec!["[Speaker 1]:", "Hi.[Speaker 2]:", "[Speaker 3]:", "Hello."]
+        );
+        assert_eq!(texts(KeepDelimiter::Drop), vec!["Hi.", "Hello."]);
+
+        // Dropped delimiters are replaced by a blank line when packing
+        let config = ChunkConfig::new(100).with_pattern(r"Article \d+\.");
+        let chunks = RegexChunker::new(KeepDelimiter::Drop)
+            .chunk("Article 1. First. Article 2. Second.", &config);
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].text, "First.\n\nSecond.");
+        assert_eq!((chunks[0].start, chunks[0].end), (11, 36));
+    }
+
+    #[test]
+    fn test_regex_keep_empty_segments() {
+        let config = ChunkConfig::new(1).with_pattern(r"\|");
+        let spans = |text: &str, config: &ChunkConfig| -> Vec<(String, usize, usize)> {
+            RegexChunker::new(KeepDelimiter::Drop)
+                .chunk(text, config)
+                .into_iter()
+                .map(|c| (c.text, c.start, c.end))
+                .collect()
+        };
+        let s = |text: &str, start, end| (text.to_string(), start, end);
+
+        // Suppressed by default
+        assert_eq!(spans("|a||b|", &config), vec![s("a", 1, 2), s("b", 4, 5)]);
+
+        // Back-to-back, leading and trailing matches each leave an empty segment
+        let config = config.with_skip_empty_segments(false);
+        assert_eq!(
+            spans("a||b", &config),
+            vec![s("a", 0, 1), s("", 2, 2), s("b", 3, 4)]
+        );
+        assert_eq!(
+            spans("|a|b|", &config),
+            vec![s("", 0, 0), s("a", 1, 2), s("b", 3, 4), s("", 5, 5)]
+        );
+    }
+
+    #[test]
+    fn test_regex_pattern_size_limit() {
+        let config = ChunkConfig::new(100).with_pattern(r"(\w{1000}){1000}");
+        let err = RegexChunker::default()
+            .try_chunk("text", &config)
+            .unwrap_err();
+
+        assert!(matches!(err, ChunkError::InvalidConfig(_)));
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_regex_missing_pattern() {
+        let chunker = RegexChunker::default();
+        let config = ChunkConfig::new(100);
+
+        assert!(chunker.try_chunk("text", &config).is_err());
+    }
+}
@@ -1,136 +1,572 @@
 //! Sentence-based chunking algorithm.
 
+use crate::algorithms::balanced_partition;
 use crate::chunk::{Chunk, ChunkMetadata};
-use crate::config::{ChunkConfig, SentenceDetector};
+use crate::config::{ChunkConfig, SentenceDetector, DEFAULT_SENTENCE_TERMINATORS};
+use crate::error::ChunkError;
 use crate::traits::ChunkAlgorithm;
 use regex::Regex;
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::sync::OnceLock;
 use unicode_segmentation::UnicodeSegmentation;
 
+/// CJK terminators that end a sentence without trailing whitespace.
+const CJK_TERMINATORS: &str = "。！？．｡";
+
+/// Terminators the multilingual detector accepts without trailing whitespace:
+/// the CJK set plus the ellipsis, doubled marks, Devanagari dandas and the
+/// Arabic/Urdu question mark and full stop.
+const MULTILINGUAL_TERMINATORS: &str = "。！？．｡…‼⁇⁈⁉।॥؟۔";
+
+/// Sentence-ending punctuation followed by whitespace or end of string.
+///
+/// CJK terminators (。！？ and the full/half-width periods ．｡) end a sentence
+/// without trailing whitespace, together with any closing brackets or quotes.
+fn sentence_end_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(&sentence_end_pattern(
+            DEFAULT_SENTENCE_TERMINATORS,
+            CJK_TERMINATORS,
+        ))
+        .unwrap()
+    })
+}
+
+/// Like [`sentence_end_re`], with [`MULTILINGUAL_TERMINATORS`] as the unspaced set.
+fn multilingual_end_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(&sentence_end_pattern(
+            DEFAULT_SENTENCE_TERMINATORS,
+            MULTILINGUAL_TERMINATORS,
+        ))
+        .unwrap()
+    })
+}
+
+/// Pattern matching a run of `terminators` followed by whitespace or end of
+/// string, or a run of `unspaced` terminators.
+fn sentence_end_pattern(terminators: &[char], unspaced: &str) -> String {
+    let class: String = terminators
+        .iter()
+        .map(|c| regex::escape(c.encode_utf8(&mut [0; 4])))
+        .collect();
+    format!(r"[{class}]+[\s]+|[{class}]+$|[{unspaced}]+[」』）)】》〉”’]*\s*")
+}
+
+/// Run `f` with the sentence-end regex for `config.sentence_terminators`.
+///
+/// The default terminators share a static regex per detector; the most
+/// recent custom set is compiled once per thread and reused.
+fn with_sentence_end_re<R>(config: &ChunkConfig, f: impl FnOnce(&Regex) -> R) -> R {
+    thread_local! {
+        static CUSTOM: RefCell<Option<(Vec<char>, bool, Regex)>> = const { RefCell::new(None) };
+    }
+
+    let terminators = &config.sentence_terminators;
+    let multilingual = config.sentence_detector == SentenceDetector::Multilingual;
+    if terminators == DEFAULT_SENTENCE_TERMINATORS {
+        return f(if multilingual {
+            multilingual_end_re()
+        } else {
+            sentence_end_re()
+        });
+    }
+    CUSTOM.with_borrow_mut(|cached| {
+        if cached
+            .as_ref()
+            .is_none_or(|(key, ml, _)| key != terminators || *ml != multilingual)
+        {
+            let unspaced = if multilingual {
+                MULTILINGUAL_TERMINATORS
+            } else {
+                CJK_TERMINATORS
+            };
+            let re = Regex::new(&sentence_end_pattern(terminators, unspaced)).unwrap();
+            *cached = Some((terminators.clone(), multilingual, re));
+        }
+        f(&cached.as_ref().unwrap().2)
+    })
+}
+
+/// Abbreviations the smart detector does not split after, unless overridden
+/// by `ChunkConfig::abbreviations`. Matching ignores ASCII case.
+pub const DEFAULT_ABBREVIATIONS: &[&str] = &[
+    "Mr.", "Mrs.", "Ms.", "Dr.", "Prof.", "Sr.", "Jr.", "St.", "Gen.", "Rev.", "vs.", "etc.",
+    "e.g.", "i.e.", "cf.", "al.", "approx.", "Inc.", "Ltd.", "Co.", "Corp.", "No.", "Fig.", "Vol.",
+    "Jan.", "Feb.", "Mar.", "Apr.", "Jun.", "Jul.", "Aug.", "Sep.", "Sept.", "Oct.", "Nov.",
+    "Dec.",
+];
+
 /// Sentence-based chunker with configurable detection method.
 pub struct SentenceChunker;
 
 impl SentenceChunker {
-    /// Split text into sentences using regex (fast, basic).
-    fn split_regex(text: &str) -> Vec<(usize, usize, &str)> {
-        // Match sentence-ending punctuation followed by whitespace or end of string
-        let re = Regex::new(r"[.!?]+[\s]+|[.!?]+$").unwrap();
+    /// Find the next sentence at or after byte offset `pos` using regex (fast, basic).
+    ///
+    /// Returns the sentence start, its text, and the offset to resume from.
+    fn next_regex<'t>(
+        text: &'t str,
+        mut pos: usize,
+        config: &ChunkConfig,
+    ) -> Option<(usize, &'t str, usize)> {
+        while pos < text.len() {
+            let found = with_sentence_end_re(config, |re| {
+                re.find_at(text, pos).map(|m| (m.start(), m.end()))
+            });
+            let Some((_, mat_end)) = found else {
+                // Remaining text with no ending punctuation
+                let remaining = &text[pos..];
+                return (!remaining.trim().is_empty()).then(|| (pos, remaining.trim(), text.len()));
+            };
 
-        let mut sentences = Vec::new();
-        let mut last_end = 0;
+            let sentence = &text[pos..mat_end];
+            if !sentence.trim().is_empty() {
+                return Some((pos, sentence.trim_end(), mat_end));
+            }
+            pos = mat_end;
+        }
+
+        None
+    }
+
+    /// Find the next sentence at or after byte offset `pos` using regex, skipping
+    /// periods that end an abbreviation or a single capital-letter initial,
+    /// decimal points (including full-width ones), and ellipses ("...")
+    /// followed by a lowercase word.
+    fn next_smart<'t>(
+        text: &'t str,
+        mut pos: usize,
+        config: &ChunkConfig,
+    ) -> Option<(usize, &'t str, usize)> {
+        let mut search = pos;
+        while pos < text.len() {
+            let found = with_sentence_end_re(config, |re| {
+                re.find_at(text, search).map(|m| (m.start(), m.end()))
+            });
+            let Some((mat_start, mat_end)) = found else {
+                let remaining = &text[pos..];
+                return (!remaining.trim().is_empty()).then(|| (pos, remaining.trim(), text.len()));
+            };
+
+            let punct = text[mat_start..mat_end].trim_end();
+            let before = &text[pos..mat_start];
+            let after = &text[mat_end..];
+            let decimal_point = mat_end - mat_start == punct.len()
+                && before.ends_with(|c: char| c.is_ascii_digit())
+                && after.starts_with(|c: char| c.is_ascii_digit());
+            let continues = match punct {
+                "." => Self::ends_with_abbreviation(before, config),
+                "..." => after.starts_with(char::is_lowercase),
+                _ => false,
+            };
+            if !after.is_empty() && (decimal_point || continues) {
+                search = mat_end;
+                continue;
+            }
 
-        for mat in re.find_iter(text) {
-            let sentence_end = mat.end();
-            let sentence = &text[last_end..sentence_end];
+            let sentence = &text[pos..mat_end];
             if !sentence.trim().is_empty() {
-                sentences.push((last_end, sentence_end, sentence.trim_end()));
+                return Some((pos, sentence.trim_end(), mat_end));
             }
-            last_end = sentence_end;
+            pos = mat_end;
+            search = pos;
+        }
+
+        None
+    }
+
+    /// Check whether the last word of `before` (whose period follows it) is an
+    /// abbreviation or a single capital-letter initial such as "J".
+    fn ends_with_abbreviation(before: &str, config: &ChunkConfig) -> bool {
+        let word = before
+            .rsplit(char::is_whitespace)
+            .next()
+            .unwrap_or("")
+            .trim_start_matches(|c: char| !c.is_alphanumeric());
+        if word.is_empty() {
+            return false;
+        }
+
+        let mut chars = word.chars();
+        if chars.next().is_some_and(char::is_uppercase) && chars.next().is_none() {
+            return true;
         }
 
-        // Handle remaining text (no ending punctuation)
-        if last_end < text.len() {
-            let remaining = &text[last_end..];
-            if !remaining.trim().is_empty() {
-                sentences.push((last_end, text.len(), remaining.trim()));
+        let matches = |abbreviation: &str| {
+            abbreviation
+                .trim_end_matches('.')
+                .eq_ignore_ascii_case(word)
+        };
+        match &config.abbreviations {
+            Some(list) => list.iter().any(|a| matches(a)),
+            None => DEFAULT_ABBREVIATIONS.iter().any(|a| matches(a)),
+        }
+    }
+
+    /// Find the next sentence at or after byte offset `pos` for mixed-script text.
+    ///
+    /// Splits like [`next_regex`](Self::next_regex) with the wider
+    /// [`MULTILINGUAL_TERMINATORS`] set. Text with no terminator left (Thai,
+    /// for instance, does not punctuate sentences) falls back to Unicode
+    /// segmentation.
+    fn next_multilingual<'t>(
+        text: &'t str,
+        pos: usize,
+        config: &ChunkConfig,
+    ) -> Option<(usize, &'t str, usize)> {
+        let terminated = with_sentence_end_re(config, |re| re.is_match_at(text, pos));
+        if terminated {
+            Self::next_regex(text, pos, config)
+        } else {
+            Self::next_unicode(text, pos)
+        }
+    }
+
+    /// Find the next sentence at or after byte offset `pos` using Unicode segmentation (accurate).
+    fn next_unicode(text: &str, mut pos: usize) -> Option<(usize, &str, usize)> {
+        while pos < text.len() {
+            let sentence = text[pos..].split_sentence_bounds().next()?;
+            let trimmed = sentence.trim();
+            let sentence_pos = pos;
+            pos += sentence.len();
+
+            if !trimmed.is_empty() {
+                // Offset past the leading whitespace that trim() removed
+                let start = sentence_pos + sentence.len() - sentence.trim_start().len();
+                return Some((start, trimmed, pos));
             }
         }
 
-        sentences
+        None
     }
 
-    /// Split text into sentences using Unicode segmentation (accurate).
-    fn split_unicode(text: &str) -> Vec<(usize, usize, &str)> {
-        let mut sentences = Vec::new();
-        let mut byte_offset = 0;
+    /// Find the next sentence at or after `pos` with the configured detector.
+    ///
+    /// With `respect_enclosures`, a sentence that ends inside an open quote,
+    /// parenthesis or bracket is joined with the following ones until the
+    /// enclosure closes. Enclosures that do not close within the paragraph
+    /// are treated as unbalanced and do not suppress the break.
+    pub(crate) fn next_sentence<'t>(
+        text: &'t str,
+        pos: usize,
+        config: &ChunkConfig,
+    ) -> Option<(usize, &'t str, usize)> {
+        let (start, mut sentence, mut next) = Self::detect(text, pos, config)?;
+        if !config.respect_enclosures {
+            return Some((start, sentence, next));
+        }
+
+        let mut open = Vec::new();
+        let mut scanned = start;
+        loop {
+            for (i, c) in text[scanned..next].char_indices() {
+                track_enclosure(text, scanned + i, c, &mut open);
+            }
+            scanned = next;
+            if open.is_empty() || !closes_in_paragraph(text, next, open.clone()) {
+                break;
+            }
+            let Some((more_start, more, more_next)) = Self::detect(text, next, config) else {
+                break;
+            };
+            sentence = &text[start..more_start + more.len()];
+            next = more_next;
+        }
+        Some((start, sentence, next))
+    }
+
+    /// Find the next sentence at or after `pos` with the configured detector alone.
+    fn detect<'t>(
+        text: &'t str,
+        pos: usize,
+        config: &ChunkConfig,
+    ) -> Option<(usize, &'t str, usize)> {
+        match config.sentence_detector {
+            SentenceDetector::Regex | SentenceDetector::Custom => {
+                Self::next_regex(text, pos, config)
+            }
+            SentenceDetector::Unicode => Self::next_unicode(text, pos),
+            SentenceDetector::Smart => Self::next_smart(text, pos, config),
+            SentenceDetector::Multilingual => Self::next_multilingual(text, pos, config),
+        }
+    }
 
-        for sentence in text.split_sentence_bounds() {
+    /// Find the next sentence at or after `pos` that ends at one of the sorted
+    /// byte offsets in `ends` (or at the end of the text).
+    fn next_at_boundaries<'t>(
+        text: &'t str,
+        mut pos: usize,
+        ends: &[usize],
+    ) -> Option<(usize, &'t str, usize)> {
+        while pos < text.len() {
+            let end = ends
+                .get(ends.partition_point(|&e| e <= pos))
+                .copied()
+                .unwrap_or(text.len());
+            let sentence = &text[pos..end];
             let trimmed = sentence.trim();
             if !trimmed.is_empty() {
-                let start = byte_offset + sentence.find(trimmed).unwrap_or(0);
-                let end = start + trimmed.len();
-                sentences.push((start, end, trimmed));
+                let start = pos + sentence.len() - sentence.trim_start().len();
+                return Some((start, trimmed, end));
             }
-            byte_offset += sentence.len();
+            pos = end;
         }
 
+        None
+    }
+
+    /// Pack sentences that end at the given byte offsets, as reported by an
+    /// external segmenter, up to `config.max_size`.
+    ///
+    /// Offsets may be unsorted or repeated; 0 and `text.len()` are implied.
+    /// Returns [`ChunkError::ProcessingError`] for an offset past the end of
+    /// the text or inside a multi-byte character.
+    pub fn chunk_with_boundaries(
+        &self,
+        text: &str,
+        boundaries: &[usize],
+        config: &ChunkConfig,
+    ) -> Result<Vec<Chunk>, ChunkError> {
+        if let Some(&bad) = boundaries.iter().find(|&&b| !text.is_char_boundary(b)) {
+            return Err(ChunkError::ProcessingError(format!(
+                "sentence boundary {} is not a character boundary in text of {} bytes",
+                bad,
+                text.len()
+            )));
+        }
+        let mut ends = boundaries.to_vec();
+        ends.sort_unstable();
+        ends.dedup();
+
+        let mut iter = self.chunk_iter(text, config);
+        iter.boundaries = Some(ends);
+        Ok(Chunk::link_sequence(iter.collect()))
+    }
+
+    /// Pack sentences found by `segmenter`, which returns sentence boundary
+    /// byte offsets for the text it is given.
+    pub fn chunk_with_segmenter(
+        &self,
+        text: &str,
+        config: &ChunkConfig,
+        segmenter: impl FnOnce(&str) -> Vec<usize>,
+    ) -> Result<Vec<Chunk>, ChunkError> {
+        let boundaries = segmenter(text);
+        self.chunk_with_boundaries(text, &boundaries, config)
+    }
+
+    /// Split text into (start, sentence) pairs with the configured detector.
+    pub(crate) fn split<'t>(text: &'t str, config: &ChunkConfig) -> Vec<(usize, &'t str)> {
+        let mut sentences = Vec::new();
+        let mut pos = 0;
+        while let Some((start, sentence, next)) = Self::next_sentence(text, pos, config) {
+            sentences.push((start, sentence));
+            pos = next;
+        }
         sentences
     }
+
+    /// Lazily yield sentence-packed chunks, detecting sentences on demand.
+    ///
+    /// `text` may be borrowed (`&str`) or owned (`String`).
+    pub fn chunk_iter<S: AsRef<str>>(&self, text: S, config: &ChunkConfig) -> SentenceIter<S> {
+        SentenceIter {
+            text,
+            config: config.clone(),
+            pos: 0,
+            current_text: String::new(),
+            current_start: 0,
+            current_end: 0,
+            emitted_end: 0,
+            boundaries: None,
+            current_count: 0,
+            plan: None,
+        }
+    }
 }
 
-impl ChunkAlgorithm for SentenceChunker {
-    fn chunk(&self, text: &str, config: &ChunkConfig) -> Vec<Chunk> {
-        if text.is_empty() {
-            return Vec::new();
+/// Update `open`, the stack of pending closers, for the character `c` at byte `i`.
+///
+/// `"`, `'`, `(` and `[` open enclosures; a quote closes the innermost matching
+/// quote instead when one is open. An apostrophe inside or at the end of a
+/// word ("don't", "dogs'") is not treated as an opening quote.
+fn track_enclosure(text: &str, i: usize, c: char, open: &mut Vec<char>) {
+    match c {
+        '(' => open.push(')'),
+        '[' => open.push(']'),
+        ')' | ']' if open.last() == Some(&c) => {
+            open.pop();
+        }
+        '"' | '\'' => {
+            let after_word = text[..i]
+                .chars()
+                .next_back()
+                .is_some_and(char::is_alphanumeric);
+            let before_word = text[i + 1..]
+                .chars()
+                .next()
+                .is_some_and(char::is_alphanumeric);
+            if c == '\'' && after_word && before_word {
+                return;
+            }
+            if let Some(depth) = open.iter().rposition(|&closer| closer == c) {
+                open.truncate(depth);
+            } else if c == '"' || !after_word {
+                open.push(c);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Check whether every enclosure in `open` closes between `pos` and the end of
+/// the paragraph (the next blank line).
+fn closes_in_paragraph(text: &str, pos: usize, mut open: Vec<char>) -> bool {
+    let end = text[pos..].find("\n\n").map_or(text.len(), |i| pos + i);
+    for (i, c) in text[pos..end].char_indices() {
+        track_enclosure(text, pos + i, c, &mut open);
+        if open.is_empty() {
+            return true;
         }
+    }
+    false
+}
+
+/// Iterator returned by [`SentenceChunker::chunk_iter`].
+pub struct SentenceIter<S> {
+    text: S,
+    config: ChunkConfig,
+    pos: usize,
+    current_text: String,
+    current_start: usize,
+    /// End of the last pending sentence in `text`.
+    current_end: usize,
+    /// End of the last emitted chunk, where a `preserve_separators` chunk starts.
+    emitted_end: usize,
+    /// Sorted external sentence end offsets, replacing the configured detector.
+    boundaries: Option<Vec<usize>>,
+    /// Number of pending sentences.
+    current_count: usize,
+    /// Sentences per remaining chunk when `config.balanced` is set, planned
+    /// over the whole text on the first call.
+    plan: Option<VecDeque<usize>>,
+}
 
-        let sentences = match config.sentence_detector {
-            SentenceDetector::Regex => Self::split_regex(text),
-            SentenceDetector::Unicode => Self::split_unicode(text),
+impl<S: AsRef<str>> SentenceIter<S> {
+    /// Take the pending sentences as a chunk, followed by a chunk starting at `next_start`.
+    ///
+    /// With `preserve_separators` the chunk is the source slice from the end
+    /// of the previous chunk to `next_start`, whitespace included.
+    fn flush(&mut self, next_start: usize) -> Chunk {
+        let joined = std::mem::take(&mut self.current_text);
+        let (text, start, end) = if self.config.preserve_separators {
+            let start = self.emitted_end;
+            let source = &self.text.as_ref()[start..next_start];
+            (source.to_string(), start, next_start)
+        } else {
+            (joined, self.current_start, self.current_end)
         };
+        self.emitted_end = end;
+        self.current_count = 0;
+        if let Some(plan) = &mut self.plan {
+            plan.pop_front();
+        }
+        let metadata = ChunkMetadata::for_method(SentenceChunker.name());
+        Chunk::with_uuid(text, start, end, metadata)
+    }
+}
 
-        let mut chunks = Vec::new();
-        let mut current_text = String::new();
-        let mut current_start = 0;
-        let mut chunk_start_set = false;
+impl<S: AsRef<str>> SentenceIter<S> {
+    /// Find the next sentence at or after `pos` as `(start, sentence, next)`.
+    fn next_span<'t>(&self, text: &'t str, pos: usize) -> Option<(usize, &'t str, usize)> {
+        match &self.boundaries {
+            Some(ends) => SentenceChunker::next_at_boundaries(text, pos, ends),
+            None => SentenceChunker::next_sentence(text, pos, &self.config),
+        }
+    }
 
-        for (start, _end, sentence) in sentences {
-            // Check if adding this sentence would exceed max_size
-            let potential_len = if current_text.is_empty() {
-                sentence.len()
-            } else {
-                current_text.len() + 1 + sentence.len() // +1 for space
-            };
+    /// Plan how many sentences go in each chunk so chunk sizes are balanced.
+    fn balanced_plan(&self) -> VecDeque<usize> {
+        let text = self.text.as_ref();
+        let mut sizes = Vec::new();
+        let mut pos = 0;
+        while let Some((_, sentence, next)) = self.next_span(text, pos) {
+            sizes.push(self.config.measure(sentence));
+            pos = next;
+        }
+        balanced_partition(&sizes, self.config.measure(" "), self.config.size_limit()).into()
+    }
 
-            if potential_len > config.max_size && !current_text.is_empty() {
-                // Flush current chunk
-                let metadata = ChunkMetadata {
-                    method: self.name().to_string(),
-                    section: None,
-                    overlap_chars: None,
-                    parent_chunk_id: None,
-                };
-                chunks.push(Chunk::with_uuid(
-                    current_text.clone(),
-                    current_start,
-                    current_start + current_text.len(),
-                    metadata,
-                ));
-
-                // Start new chunk
-                current_text = sentence.to_string();
-                current_start = start;
-                chunk_start_set = true;
-            } else {
-                if !chunk_start_set {
-                    current_start = start;
-                    chunk_start_set = true;
-                }
-                if current_text.is_empty() {
-                    current_text = sentence.to_string();
-                } else {
-                    current_text.push(' ');
-                    current_text.push_str(sentence);
-                }
+    /// Whether the pending sentences are a full chunk before adding `sentence`.
+    fn is_full(&self, sentence: &str) -> bool {
+        match &self.plan {
+            Some(plan) => plan.front().is_some_and(|&len| self.current_count >= len),
+            None => {
+                self.config.joined_size(&self.current_text, " ", sentence)
+                    > self.config.size_limit()
             }
         }
+    }
+}
 
-        // Flush remaining text
-        if !current_text.is_empty() {
-            let metadata = ChunkMetadata {
-                method: self.name().to_string(),
-                section: None,
-                overlap_chars: None,
-                parent_chunk_id: None,
+impl<S: AsRef<str>> Iterator for SentenceIter<S> {
+    type Item = Chunk;
+
+    fn next(&mut self) -> Option<Chunk> {
+        if self.config.balanced && self.plan.is_none() {
+            self.plan = Some(self.balanced_plan());
+        }
+        loop {
+            let text = self.text.as_ref();
+            let Some((start, sentence, next)) = self.next_span(text, self.pos) else {
+                // Flush remaining text
+                self.pos = self.text.as_ref().len();
+                return (!self.current_text.is_empty()).then(|| self.flush(self.pos));
             };
-            chunks.push(Chunk::with_uuid(
-                current_text.clone(),
-                current_start,
-                current_start + current_text.len(),
-                metadata,
-            ));
+            self.pos = next;
+            let end = start + sentence.len();
+
+            if self.current_text.is_empty() {
+                self.current_text = sentence.to_string();
+                self.current_start = start;
+                self.current_end = end;
+                self.current_count = 1;
+            } else if self.is_full(sentence) {
+                // Adding this sentence would exceed max_size: start a new chunk
+                let sentence = sentence.to_string();
+                let chunk = self.flush(start);
+                self.current_text = sentence;
+                self.current_start = start;
+                self.current_end = end;
+                self.current_count = 1;
+                return Some(chunk);
+            } else {
+                self.current_text.push(' '); // +1 for space
+                self.current_text.push_str(sentence);
+                self.current_end = end;
+                self.current_count += 1;
+            }
         }
+    }
+}
 
-        chunks
+impl ChunkAlgorithm for SentenceChunker {
+    fn chunk(&self, text: &str, config: &ChunkConfig) -> Vec<Chunk> {
+        Chunk::link_sequence(self.chunk_iter(text, config).collect())
+    }
+
+    fn chunk_finalized_iter(
+        &self,
+        text: String,
+        config: &ChunkConfig,
+    ) -> Result<Box<dyn Iterator<Item = Chunk> + Send + Sync>, ChunkError> {
+        Ok(Box::new(Chunk::finalize_iter(
+            self.chunk_iter(text, config),
+            config.clone(),
+        )))
     }
 
     fn name(&self) -> &str {
@@ -180,4 +616,467 @@ mod tests {
 
         assert!(chunks.is_empty());
     }
+
+    #[test]
+    fn test_sentence_unicode_matches_segmentation() {
+        let text = "Mr. Smith left.  \"Really?\" she asked!\n\nPi is 3.14. Done";
+        let expected: Vec<&str> = text
+            .split_sentence_bounds()
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .collect();
+        let sentences: Vec<&str> = SentenceChunker::split(
+            text,
+            &ChunkConfig::default().with_sentence_detector(SentenceDetector::Unicode),
+        )
+        .into_iter()
+        .map(|(start, sentence)| {
+            assert!(text[start..].starts_with(sentence));
+            sentence
+        })
+        .collect();
+
+        assert_eq!(sentences, expected);
+    }
+
+    #[test]
+    fn test_sentence_iter_is_lazy() {
+        let chunker = SentenceChunker;
+        let config = ChunkConfig::new(20);
+        let text = "One short line. ".repeat(100_000);
+        let first: Vec<Chunk> = chunker.chunk_iter(text.as_str(), &config).take(2).collect();
+
+        assert_eq!(first[0].text, "One short line.");
+        assert_eq!(first[1].start, 16);
+
+        // Owned text lets the iterator outlive the original borrow
+        let owned_text = text[..48].to_string();
+        let owned: Vec<Chunk> = chunker.chunk_iter(owned_text, &config).collect();
+        assert_eq!(owned.len(), 3);
+    }
+
+    #[test]
+    fn test_sentence_smart_abbreviations() {
+        let chunker = SentenceChunker;
+        let text = "Dr. Smith went to Washington D.C. He arrived at 3.5 p.m.";
+        let texts = |config: &ChunkConfig| {
+            chunker
+                .chunk(text, config)
+                .into_iter()
+                .map(|c| c.text)
+                .collect::<Vec<_>>()
+        };
+
+        let smart = ChunkConfig::new(1).with_sentence_detector(SentenceDetector::Smart);
+        assert_eq!(
+            texts(&smart),
+            vec![
+                "Dr. Smith went to Washington D.C.",
+                "He arrived at 3.5 p.m."
+            ]
+        );
+
+        // The regex detector is unchanged and still splits after "Dr."
+        assert_eq!(texts(&ChunkConfig::new(1))[0], "Dr.");
+    }
+
+    #[test]
+    fn test_sentence_smart_chunk_boundaries() {
+        let chunker = SentenceChunker;
+        let config = ChunkConfig::new(20).with_sentence_detector(SentenceDetector::Smart);
+        let text = "Mr. Jones left. Dr. Who came. J. R. R. Tolkien wrote.";
+        let chunks = chunker.chunk(text, &config);
+
+        let texts: Vec<&str> = chunks.iter().map(|c| c.text.as_str()).collect();
+        assert_eq!(
+            texts,
+            vec![
+                "Mr. Jones left.",
+                "Dr. Who came.",
+                "J. R. R. Tolkien wrote."
+            ]
+        );
+        for chunk in &chunks {
+            assert_eq!(&text[chunk.start..chunk.end], chunk.text);
+        }
+    }
+
+    #[test]
+    fn test_sentence_smart_custom_abbreviations() {
+        let chunker = SentenceChunker;
+        let config = ChunkConfig::new(1)
+            .with_sentence_detector(SentenceDetector::Smart)
+            .with_abbreviations(["approx."]);
+        let chunks = chunker.chunk("It is approx. five km. Ask Dr. Lee.", &config);
+
+        let texts: Vec<&str> = chunks.iter().map(|c| c.text.as_str()).collect();
+        assert_eq!(texts, vec!["It is approx. five km.", "Ask Dr.", "Lee."]);
+    }
+
+    #[test]
+    fn test_sentence_cjk_terminators() {
+        let config = ChunkConfig::default();
+        let sentences = |text| {
+            SentenceChunker::split(text, &config)
+                .into_iter()
+                .map(|(_, s)| s)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            sentences("これは一文目です。これは二文目です。"),
+            vec!["これは一文目です。", "これは二文目です。"]
+        );
+        assert_eq!(
+            sentences("「本当？」と聞いた。你好！再见．"),
+            vec!["「本当？」", "と聞いた。", "你好！", "再见．"]
+        );
+        // Commas (、) do not end a sentence, and ASCII behavior is unchanged
+        assert_eq!(sentences("一つ、二つ。"), vec!["一つ、二つ。"]);
+        assert_eq!(
+            sentences("Hi there. 3.5 is fine!"),
+            vec!["Hi there.", "3.5 is fine!"]
+        );
+    }
+
+    #[test]
+    fn test_sentence_cjk_chunking() {
+        let chunker = SentenceChunker;
+        let config = ChunkConfig::new(30);
+        let text = "これは一文目です。これは二文目です。";
+        let chunks = chunker.chunk(text, &config);
+
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[1].text, "これは二文目です。");
+        assert_eq!(&text[chunks[1].start..chunks[1].end], chunks[1].text);
+    }
+
+    #[test]
+    fn test_sentence_multilingual() {
+        let config = ChunkConfig::default().with_sentence_detector(SentenceDetector::Multilingual);
+        let sentences = |text| {
+            SentenceChunker::split(text, &config)
+                .into_iter()
+                .map(|(_, s)| s)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            sentences("今日は晴れです。明日は雨でしょう…本当？"),
+            vec!["今日は晴れです。", "明日は雨でしょう…", "本当？"]
+        );
+        assert_eq!(
+            sentences("We met in Tokyo. 東京は大きい。It was fun! また行きたい"),
+            vec![
+                "We met in Tokyo.",
+                "東京は大きい。",
+                "It was fun!",
+                "また行きたい"
+            ]
+        );
+        assert_eq!(
+            sentences("यह पहला वाक्य है।यह दूसरा है।"),
+            vec!["यह पहला वाक्य है।", "यह दूसरा है।"]
+        );
+        // No punctuation at all: Unicode segmentation decides
+        let thai = "สวัสดีครับ ผมชื่อสมชาย";
+        assert_eq!(
+            SentenceChunker::split(thai, &config),
+            SentenceChunker::split(
+                thai,
+                &ChunkConfig::default().with_sentence_detector(SentenceDetector::Unicode)
+            )
+        );
+    }
+
+    #[test]
+    fn test_sentence_multilingual_offsets() {
+        let chunker = SentenceChunker;
+        let config = ChunkConfig::new(1).with_sentence_detector(SentenceDetector::Multilingual);
+        let text = "  Hello there. こんにちは。元気？  Fine…thanks\n\nสวัสดี ";
+        let chunks = chunker.chunk(text, &config);
+
+        assert_eq!(chunks.len(), 6);
+        assert_eq!(chunks[1].text, "こんにちは。");
+        for chunk in &chunks {
+            assert!(text.is_char_boundary(chunk.start) && text.is_char_boundary(chunk.end));
+            assert_eq!(&text[chunk.start..chunk.end], chunk.text);
+        }
+    }
+
+    #[test]
+    fn test_sentence_smart_decimals_and_ellipses() {
+        let config = ChunkConfig::default().with_sentence_detector(SentenceDetector::Smart);
+        let sentences = |text| {
+            SentenceChunker::split(text, &config)
+                .into_iter()
+                .map(|(_, s)| s)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            sentences("Dr. Smith went to Washington. He left."),
+            vec!["Dr. Smith went to Washington.", "He left."]
+        );
+        assert_eq!(
+            sentences("Pi is 3.14 roughly. 円周率は3．14です。"),
+            vec!["Pi is 3.14 roughly.", "円周率は3．14です。"]
+        );
+        assert_eq!(
+            sentences("Use a tool, e.g. this continues. Next one."),
+            vec!["Use a tool, e.g. this continues.", "Next one."]
+        );
+        assert_eq!(
+            sentences("Well... maybe later. Wait... Then go."),
+            vec!["Well... maybe later.", "Wait...", "Then go."]
+        );
+    }
+
+    #[test]
+    fn test_sentence_respect_enclosures() {
+        let text = "He said \"Go now. Run.\" and left. She (who knew. Mr. X) stayed. Don't stop.";
+        let split = |config: &ChunkConfig| {
+            SentenceChunker::split(text, config)
+                .into_iter()
+                .map(|(_, s)| s)
+                .collect::<Vec<_>>()
+        };
+
+        let default = ChunkConfig::default();
+        assert_eq!(split(&default).len(), 6);
+
+        for detector in [
+            SentenceDetector::Regex,
+            SentenceDetector::Unicode,
+            SentenceDetector::Smart,
+        ] {
+            let config = ChunkConfig::default()
+                .with_sentence_detector(detector)
+                .with_respect_enclosures(true);
+            assert_eq!(
+                split(&config),
+                vec![
+                    "He said \"Go now. Run.\" and left.",
+                    "She (who knew. Mr. X) stayed.",
+                    "Don't stop."
+                ],
+                "{:?}",
+                detector
+            );
+        }
+    }
+
+    #[test]
+    fn test_sentence_unbalanced_enclosure_falls_back() {
+        let config = ChunkConfig::default().with_respect_enclosures(true);
+        let text = "He said \"Go now. Run. Then stop.\n\nNew \"paragraph.\"";
+        let sentences = SentenceChunker::split(text, &config);
+
+        assert_eq!(
+            sentences.iter().map(|(_, s)| *s).collect::<Vec<_>>(),
+            vec![
+                "He said \"Go now.",
+                "Run.",
+                "Then stop.",
+                "New \"paragraph.\""
+            ]
+        );
+        for (start, sentence) in sentences {
+            assert_eq!(&text[start..start + sentence.len()], sentence);
+        }
+    }
+
+    #[test]
+    fn test_sentence_custom_terminators() {
+        let text = "यह पहला वाक्य है। यह दूसरा है। Done. Still one";
+        let split = |config: &ChunkConfig| {
+            SentenceChunker::split(text, config)
+                .into_iter()
+                .map(|(_, s)| s)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(split(&ChunkConfig::default()).len(), 2);
+
+        let danda = ChunkConfig::default().with_sentence_terminators(['।', '.']);
+        assert_eq!(
+            split(&danda),
+            vec!["यह पहला वाक्य है।", "यह दूसरा है।", "Done.", "Still one"]
+        );
+        let smart = danda
+            .clone()
+            .with_sentence_detector(SentenceDetector::Smart);
+        assert_eq!(split(&smart), split(&danda));
+
+        // Regex metacharacters are matched literally
+        let caret = ChunkConfig::default().with_sentence_terminators(['^', ']']);
+        assert_eq!(
+            SentenceChunker::split("a^ b] c.", &caret)
+                .into_iter()
+                .map(|(_, s)| s)
+                .collect::<Vec<_>>(),
+            vec!["a^", "b]", "c."]
+        );
+    }
+
+    #[test]
+    fn test_sentence_external_boundaries() {
+        let text = "one two three four five";
+        let config = ChunkConfig::new(9).with_sentence_detector(SentenceDetector::Custom);
+
+        // Unsorted, repeated offsets after "two" and "three"
+        let chunks = SentenceChunker
+            .chunk_with_boundaries(text, &[13, 7, 13], &config)
+            .unwrap();
+        let texts: Vec<_> = chunks.iter().map(|c| c.text.as_str()).collect();
+        assert_eq!(texts, vec!["one two", "three", "four five"]);
+        for chunk in &chunks {
+            assert_eq!(&text[chunk.start..chunk.end], chunk.text);
+        }
+
+        let chunks = SentenceChunker
+            .chunk_with_segmenter(text, &config, |t| vec![t.find("five").unwrap()])
+            .unwrap();
+        assert_eq!(chunks.last().unwrap().text, "five");
+    }
+
+    #[test]
+    fn test_sentence_external_boundaries_invalid() {
+        let config = ChunkConfig::default();
+        assert!(matches!(
+            SentenceChunker.chunk_with_boundaries("héllo", &[2], &config),
+            Err(ChunkError::ProcessingError(_))
+        ));
+        assert!(SentenceChunker
+            .chunk_with_boundaries("hi", &[3], &config)
+            .is_err());
+    }
+
+    #[test]
+    fn test_sentence_unicode_repeated_offsets() {
+        let config = ChunkConfig::default().with_sentence_detector(SentenceDetector::Unicode);
+        // UAX #29 does not break before a lowercase word, so the repeats are capitalized
+        for text in ["Go. Go. Go.", "  Go.\u{3000} Go.\n\n Go go."] {
+            let sentences = SentenceChunker::split(text, &config);
+            assert_eq!(sentences.len(), 3, "{:?}", text);
+            for (start, sentence) in sentences {
+                assert_eq!(&text[start..start + sentence.len()], sentence);
+            }
+        }
+
+        let chunks = SentenceChunker.chunk(
+            "Go. Go. Go.",
+            &ChunkConfig::new(3).with_sentence_detector(SentenceDetector::Unicode),
+        );
+        let starts: Vec<_> = chunks.iter().map(|c| c.start).collect();
+        assert_eq!(starts, vec![0, 4, 8]);
+    }
+
+    #[test]
+    fn test_sentence_unicode_offsets_with_whitespace() {
+        let config = ChunkConfig::new(1).with_sentence_detector(SentenceDetector::Unicode);
+        let words = ["Go.", "Stop here!", "Go again?", "Go."];
+        let separators = [
+            " ",
+            "   ",
+            "\t",
+            "\t \t",
+            "\u{a0}",
+            "\u{a0} \u{a0}",
+            "\n\n  ",
+            "\r\n\t",
+        ];
+
+        // Every pairing of separator and padding, each doc ending in trailing whitespace
+        for (i, lead) in separators.iter().enumerate() {
+            for trail in &separators[i..] {
+                let mut text = lead.to_string();
+                for word in words {
+                    text.push_str(word);
+                    text.push_str(trail);
+                }
+
+                let sentences = SentenceChunker::split(&text, &config);
+                assert_eq!(sentences.len(), words.len(), "{:?}", text);
+                for ((start, sentence), word) in sentences.into_iter().zip(words) {
+                    assert_eq!(sentence, word, "{:?}", text);
+                    assert_eq!(&text[start..start + sentence.len()], sentence);
+                }
+                for chunk in SentenceChunker.chunk(&text, &config) {
+                    assert_eq!(&text[chunk.start..chunk.end], chunk.text, "{:?}", text);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_sentence_end_is_source_offset() {
+        let text = "One here.   Two here.\n\n  Three here.\tFour.";
+        let chunks = SentenceChunker.chunk(text, &ChunkConfig::new(25));
+        let texts: Vec<&str> = chunks.iter().map(|c| c.text.as_str()).collect();
+        assert_eq!(texts, vec!["One here. Two here.", "Three here. Four."]);
+
+        // The joined text is shorter than the source span it came from
+        assert_eq!((chunks[0].start, chunks[0].end), (0, 21));
+        assert_eq!(chunks[1].end, text.len());
+        for chunk in &chunks {
+            let source = &text[chunk.start..chunk.end];
+            assert!(chunk.text.split(' ').all(|word| source.contains(word)));
+            assert!(source.ends_with(chunk.text.rsplit(' ').next().unwrap()));
+        }
+    }
+
+    #[test]
+    fn test_sentence_preserve_separators() {
+        let text = "  One here.   Two here.\n\n  Three here.\tFour.  ";
+        let config = ChunkConfig::new(25).with_preserve_separators(true);
+        let chunks = SentenceChunker.chunk(text, &config);
+        let texts: Vec<&str> = chunks.iter().map(|c| c.text.as_str()).collect();
+        assert_eq!(
+            texts,
+            vec!["  One here.   Two here.\n\n  ", "Three here.\tFour.  "]
+        );
+        assert_eq!(texts.concat(), text);
+        assert_eq!((chunks[1].start, chunks[1].end), (27, text.len()));
+
+        let lazy: Vec<String> = SentenceChunker
+            .chunk_iter(text, &config)
+            .map(|c| c.text)
+            .collect();
+        assert_eq!(lazy, texts);
+    }
+
+    #[test]
+    fn test_sentence_balanced() {
+        let text = "One fish. Two fish. Red fish. Big fish. Old fish. New fish. Fat fish.";
+        let spread = |chunks: &[Chunk]| {
+            let sizes = chunks.iter().map(|c| c.text.len());
+            sizes.clone().max().unwrap() - sizes.min().unwrap()
+        };
+        let greedy = SentenceChunker.chunk(text, &ChunkConfig::new(29));
+        let config = ChunkConfig::new(29).with_balanced(true);
+        let balanced = SentenceChunker.chunk(text, &config);
+
+        let sizes: Vec<usize> = greedy.iter().map(|c| c.text.len()).collect();
+        assert_eq!(sizes, vec![29, 29, 9]);
+        let sizes: Vec<usize> = balanced.iter().map(|c| c.text.len()).collect();
+        assert_eq!(sizes, vec![29, 19, 19]);
+        assert!(spread(&balanced) < spread(&greedy));
+        for chunk in &balanced {
+            assert_eq!(&text[chunk.start..chunk.end], chunk.text);
+        }
+
+        let lazy: Vec<String> = SentenceChunker
+            .chunk_iter(text, &config)
+            .map(|c| c.text)
+            .collect();
+        assert_eq!(
+            lazy,
+            vec![
+                "One fish. Two fish. Red fish.",
+                "Big fish. Old fish.",
+                "New fish. Fat fish."
+            ]
+        );
+    }
 }
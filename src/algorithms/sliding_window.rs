@@ -1,61 +1,176 @@
 //! Sliding window chunking algorithm with overlap.
 
+use crate::algorithms::SentenceChunker;
 use crate::chunk::{Chunk, ChunkMetadata};
-use crate::config::ChunkConfig;
+use crate::config::{ChunkConfig, WindowUnit};
+use crate::error::ChunkError;
 use crate::traits::ChunkAlgorithm;
+use std::collections::VecDeque;
 
 /// Sliding window chunker that creates overlapping chunks.
 pub struct SlidingWindowChunker;
 
-impl ChunkAlgorithm for SlidingWindowChunker {
-    fn chunk(&self, text: &str, config: &ChunkConfig) -> Vec<Chunk> {
-        if text.is_empty() || config.max_size == 0 {
-            return Vec::new();
-        }
+impl SlidingWindowChunker {
+    /// Lazily yield overlapping chunks without materializing the whole vector.
+    ///
+    /// `max_size` and `overlap` count characters, or whole sentences when
+    /// `config.window_unit` is [`WindowUnit::Sentences`]. Chunk `start`/`end`
+    /// are byte offsets into `text` either way. `text` may be borrowed or owned.
+    pub fn chunk_iter<S: AsRef<str>>(&self, text: S, config: &ChunkConfig) -> SlidingWindowIter<S> {
+        let max_size = config.max_size;
+        let overlap = config.overlap.min(max_size.saturating_sub(1));
+        let step = max_size.saturating_sub(overlap);
+        let done = text.as_ref().is_empty() || max_size == 0 || step == 0;
 
-        let overlap = config.overlap.min(config.max_size.saturating_sub(1));
-        let step = config.max_size.saturating_sub(overlap);
-
-        if step == 0 {
-            return Vec::new();
+        SlidingWindowIter {
+            text,
+            max_size,
+            step,
+            start_byte: 0,
+            prev_end: 0,
+            done,
+            sentences: (config.window_unit == WindowUnit::Sentences).then(|| SentenceWindow {
+                config: config.clone(),
+                pos: 0,
+                window: VecDeque::new(),
+            }),
         }
+    }
+}
 
-        let mut chunks = Vec::new();
-        let chars: Vec<char> = text.chars().collect();
-        let mut start_char_idx = 0;
+/// State for windows measured in sentences.
+struct SentenceWindow {
+    /// Config used to detect sentences.
+    config: ChunkConfig,
+    /// Byte offset to resume sentence detection from.
+    pos: usize,
+    /// Byte spans of the sentences in the current window.
+    window: VecDeque<(usize, usize)>,
+}
 
-        while start_char_idx < chars.len() {
-            let end_char_idx = (start_char_idx + config.max_size).min(chars.len());
-            let chunk_text: String = chars[start_char_idx..end_char_idx].iter().collect();
+/// Iterator returned by [`SlidingWindowChunker::chunk_iter`].
+pub struct SlidingWindowIter<S> {
+    text: S,
+    max_size: usize,
+    step: usize,
+    start_byte: usize,
+    /// End byte of the previous window, to measure what it shares with the next one.
+    prev_end: usize,
+    done: bool,
+    sentences: Option<SentenceWindow>,
+}
 
-            // Calculate byte positions
-            let start_byte = chars[..start_char_idx].iter().map(|c| c.len_utf8()).sum();
-            let end_byte = start_byte + chunk_text.len();
+impl<S: AsRef<str>> SlidingWindowIter<S> {
+    /// Fetch the span of the next sentence, if any.
+    fn next_span(&mut self) -> Option<(usize, usize)> {
+        let state = self.sentences.as_mut()?;
+        let (start, sentence, next) =
+            SentenceChunker::next_sentence(self.text.as_ref(), state.pos, &state.config)?;
+        state.pos = next;
+        Some((start, start + sentence.len()))
+    }
 
-            // Calculate actual overlap for this chunk
-            let actual_overlap = if start_char_idx > 0 {
-                Some(overlap)
-            } else {
-                None
-            };
+    /// Characters the window `start..end` shares with the previous window.
+    ///
+    /// Records `end` for the next call. `None` for the first window.
+    fn shared_chars(&mut self, start: usize, end: usize) -> Option<usize> {
+        let prev_end = std::mem::replace(&mut self.prev_end, end);
+        (prev_end > start).then(|| self.text.as_ref()[start..prev_end.min(end)].chars().count())
+    }
 
-            let metadata = ChunkMetadata {
-                method: self.name().to_string(),
-                section: None,
-                overlap_chars: actual_overlap,
-                parent_chunk_id: None,
+    /// Yield the next window of `max_size` sentences, sharing `overlap` with the previous one.
+    fn next_sentence_window(&mut self) -> Option<Chunk> {
+        while self.sentences.as_ref()?.window.len() < self.max_size {
+            let Some(span) = self.next_span() else {
+                self.done = true;
+                break;
             };
+            self.sentences.as_mut()?.window.push_back(span);
+        }
 
-            chunks.push(Chunk::with_uuid(chunk_text, start_byte, end_byte, metadata));
+        let state = self.sentences.as_mut()?;
+        let (&(start, _), &(_, end)) = (state.window.front()?, state.window.back()?);
+        state.window.drain(..self.step.min(state.window.len()));
+        let overlap_chars = self.shared_chars(start, end);
 
-            // Move to next position
-            if end_char_idx >= chars.len() {
-                break;
+        let metadata = ChunkMetadata {
+            overlap_chars,
+            ..ChunkMetadata::for_method(SlidingWindowChunker.name())
+        };
+        let text = self.text.as_ref();
+        let chunk = Chunk::with_uuid(text[start..end].to_string(), start, end, metadata);
+
+        // Stop once no sentence follows the ones this window already covered
+        if !self.done {
+            match self.next_span() {
+                Some(span) => self.sentences.as_mut()?.window.push_back(span),
+                None => self.done = true,
             }
-            start_char_idx += step;
+        }
+        Some(chunk)
+    }
+}
+
+impl<S: AsRef<str>> Iterator for SlidingWindowIter<S> {
+    type Item = Chunk;
+
+    fn next(&mut self) -> Option<Chunk> {
+        if self.done {
+            return None;
+        }
+        if self.sentences.is_some() {
+            return self.next_sentence_window();
         }
 
-        chunks
+        let start_byte = self.start_byte;
+        let end_byte = self.text.as_ref()[start_byte..]
+            .char_indices()
+            .nth(self.max_size)
+            .map_or(self.text.as_ref().len(), |(idx, _)| start_byte + idx);
+        let overlap_chars = self.shared_chars(start_byte, end_byte);
+
+        let text = self.text.as_ref();
+        let window = &text[start_byte..];
+        let metadata = ChunkMetadata {
+            overlap_chars,
+            ..ChunkMetadata::for_method(SlidingWindowChunker.name())
+        };
+        let chunk = Chunk::with_uuid(
+            text[start_byte..end_byte].to_string(),
+            start_byte,
+            end_byte,
+            metadata,
+        );
+
+        // Move to next position; a window that reaches the end is the last
+        // one, so no trailing window is ever contained in its predecessor
+        if end_byte >= text.len() {
+            self.done = true;
+        } else {
+            self.start_byte = window
+                .char_indices()
+                .nth(self.step)
+                .map_or(text.len(), |(idx, _)| start_byte + idx);
+        }
+        Some(chunk)
+    }
+}
+
+impl ChunkAlgorithm for SlidingWindowChunker {
+    fn chunk(&self, text: &str, config: &ChunkConfig) -> Vec<Chunk> {
+        // Byte offsets are tracked incrementally by the iterator, so this is linear in text length
+        Chunk::link_sequence(self.chunk_iter(text, config).collect())
+    }
+
+    fn chunk_finalized_iter(
+        &self,
+        text: String,
+        config: &ChunkConfig,
+    ) -> Result<Box<dyn Iterator<Item = Chunk> + Send + Sync>, ChunkError> {
+        Ok(Box::new(Chunk::finalize_iter(
+            self.chunk_iter(text, config),
+            config.clone(),
+        )))
     }
 
     fn name(&self) -> &str {
@@ -107,6 +222,49 @@ mod tests {
         assert_eq!(chunks[1].metadata.overlap_chars, Some(2));
     }
 
+    #[test]
+    fn test_sliding_window_final_window() {
+        let chunker = SlidingWindowChunker;
+        let spans = |chunks: Vec<Chunk>| {
+            chunks
+                .into_iter()
+                .map(|c| (c.text, c.metadata.overlap_chars))
+                .collect::<Vec<_>>()
+        };
+
+        // "ld!" shares exactly "ld" with "world"
+        let chunks = chunker.chunk("hello world!", &ChunkConfig::new(5).with_overlap(2));
+        assert_eq!(
+            spans(chunks),
+            vec![
+                ("hello".to_string(), None),
+                ("lo wo".to_string(), Some(2)),
+                ("world".to_string(), Some(2)),
+                ("ld!".to_string(), Some(2)),
+            ]
+        );
+        let chunks = chunker.chunk("hello world!", &ChunkConfig::new(5).with_overlap(2));
+        let last = chunks.last().unwrap();
+        let prev = &chunks[chunks.len() - 2];
+        assert_eq!(last.overlap_text().as_deref(), Some("ld"));
+        assert_eq!(&"hello world!"[last.start..prev.end], "ld");
+        assert_eq!(chunks[0].overlap_text(), None);
+
+        // The second window reaches the end; a third would be redundant
+        let chunks = chunker.chunk("abcdef", &ChunkConfig::new(5).with_overlap(4));
+        assert_eq!(
+            spans(chunks),
+            vec![("abcde".to_string(), None), ("bcdef".to_string(), Some(4))]
+        );
+
+        // Overlap is clamped to max_size - 1
+        let chunks = chunker.chunk("abcdef", &ChunkConfig::new(3).with_overlap(5));
+        assert!(chunks[1..]
+            .iter()
+            .all(|c| c.metadata.overlap_chars == Some(2)));
+        assert_eq!(chunks.last().unwrap().text, "def");
+    }
+
     #[test]
     fn test_sliding_window_empty() {
         let chunker = SlidingWindowChunker;
@@ -115,4 +273,148 @@ mod tests {
 
         assert!(chunks.is_empty());
     }
+
+    #[test]
+    fn test_sliding_window_iter_matches_chunk() {
+        let chunker = SlidingWindowChunker;
+        let config = ChunkConfig::new(5).with_overlap(2);
+        let text = "日本語のテキスト with overlap";
+        let eager = chunker.chunk(text, &config);
+        let lazy: Vec<Chunk> = chunker.chunk_iter(text, &config).collect();
+
+        assert_eq!(eager.len(), lazy.len());
+        for (a, b) in eager.iter().zip(lazy.iter()) {
+            assert_eq!(a.text, b.text);
+            assert_eq!(a.start, b.start);
+            assert_eq!(a.end, b.end);
+            assert_eq!(a.metadata.overlap_chars, b.metadata.overlap_chars);
+        }
+    }
+
+    #[test]
+    fn test_sliding_window_multibyte_overlap() {
+        let chunker = SlidingWindowChunker;
+        let config = ChunkConfig::new(3).with_overlap(2);
+        let text = "aé日本🙂b";
+        let chunks = chunker.chunk(text, &config);
+
+        let texts: Vec<&str> = chunks.iter().map(|c| c.text.as_str()).collect();
+        assert_eq!(texts, vec!["aé日", "é日本", "日本🙂", "本🙂b"]);
+        for chunk in &chunks {
+            assert_eq!(&text[chunk.start..chunk.end], chunk.text);
+        }
+        // The overlap "é日" spans 5 bytes but is reported as 2 characters
+        assert_eq!(chunks[1].start, 1);
+        assert_eq!(chunks[1].metadata.overlap_chars, Some(2));
+    }
+
+    #[test]
+    fn test_sliding_window_large_output() {
+        let chunker = SlidingWindowChunker;
+        let config = ChunkConfig::new(1000).with_overlap(100);
+        let small = "日本語 text ".repeat(100_000); // ~1.3 MB
+        let large = small.repeat(8); // ~10 MB
+
+        let small_chunks = chunker.chunk(&small, &config);
+        let large_chunks = chunker.chunk(&large, &config);
+
+        assert_eq!(large_chunks.last().unwrap().end, large.len());
+        assert!(large_chunks.len() >= small_chunks.len() * 7);
+        assert!(large_chunks.iter().all(|c| c.text.chars().count() <= 1000));
+    }
+
+    /// Wall-clock check, run with `--ignored`; `benches/large_input.rs`
+    /// tracks the same algorithm with criterion.
+    #[test]
+    #[ignore = "timing-sensitive"]
+    fn test_sliding_window_linear_time() {
+        use std::time::Instant;
+
+        let chunker = SlidingWindowChunker;
+        let config = ChunkConfig::new(1000).with_overlap(100);
+        let small = "日本語 text ".repeat(100_000); // ~1.3 MB
+        let large = small.repeat(8); // ~10 MB
+
+        let timer = Instant::now();
+        chunker.chunk(&small, &config);
+        let small_elapsed = timer.elapsed();
+
+        let timer = Instant::now();
+        chunker.chunk(&large, &config);
+        let large_elapsed = timer.elapsed();
+
+        // Linear scaling is ~8x and quadratic ~64x; allow 3x noise over linear
+        assert!(large_elapsed < small_elapsed * 24);
+    }
+
+    #[test]
+    fn test_sliding_window_matches_char_vec_reference() {
+        let chunker = SlidingWindowChunker;
+        let text = "日本語テスト mixed ascii, émojis 🙂🙃 ".repeat(300);
+        let chars: Vec<char> = text.chars().collect();
+
+        for (max_size, overlap) in [(3, 1), (10, 9), (64, 16), (1000, 0)] {
+            let mut expected = Vec::new();
+            let mut start = 0;
+            loop {
+                let end = (start + max_size).min(chars.len());
+                let start_byte: usize = chars[..start].iter().map(|c| c.len_utf8()).sum();
+                let chunk_text: String = chars[start..end].iter().collect();
+                expected.push((start_byte, start_byte + chunk_text.len(), chunk_text));
+                if end >= chars.len() {
+                    break;
+                }
+                start += max_size - overlap;
+            }
+
+            let config = ChunkConfig::new(max_size).with_overlap(overlap);
+            let chunks: Vec<(usize, usize, String)> = chunker
+                .chunk(&text, &config)
+                .into_iter()
+                .map(|c| (c.start, c.end, c.text))
+                .collect();
+            assert_eq!(chunks, expected);
+        }
+    }
+
+    #[test]
+    fn test_sliding_window_sentences() {
+        let text = (1..=20)
+            .map(|i| format!("Sentence number {}.", i))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let config = ChunkConfig::new(5)
+            .with_overlap(2)
+            .with_window_unit(WindowUnit::Sentences);
+        let chunks = SlidingWindowChunker.chunk(&text, &config);
+
+        // Windows start at sentences 1, 4, 7, 10, 13 and 16
+        assert_eq!(chunks.len(), 6);
+        assert!(chunks[0].text.starts_with("Sentence number 1."));
+        assert!(chunks[0].text.ends_with("Sentence number 5."));
+        assert!(chunks[1].text.starts_with("Sentence number 4."));
+        assert!(chunks[5].text.ends_with("Sentence number 20."));
+        assert_eq!(chunks[0].metadata.overlap_chars, None);
+
+        for (prev, chunk) in chunks.iter().zip(&chunks[1..]) {
+            assert_eq!(&text[chunk.start..chunk.end], chunk.text);
+            let shared = &text[chunk.start..prev.end];
+            assert_eq!(shared.matches('.').count(), 2);
+            assert_eq!(chunk.metadata.overlap_chars, Some(shared.chars().count()));
+        }
+    }
+
+    #[test]
+    fn test_sliding_window_sentences_short_text() {
+        let config = ChunkConfig::new(5)
+            .with_overlap(2)
+            .with_window_unit(WindowUnit::Sentences);
+        let chunks = SlidingWindowChunker.chunk("One. Two. Three.", &config);
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].text, "One. Two. Three.");
+
+        let chunks = SlidingWindowChunker.chunk("One. Two. Three. Four. Five.", &config);
+        assert_eq!(chunks.len(), 1);
+        assert!(SlidingWindowChunker.chunk("", &config).is_empty());
+    }
 }
@@ -0,0 +1,117 @@
+//! Word-count chunking algorithm.
+//!
+//! Groups text into chunks of at most `max_size` words, where words follow
+//! Unicode word boundaries. `config.overlap` words are repeated at the start
+//! of the next chunk. Chunk text is sliced from the source, so the original
+//! spacing and punctuation between words is preserved.
+
+use crate::chunk::{Chunk, ChunkMetadata};
+use crate::config::ChunkConfig;
+use crate::traits::ChunkAlgorithm;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Word chunker that packs a fixed number of words per chunk.
+pub struct WordChunker;
+
+impl ChunkAlgorithm for WordChunker {
+    fn chunk(&self, text: &str, config: &ChunkConfig) -> Vec<Chunk> {
+        let words: Vec<(usize, usize)> = text
+            .unicode_word_indices()
+            .map(|(start, word)| (start, start + word.len()))
+            .collect();
+        if words.is_empty() || config.max_size == 0 {
+            return Vec::new();
+        }
+
+        let overlap = config.overlap.min(config.max_size - 1);
+        let step = config.max_size - overlap;
+        let mut chunks = Vec::new();
+        let mut first = 0;
+
+        loop {
+            let last = (first + config.max_size).min(words.len());
+            let start = words[first].0;
+            let end = words[last - 1].1;
+
+            let metadata = ChunkMetadata {
+                overlap_chars: (first > 0 && overlap > 0)
+                    .then(|| text[start..words[first + overlap - 1].1].chars().count()),
+                ..ChunkMetadata::for_method(self.name())
+            };
+            chunks.push(Chunk::with_uuid(
+                text[start..end].to_string(),
+                start,
+                end,
+                metadata,
+            ));
+
+            if last == words.len() {
+                break;
+            }
+            first += step;
+        }
+
+        Chunk::link_sequence(chunks)
+    }
+
+    fn name(&self) -> &str {
+        "word"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_word_basic() {
+        let chunker = WordChunker;
+        let config = ChunkConfig::new(3);
+        let text = "one two  three\tfour, five";
+        let chunks = chunker.chunk(text, &config);
+
+        let texts: Vec<&str> = chunks.iter().map(|c| c.text.as_str()).collect();
+        assert_eq!(texts, vec!["one two  three", "four, five"]);
+        for chunk in &chunks {
+            assert_eq!(&text[chunk.start..chunk.end], chunk.text);
+        }
+    }
+
+    #[test]
+    fn test_word_count_limit() {
+        let chunker = WordChunker;
+        let config = ChunkConfig::new(7).with_overlap(2);
+        let text = "The quick brown fox jumps over the lazy dog. ".repeat(20);
+        let chunks = chunker.chunk(&text, &config);
+
+        assert!(chunks.len() > 1);
+        for chunk in &chunks {
+            assert!(chunk.text.unicode_words().count() <= 7);
+        }
+        assert_eq!(
+            chunks.last().unwrap().text.unicode_words().next_back(),
+            Some("dog")
+        );
+    }
+
+    #[test]
+    fn test_word_overlap() {
+        let chunker = WordChunker;
+        let config = ChunkConfig::new(3).with_overlap(1);
+        let chunks = chunker.chunk("a b c d e", &config);
+
+        let texts: Vec<&str> = chunks.iter().map(|c| c.text.as_str()).collect();
+        assert_eq!(texts, vec!["a b c", "c d e"]);
+        assert_eq!(chunks[0].metadata.overlap_chars, None);
+        assert_eq!(chunks[1].metadata.overlap_chars, Some(1));
+    }
+
+    #[test]
+    fn test_word_empty() {
+        let chunker = WordChunker;
+        let config = ChunkConfig::new(10);
+
+        assert!(chunker.chunk("", &config).is_empty());
+        assert!(chunker.chunk("  ...  ", &config).is_empty());
+    }
+}
@@ -0,0 +1,277 @@
+//! XML-aware chunking algorithm.
+//!
+//! Walks XML elements with a streaming parser and extracts the text content
+//! of selected element paths (e.g. "doc/section/para"):
+//! - CDATA sections are included verbatim
+//! - Character and predefined entity references are decoded
+//! - Each chunk records its element path in `section`
+
+use crate::chunk::{Chunk, ChunkMetadata};
+use crate::config::ChunkConfig;
+use crate::error::ChunkError;
+use crate::traits::{parse_options, ChunkAlgorithm};
+use quick_xml::escape::resolve_predefined_entity;
+use quick_xml::events::Event;
+use quick_xml::Reader;
+use serde::Deserialize;
+use serde_json::Value;
+
+/// Text content extracted from a matching element.
+#[derive(Debug)]
+struct XmlSegment {
+    /// Slash-separated element path (e.g. "doc/section/para")
+    path: String,
+    /// Decoded text content of the element
+    text: String,
+    /// Start byte position of the element's start tag
+    start: usize,
+    /// End byte position of the element's end tag
+    end: usize,
+}
+
+/// An element currently being captured.
+struct Capture {
+    depth: usize,
+    path: String,
+    text: String,
+    start: usize,
+}
+
+/// XML chunker that extracts text of configured element paths.
+pub struct XmlChunker {
+    /// Element paths whose text content becomes chunks (e.g. ["doc/section/para"])
+    pub element_paths: Vec<String>,
+}
+
+impl XmlChunker {
+    /// Create a new XmlChunker extracting the specified element paths.
+    pub fn new(element_paths: Vec<String>) -> Self {
+        Self { element_paths }
+    }
+
+    /// Parse XML into segments for each matching element.
+    fn parse_segments(&self, text: &str) -> Result<Vec<XmlSegment>, ChunkError> {
+        let mut reader = Reader::from_str(text);
+        let mut segments = Vec::new();
+        let mut stack: Vec<String> = Vec::new();
+        let mut capture: Option<Capture> = None;
+
+        loop {
+            let event_start = reader.buffer_position() as usize;
+            let event = reader.read_event().map_err(|e| {
+                ChunkError::ProcessingError(format!(
+                    "invalid XML at byte {}: {}",
+                    reader.error_position(),
+                    e
+                ))
+            })?;
+
+            let content = match event {
+                Event::Start(e) => {
+                    stack.push(String::from_utf8_lossy(e.name().as_ref()).into_owned());
+                    if capture.is_none() {
+                        let path = stack.join("/");
+                        if self.element_paths.contains(&path) {
+                            capture = Some(Capture {
+                                depth: stack.len(),
+                                path,
+                                text: String::new(),
+                                start: event_start,
+                            });
+                        }
+                    }
+                    continue;
+                }
+                Event::End(_) => {
+                    if let Some(c) = capture.take() {
+                        if c.depth == stack.len() {
+                            segments.push(XmlSegment {
+                                path: c.path,
+                                text: c.text.trim().to_string(),
+                                start: c.start,
+                                end: reader.buffer_position() as usize,
+                            });
+                        } else {
+                            capture = Some(c);
+                        }
+                    }
+                    stack.pop();
+                    continue;
+                }
+                Event::Text(e) => e.decode().map(|s| s.into_owned()),
+                Event::CData(e) => e.decode().map(|s| s.into_owned()),
+                Event::GeneralRef(e) => {
+                    let resolved = match e.resolve_char_ref() {
+                        Ok(Some(ch)) => ch.to_string(),
+                        _ => {
+                            let name = e
+                                .decode()
+                                .map_err(|err| ChunkError::ProcessingError(err.to_string()))?;
+                            resolve_predefined_entity(&name)
+                                .map(str::to_string)
+                                .unwrap_or_else(|| format!("&{};", name))
+                        }
+                    };
+                    Ok(resolved)
+                }
+                Event::Eof => break,
+                _ => continue,
+            };
+
+            let content = content.map_err(|e| ChunkError::ProcessingError(e.to_string()))?;
+            if let Some(ref mut c) = capture {
+                c.text.push_str(&content);
+            }
+        }
+
+        Ok(segments)
+    }
+
+    /// Create a chunk from a packed segment.
+    fn make_chunk(&self, segment: XmlSegment) -> Chunk {
+        let metadata = ChunkMetadata {
+            section: Some(segment.path),
+            ..ChunkMetadata::for_method(self.name())
+        };
+        Chunk::with_uuid(segment.text, segment.start, segment.end, metadata)
+    }
+}
+
+/// Options accepted by [`XmlChunker::with_options`](ChunkAlgorithm::with_options).
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct XmlOptions {
+    element_paths: Option<Vec<String>>,
+}
+
+impl ChunkAlgorithm for XmlChunker {
+    /// Chunk XML text. Malformed documents produce no chunks; use
+    /// [`try_chunk`](Self::try_chunk) to get the parse error instead.
+    fn chunk(&self, text: &str, config: &ChunkConfig) -> Vec<Chunk> {
+        self.try_chunk(text, config).unwrap_or_default()
+    }
+
+    /// Chunk XML, returning an error if the document is malformed.
+    fn try_chunk(&self, text: &str, config: &ChunkConfig) -> Result<Vec<Chunk>, ChunkError> {
+        if text.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let segments = self.parse_segments(text)?;
+        let mut chunks = Vec::new();
+        let mut current: Option<XmlSegment> = None;
+
+        for segment in segments {
+            if segment.text.is_empty() {
+                continue;
+            }
+
+            // Pack consecutive elements of the same path up to max_size
+            if let Some(ref mut pending) = current {
+                let potential_len = pending.text.len() + 2 + segment.text.len();
+                if pending.path == segment.path && potential_len <= config.max_size {
+                    pending.text.push_str("\n\n");
+                    pending.text.push_str(&segment.text);
+                    pending.end = segment.end;
+                    continue;
+                }
+            }
+
+            if let Some(pending) = current.replace(segment) {
+                chunks.push(self.make_chunk(pending));
+            }
+        }
+
+        if let Some(pending) = current {
+            chunks.push(self.make_chunk(pending));
+        }
+
+        Ok(Chunk::link_sequence(chunks))
+    }
+
+    fn name(&self) -> &str {
+        "xml"
+    }
+
+    fn with_options(&self, options: &Value) -> Result<Box<dyn ChunkAlgorithm>, ChunkError> {
+        let options: XmlOptions = parse_options(self.name(), options)?;
+        Ok(Box::new(Self::new(
+            options
+                .element_paths
+                .unwrap_or_else(|| self.element_paths.clone()),
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DOC: &str = r#"<?xml version="1.0"?>
+<doc>
+  <title>Report</title>
+  <section>
+    <para>Fish &amp; chips &#169; 2024.</para>
+    <para>Second <b>bold</b> para.</para>
+    <code><![CDATA[if a < b { x(); }]]></code>
+  </section>
+</doc>"#;
+
+    #[test]
+    fn test_xml_extracts_paths() {
+        let chunker = XmlChunker::new(vec![
+            "doc/title".to_string(),
+            "doc/section/para".to_string(),
+        ]);
+        let config = ChunkConfig::new(1000);
+        let chunks = chunker.chunk(DOC, &config);
+
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[0].text, "Report");
+        assert_eq!(chunks[0].metadata.section, Some("doc/title".to_string()));
+        assert_eq!(chunks[1].text, "Fish & chips © 2024.\n\nSecond bold para.");
+        assert_eq!(
+            chunks[1].metadata.section,
+            Some("doc/section/para".to_string())
+        );
+        assert!(DOC[chunks[1].start..chunks[1].end].starts_with("<para>Fish"));
+        assert!(DOC[chunks[1].start..chunks[1].end].ends_with("para.</para>"));
+    }
+
+    #[test]
+    fn test_xml_cdata() {
+        let chunker = XmlChunker::new(vec!["doc/section/code".to_string()]);
+        let config = ChunkConfig::new(1000);
+        let chunks = chunker.chunk(DOC, &config);
+
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].text, "if a < b { x(); }");
+    }
+
+    #[test]
+    fn test_xml_split_by_size() {
+        let chunker = XmlChunker::new(vec!["doc/section/para".to_string()]);
+        let config = ChunkConfig::new(20);
+        let chunks = chunker.chunk(DOC, &config);
+
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[1].text, "Second bold para.");
+    }
+
+    #[test]
+    fn test_xml_malformed() {
+        let chunker = XmlChunker::new(vec!["a".to_string()]);
+        let config = ChunkConfig::new(100);
+
+        assert!(chunker.try_chunk("<a><b></a>", &config).is_err());
+        assert!(chunker.chunk("<a><b></a>", &config).is_empty());
+    }
+
+    #[test]
+    fn test_xml_empty() {
+        let chunker = XmlChunker::new(vec!["a".to_string()]);
+        let config = ChunkConfig::new(100);
+
+        assert!(chunker.chunk("", &config).is_empty());
+    }
+}
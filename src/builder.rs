@@ -0,0 +1,344 @@
+//! Builder API for Rust consumers.
+//!
+//! [`ChunkerBuilder`] is the Rust counterpart of the Python `Chunker`: pick a
+//! method by name, set options, and get a [`BoundChunker`] that chunks text
+//! with that configuration.
+//!
+//! ```
+//! use bunkatsu::ChunkerBuilder;
+//!
+//! let chunker = ChunkerBuilder::new()
+//!     .method("sentence")
+//!     .max_size(512)
+//!     .overlap(50)
+//!     .build()?;
+//!
+//! let chunks = chunker.chunk("Hello world. How are you?")?;
+//! assert_eq!(chunks.len(), 1);
+//! # Ok::<(), bunkatsu::ChunkError>(())
+//! ```
+
+use crate::algorithms::{HeadingChunker, RecursiveChunker, RecursiveStrategy};
+use crate::chunk::Chunk;
+use crate::config::{ChunkConfig, SentenceDetector};
+use crate::error::ChunkError;
+use crate::registry::AlgorithmRegistry;
+use crate::traits::ChunkAlgorithm;
+use std::sync::Arc;
+
+/// Builder for a [`BoundChunker`].
+///
+/// The method defaults to `"fixed_size"` and the config to
+/// [`ChunkConfig::default`]. Method names other than `"heading"` and
+/// `"recursive"` are looked up in an [`AlgorithmRegistry`].
+///
+/// ```
+/// use bunkatsu::{ChunkerBuilder, RecursiveStrategy};
+///
+/// let chunker = ChunkerBuilder::new()
+///     .method("recursive")
+///     .recursive_strategy(RecursiveStrategy::SentenceFirst)
+///     .max_size(20)
+///     .build()?;
+/// assert_eq!(chunker.method(), "recursive");
+///
+/// let headings = ChunkerBuilder::new()
+///     .method("heading")
+///     .heading_levels(vec![1])
+///     .build()?;
+/// let chunks = headings.chunk("# A\n\none\n\n## B\n\ntwo")?;
+/// assert_eq!(chunks.len(), 1);
+///
+/// assert!(ChunkerBuilder::new().method("nope").build().is_err());
+/// # Ok::<(), bunkatsu::ChunkError>(())
+/// ```
+pub struct ChunkerBuilder {
+    method: String,
+    config: ChunkConfig,
+    heading_levels: Option<Vec<usize>>,
+    recursive_strategy: Option<RecursiveStrategy>,
+    registry: Option<AlgorithmRegistry>,
+}
+
+impl Default for ChunkerBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ChunkerBuilder {
+    /// Create a builder for fixed-size chunking with the default config.
+    pub fn new() -> Self {
+        Self {
+            method: "fixed_size".to_string(),
+            config: ChunkConfig::default(),
+            heading_levels: None,
+            recursive_strategy: None,
+            registry: None,
+        }
+    }
+
+    /// Set the chunking method by name (e.g. "sentence", "heading").
+    pub fn method(mut self, method: impl Into<String>) -> Self {
+        self.method = method.into();
+        self
+    }
+
+    /// Replace the whole configuration.
+    pub fn config(mut self, config: ChunkConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Set the maximum chunk size.
+    pub fn max_size(mut self, max_size: usize) -> Self {
+        self.config.max_size = max_size;
+        self
+    }
+
+    /// Set the overlap between chunks.
+    pub fn overlap(mut self, overlap: usize) -> Self {
+        self.config.overlap = overlap;
+        self
+    }
+
+    /// Set the sentence detection method.
+    pub fn sentence_detector(mut self, detector: SentenceDetector) -> Self {
+        self.config.sentence_detector = detector;
+        self
+    }
+
+    /// Set the heading levels that start a new chunk (heading method only).
+    pub fn heading_levels(mut self, levels: Vec<usize>) -> Self {
+        self.heading_levels = Some(levels);
+        self
+    }
+
+    /// Set the fallback order for recursive chunking (recursive method only).
+    pub fn recursive_strategy(mut self, strategy: RecursiveStrategy) -> Self {
+        self.recursive_strategy = Some(strategy);
+        self
+    }
+
+    /// Look up methods in a custom registry instead of the built-in one.
+    pub fn registry(mut self, registry: AlgorithmRegistry) -> Self {
+        self.registry = Some(registry);
+        self
+    }
+
+    /// Validate the method, its options and the config, and build the chunker.
+    pub fn build(self) -> Result<BoundChunker, ChunkError> {
+        self.config.validate()?;
+
+        if self.heading_levels.is_some() && self.method != "heading" {
+            return Err(ChunkError::InvalidConfig(format!(
+                "heading_levels only applies to the heading method, not {:?}",
+                self.method
+            )));
+        }
+        if self.recursive_strategy.is_some() && self.method != "recursive" {
+            return Err(ChunkError::InvalidConfig(format!(
+                "recursive_strategy only applies to the recursive method, not {:?}",
+                self.method
+            )));
+        }
+
+        let algorithm: Arc<dyn ChunkAlgorithm> = match self.method.as_str() {
+            "heading" => Arc::new(match self.heading_levels {
+                Some(levels) => HeadingChunker::try_new(levels)?,
+                None => HeadingChunker::default(),
+            }),
+            "recursive" => Arc::new(RecursiveChunker::new(
+                self.recursive_strategy.unwrap_or_default(),
+            )),
+            name => self
+                .registry
+                .unwrap_or_default()
+                .get(name)
+                .ok_or_else(|| ChunkError::AlgorithmNotFound(name.to_string()))?,
+        };
+
+        Ok(BoundChunker {
+            algorithm,
+            config: self.config,
+        })
+    }
+}
+
+/// A chunking method bound to a validated configuration.
+pub struct BoundChunker {
+    algorithm: Arc<dyn ChunkAlgorithm>,
+    config: ChunkConfig,
+}
+
+impl BoundChunker {
+    /// Chunk text, applying the config's normalization, ID strategy and checksums.
+    ///
+    /// Returns [`ChunkError::EmptyInput`] for blank text when
+    /// `error_on_empty` is set.
+    pub fn chunk(&self, text: &str) -> Result<Vec<Chunk>, ChunkError> {
+        let text = self.config.normalize_input(text);
+        self.config.check_input(&text)?;
+        self.algorithm.chunk_finalized(&text, &self.config)
+    }
+
+    /// Name of the bound chunking method.
+    pub fn method(&self) -> &str {
+        self.algorithm.name()
+    }
+
+    /// The validated configuration.
+    pub fn config(&self) -> &ChunkConfig {
+        &self.config
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::ParagraphChunker;
+    use crate::config::{IdStrategy, NormalizationForm};
+
+    #[test]
+    fn test_builder_default_method() {
+        let chunker = ChunkerBuilder::new().max_size(10).build().unwrap();
+        assert_eq!(chunker.method(), "fixed_size");
+        assert_eq!(chunker.config().max_size, 10);
+        assert_eq!(chunker.chunk("a".repeat(25).as_str()).unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_builder_unknown_method() {
+        assert!(matches!(
+            ChunkerBuilder::new().method("nope").build(),
+            Err(ChunkError::AlgorithmNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_builder_invalid_config() {
+        let result = ChunkerBuilder::new().max_size(10).overlap(10).build();
+        assert!(matches!(result, Err(ChunkError::InvalidConfig(_))));
+    }
+
+    #[test]
+    fn test_builder_option_for_wrong_method() {
+        assert!(ChunkerBuilder::new()
+            .method("sentence")
+            .heading_levels(vec![1])
+            .build()
+            .is_err());
+        assert!(ChunkerBuilder::new()
+            .recursive_strategy(RecursiveStrategy::SentenceFirst)
+            .build()
+            .is_err());
+        assert!(ChunkerBuilder::new()
+            .method("heading")
+            .heading_levels(vec![9])
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    fn test_builder_applies_id_strategy() {
+        let config = ChunkConfig::new(10).with_id_strategy(IdStrategy::Sequential);
+        let chunker = ChunkerBuilder::new().config(config).build().unwrap();
+        let chunks = chunker.chunk(&"a".repeat(25)).unwrap();
+        assert_eq!(chunks[0].id, "doc-0");
+        assert_eq!(chunks[2].id, "doc-2");
+    }
+
+    #[test]
+    fn test_builder_normalizes_text() {
+        let text = "  Caf\u{e9}\u{7}  au\t\tlait.\n\nUne  \u{fb01}n.  ";
+        let plain = ChunkConfig::new(100);
+        let chunk_with = |config: ChunkConfig| {
+            ChunkerBuilder::new()
+                .method("paragraph")
+                .config(config)
+                .build()
+                .unwrap()
+                .chunk(text)
+                .unwrap()
+        };
+
+        // Disabled options leave the algorithm's output untouched
+        let raw = ParagraphChunker.chunk(text, &plain);
+        let finalized = chunk_with(plain.clone());
+        let spans = |chunks: &[Chunk]| {
+            chunks
+                .iter()
+                .map(|c| (c.text.clone(), c.start, c.end))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(spans(&finalized), spans(&raw));
+
+        let collapsed = chunk_with(plain.clone().with_collapse_whitespace(true));
+        assert_eq!(collapsed[0].text, "Caf\u{e9}\u{7} au lait. Une \u{fb01}n.");
+        let positions =
+            |chunks: &[Chunk]| chunks.iter().map(|c| (c.start, c.end)).collect::<Vec<_>>();
+        assert_eq!(positions(&collapsed), positions(&raw));
+
+        let fixed = ChunkConfig::new(100)
+            .with_strip_control_chars(true)
+            .with_normalize_unicode(Some(NormalizationForm::Nfkc))
+            .with_collapse_whitespace(true)
+            .with_trim(true);
+        let chunks = ChunkerBuilder::new()
+            .config(fixed)
+            .build()
+            .unwrap()
+            .chunk(text)
+            .unwrap();
+        assert_eq!(chunks[0].text, "Caf\u{e9} au lait. Une fin.");
+        assert_eq!((chunks[0].start, chunks[0].end), (0, text.len()));
+    }
+
+    #[test]
+    fn test_builder_normalizes_input() {
+        let nfd = "Cafe\u{301}. Cre\u{300}me bru\u{302}le\u{301}e.";
+        let config = ChunkConfig::new(10).with_normalization(Some(NormalizationForm::Nfc));
+        let chunks = ChunkerBuilder::new()
+            .method("sentence")
+            .config(config)
+            .build()
+            .unwrap()
+            .chunk(nfd)
+            .unwrap();
+
+        let normalized = "Caf\u{e9}. Cr\u{e8}me br\u{fb}l\u{e9}e.";
+        let texts: Vec<&str> = chunks.iter().map(|c| c.text.as_str()).collect();
+        assert_eq!(texts, vec!["Caf\u{e9}.", "Cr\u{e8}me br\u{fb}l\u{e9}e."]);
+        // Positions refer to the normalized text
+        for chunk in &chunks {
+            assert_eq!(&normalized[chunk.start..chunk.end], chunk.text);
+        }
+    }
+
+    #[test]
+    fn test_builder_normalizes_input_then_chunks() {
+        let nfd = "Cafe\u{301}. Cre\u{300}me bru\u{302}le\u{301}e.";
+        let normalized = "Caf\u{e9}. Cr\u{e8}me br\u{fb}l\u{e9}e.";
+        let config = ChunkConfig::new(10)
+            .with_normalization(Some(NormalizationForm::Nfc))
+            .with_normalize_unicode(Some(NormalizationForm::Nfd));
+        let chunks = ChunkerBuilder::new()
+            .method("sentence")
+            .config(config)
+            .build()
+            .unwrap()
+            .chunk(nfd)
+            .unwrap();
+
+        // Sizes and positions follow the NFC input, chunk text is NFD
+        let texts: Vec<&str> = chunks.iter().map(|c| c.text.as_str()).collect();
+        assert_eq!(
+            texts,
+            vec!["Cafe\u{301}.", "Cre\u{300}me bru\u{302}le\u{301}e."]
+        );
+        for chunk in &chunks {
+            let span = &normalized[chunk.start..chunk.end];
+            assert_eq!(NormalizationForm::Nfd.normalize(span), chunk.text);
+        }
+    }
+}
@@ -1,11 +1,27 @@
 //! Core chunk data structures.
 
 use pyo3::prelude::*;
-use std::collections::HashMap;
+use pyo3::types::{PyDict, PyTuple};
+use serde::{Deserialize, Serialize};
+
+use crate::config::{ChunkConfig, IdStrategy};
+use crate::error::ChunkError;
+use crate::token_estimate::estimate_tokens_cl100k;
+use sha2::{Digest, Sha256};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
 
 /// Metadata associated with a chunk.
-#[pyclass]
-#[derive(Debug, Clone, Default)]
+///
+/// Metadata compares and hashes by value, so it can be used in sets and
+/// dict keys. The links to other chunks (`parent_chunk_id`, the neighbor
+/// links and `merged_from`) hold IDs that are random per run with the
+/// default ID strategy, so they are left out of the comparison, as is the
+/// float `language_confidence`. `Chunk` equality covers the chunk's own ID.
+#[pyclass(module = "bunkatsu")]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct ChunkMetadata {
     /// The chunking method used.
     #[pyo3(get)]
@@ -13,30 +29,91 @@ pub struct ChunkMetadata {
     /// Section identifier (if applicable).
     #[pyo3(get)]
     pub section: Option<String>,
-    /// Number of overlapping characters (for sliding window).
+    /// Ancestor heading titles down to this chunk's own heading.
+    #[pyo3(get)]
+    pub section_path: Option<Vec<String>>,
+    /// Kind of structural block when the chunk is a single block (e.g.
+    /// "table", "code", "math"). Oversized markdown math blocks also keep
+    /// `extra["block_type"] = "math"`.
+    #[pyo3(get)]
+    pub block_type: Option<String>,
+    /// Number of overlapping characters, not bytes (for sliding window).
     #[pyo3(get)]
     pub overlap_chars: Option<usize>,
     /// Parent chunk ID (for recursive chunking).
     #[pyo3(get)]
     pub parent_chunk_id: Option<String>,
+    /// Additional algorithm-specific attributes and config `extra` (e.g.
+    /// "section_prefix", "split_line", or "block_type" for oversized math).
+    #[pyo3(get)]
+    pub extra: HashMap<String, String>,
+    /// CRC32 checksum of the chunk text as 8 hex digits (when
+    /// `compute_checksum` is set). Content-hash IDs use SHA-256 instead.
+    #[pyo3(get)]
+    pub checksum: Option<String>,
+    /// Position of the chunk in its document's chunk list, from 0.
+    #[pyo3(get)]
+    pub chunk_index: Option<usize>,
+    /// Number of chunks the document was split into.
+    #[pyo3(get)]
+    pub total_chunks: Option<usize>,
+    /// ID of the preceding chunk in the document.
+    #[pyo3(get)]
+    pub prev_chunk_id: Option<String>,
+    /// ID of the following chunk in the document.
+    #[pyo3(get)]
+    pub next_chunk_id: Option<String>,
+    /// IDs of the chunks combined into this one by `merge_small`.
+    #[pyo3(get)]
+    pub merged_from: Option<Vec<String>>,
+    /// ISO 639-1 code of the chunk's dominant language (when
+    /// `detect_language` is set in the config).
+    #[pyo3(get)]
+    pub language: Option<String>,
+    /// Confidence of the detected language, from 0.0 to 1.0.
+    #[pyo3(get)]
+    pub language_confidence: Option<f64>,
 }
 
 #[pymethods]
 impl ChunkMetadata {
     /// Create a new ChunkMetadata.
     #[new]
-    #[pyo3(signature = (method, section=None, overlap_chars=None, parent_chunk_id=None))]
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (method, section=None, overlap_chars=None, parent_chunk_id=None, extra=None, checksum=None, section_path=None, block_type=None, chunk_index=None, total_chunks=None, prev_chunk_id=None, next_chunk_id=None, merged_from=None, language=None, language_confidence=None))]
     pub fn new(
         method: String,
         section: Option<String>,
         overlap_chars: Option<usize>,
         parent_chunk_id: Option<String>,
+        extra: Option<HashMap<String, String>>,
+        checksum: Option<String>,
+        section_path: Option<Vec<String>>,
+        block_type: Option<String>,
+        chunk_index: Option<usize>,
+        total_chunks: Option<usize>,
+        prev_chunk_id: Option<String>,
+        next_chunk_id: Option<String>,
+        merged_from: Option<Vec<String>>,
+        language: Option<String>,
+        language_confidence: Option<f64>,
     ) -> Self {
         Self {
             method,
             section,
+            section_path,
+            block_type,
             overlap_chars,
             parent_chunk_id,
+            extra: extra.unwrap_or_default(),
+            checksum,
+            chunk_index,
+            total_chunks,
+            prev_chunk_id,
+            next_chunk_id,
+            merged_from,
+            language,
+            language_confidence,
         }
     }
 
@@ -80,20 +157,236 @@ impl ChunkMetadata {
                     .unbind(),
             );
         }
+        if let Some(ref section_path) = self.section_path {
+            map.insert(
+                "section_path".to_string(),
+                section_path
+                    .clone()
+                    .into_pyobject(py)
+                    .unwrap()
+                    .into_any()
+                    .unbind(),
+            );
+        }
+        if let Some(ref block_type) = self.block_type {
+            map.insert(
+                "block_type".to_string(),
+                block_type
+                    .clone()
+                    .into_pyobject(py)
+                    .unwrap()
+                    .into_any()
+                    .unbind(),
+            );
+        }
+        if let Some(ref checksum) = self.checksum {
+            map.insert(
+                "checksum".to_string(),
+                checksum
+                    .clone()
+                    .into_pyobject(py)
+                    .unwrap()
+                    .into_any()
+                    .unbind(),
+            );
+        }
+        if let Some(index) = self.chunk_index {
+            map.insert(
+                "chunk_index".to_string(),
+                index.into_pyobject(py).unwrap().into_any().unbind(),
+            );
+        }
+        if let Some(total) = self.total_chunks {
+            map.insert(
+                "total_chunks".to_string(),
+                total.into_pyobject(py).unwrap().into_any().unbind(),
+            );
+        }
+        if let Some(ref prev_id) = self.prev_chunk_id {
+            map.insert(
+                "prev_chunk_id".to_string(),
+                prev_id
+                    .clone()
+                    .into_pyobject(py)
+                    .unwrap()
+                    .into_any()
+                    .unbind(),
+            );
+        }
+        if let Some(ref next_id) = self.next_chunk_id {
+            map.insert(
+                "next_chunk_id".to_string(),
+                next_id
+                    .clone()
+                    .into_pyobject(py)
+                    .unwrap()
+                    .into_any()
+                    .unbind(),
+            );
+        }
+        if let Some(ref merged_from) = self.merged_from {
+            map.insert(
+                "merged_from".to_string(),
+                merged_from
+                    .clone()
+                    .into_pyobject(py)
+                    .unwrap()
+                    .into_any()
+                    .unbind(),
+            );
+        }
+        if let Some(ref language) = self.language {
+            map.insert(
+                "language".to_string(),
+                language
+                    .clone()
+                    .into_pyobject(py)
+                    .unwrap()
+                    .into_any()
+                    .unbind(),
+            );
+        }
+        if let Some(confidence) = self.language_confidence {
+            map.insert(
+                "language_confidence".to_string(),
+                confidence.into_pyobject(py).unwrap().into_any().unbind(),
+            );
+        }
+        if !self.extra.is_empty() {
+            map.insert(
+                "extra".to_string(),
+                self.extra
+                    .clone()
+                    .into_pyobject(py)
+                    .unwrap()
+                    .into_any()
+                    .unbind(),
+            );
+        }
         map
     }
 
     fn __repr__(&self) -> String {
         format!(
-            "ChunkMetadata(method='{}', section={:?}, overlap_chars={:?}, parent_chunk_id={:?})",
-            self.method, self.section, self.overlap_chars, self.parent_chunk_id
+            "ChunkMetadata(method='{}', section={:?}, section_path={:?}, block_type={:?}, overlap_chars={:?}, parent_chunk_id={:?}, extra={:?}, checksum={:?}, chunk_index={:?}, total_chunks={:?}, prev_chunk_id={:?}, next_chunk_id={:?}, merged_from={:?}, language={:?}, language_confidence={:?})",
+            self.method,
+            self.section,
+            self.section_path,
+            self.block_type,
+            self.overlap_chars,
+            self.parent_chunk_id,
+            self.extra,
+            self.checksum,
+            self.chunk_index,
+            self.total_chunks,
+            self.prev_chunk_id,
+            self.next_chunk_id,
+            self.merged_from,
+            self.language,
+            self.language_confidence
         )
     }
+
+    fn __eq__(&self, other: &Self) -> bool {
+        self == other
+    }
+
+    fn __hash__(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Pickle support: constructor keyword arguments.
+    fn __getnewargs_ex__<'py>(
+        &self,
+        py: Python<'py>,
+    ) -> PyResult<(Bound<'py, PyTuple>, Bound<'py, PyDict>)> {
+        let kwargs = PyDict::new(py);
+        kwargs.set_item("method", &self.method)?;
+        kwargs.set_item("section", self.section.clone())?;
+        kwargs.set_item("overlap_chars", self.overlap_chars)?;
+        kwargs.set_item("parent_chunk_id", self.parent_chunk_id.clone())?;
+        kwargs.set_item("extra", self.extra.clone())?;
+        kwargs.set_item("checksum", self.checksum.clone())?;
+        kwargs.set_item("section_path", self.section_path.clone())?;
+        kwargs.set_item("block_type", self.block_type.clone())?;
+        kwargs.set_item("chunk_index", self.chunk_index)?;
+        kwargs.set_item("total_chunks", self.total_chunks)?;
+        kwargs.set_item("prev_chunk_id", self.prev_chunk_id.clone())?;
+        kwargs.set_item("next_chunk_id", self.next_chunk_id.clone())?;
+        kwargs.set_item("merged_from", self.merged_from.clone())?;
+        kwargs.set_item("language", self.language.clone())?;
+        kwargs.set_item("language_confidence", self.language_confidence)?;
+        Ok((PyTuple::empty(py), kwargs))
+    }
+
+    fn __copy__(&self) -> Self {
+        self.clone()
+    }
+
+    fn __deepcopy__(&self, _memo: &Bound<'_, PyAny>) -> Self {
+        self.clone()
+    }
+}
+
+impl PartialEq for ChunkMetadata {
+    fn eq(&self, other: &Self) -> bool {
+        self.method == other.method
+            && self.section == other.section
+            && self.section_path == other.section_path
+            && self.block_type == other.block_type
+            && self.overlap_chars == other.overlap_chars
+            && self.extra == other.extra
+            && self.checksum == other.checksum
+            && self.chunk_index == other.chunk_index
+            && self.total_chunks == other.total_chunks
+            && self.language == other.language
+    }
+}
+
+impl Eq for ChunkMetadata {}
+
+impl Hash for ChunkMetadata {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.method.hash(state);
+        self.section.hash(state);
+        self.section_path.hash(state);
+        self.block_type.hash(state);
+        self.overlap_chars.hash(state);
+        // HashMap iteration order is unspecified, so hash the entries sorted
+        let mut extra: Vec<_> = self.extra.iter().collect();
+        extra.sort_unstable();
+        extra.hash(state);
+        self.checksum.hash(state);
+        self.chunk_index.hash(state);
+        self.total_chunks.hash(state);
+        self.language.hash(state);
+    }
+}
+
+impl ChunkMetadata {
+    /// Metadata for a chunk produced by `method`, with every other field unset.
+    pub fn for_method(method: impl Into<String>) -> Self {
+        Self {
+            method: method.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Copy document metadata into `extra`, keeping keys the algorithm already set.
+    pub fn merge_extra(&mut self, extra: &HashMap<String, String>) {
+        for (key, value) in extra {
+            self.extra
+                .entry(key.clone())
+                .or_insert_with(|| value.clone());
+        }
+    }
 }
 
 /// A text chunk with position and metadata.
-#[pyclass]
-#[derive(Debug, Clone)]
+#[pyclass(module = "bunkatsu")]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Chunk {
     /// Unique identifier for this chunk.
     #[pyo3(get)]
@@ -101,10 +394,10 @@ pub struct Chunk {
     /// The text content of this chunk.
     #[pyo3(get)]
     pub text: String,
-    /// Start position (character index) in the original text.
+    /// Start position (byte offset) in the original text.
     #[pyo3(get)]
     pub start: usize,
-    /// End position (character index) in the original text.
+    /// End position (byte offset, exclusive) in the original text.
     #[pyo3(get)]
     pub end: usize,
     /// Metadata associated with this chunk.
@@ -145,24 +438,322 @@ impl Chunk {
         self.text.is_empty()
     }
 
+    /// Type-token ratio of the chunk: unique words / total words.
+    ///
+    /// Words are lowercased whitespace-separated tokens. Returns 0.0 for empty text.
+    #[getter]
+    pub fn lexical_richness(&self) -> f32 {
+        let words: Vec<String> = self
+            .text
+            .split_whitespace()
+            .map(|w| w.to_lowercase())
+            .collect();
+        if words.is_empty() {
+            return 0.0;
+        }
+        let unique: HashSet<&String> = words.iter().collect();
+        unique.len() as f32 / words.len() as f32
+    }
+
+    /// Estimated `cl100k_base` token count of the chunk text.
+    ///
+    /// Computed on access with [`estimate_tokens_cl100k`](crate::estimate_tokens_cl100k).
+    #[getter]
+    pub fn token_estimate(&self) -> usize {
+        estimate_tokens_cl100k(&self.text)
+    }
+
+    /// Text this chunk shares with the previous one: its first
+    /// `metadata.overlap_chars` characters, or `None` without overlap.
+    #[getter]
+    pub fn overlap_text(&self) -> Option<String> {
+        let chars = self.metadata.overlap_chars?;
+        Some(self.text.chars().take(chars).collect())
+    }
+
+    /// Check that the stored checksum matches the current text.
+    ///
+    /// Returns false when no checksum was computed.
+    pub fn verify_checksum(&self) -> bool {
+        self.metadata.checksum.as_deref() == Some(self.compute_checksum().as_str())
+    }
+
+    /// The exact substring of `text` at this chunk's span.
+    ///
+    /// Raises ProcessingError when the span lies outside `text` or off a
+    /// character boundary.
+    #[pyo3(name = "original_slice")]
+    fn py_original_slice(&self, text: &str) -> PyResult<String> {
+        Ok(self.original_slice(text)?.to_string())
+    }
+
+    /// One-line summary with the first 50 characters of the text, escaped
+    /// like a Python string literal.
     fn __repr__(&self) -> String {
-        let preview = if self.text.len() > 50 {
-            format!("{}...", &self.text[..50])
-        } else {
-            self.text.clone()
-        };
+        let mut preview = escape_py_str(&self.text.chars().take(50).collect::<String>());
+        if self.text.chars().nth(50).is_some() {
+            preview.push_str("...");
+        }
         format!(
             "Chunk(id='{}', text='{}', start={}, end={})",
             self.id, preview, self.start, self.end
         )
     }
 
+    /// The full chunk text.
+    fn __str__(&self) -> String {
+        self.text.clone()
+    }
+
     fn __len__(&self) -> usize {
         self.text.len()
     }
+
+    /// Chunks are equal when their ID, text, span and metadata match, so
+    /// chunks from separate runs only compare equal with a deterministic
+    /// `id_strategy`.
+    fn __eq__(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+
+    fn __hash__(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.key().hash(&mut hasher);
+        hasher.finish()
+    }
+    /// Pickle support: constructor arguments.
+    fn __getnewargs__(&self) -> (String, String, usize, usize, ChunkMetadata) {
+        (
+            self.id.clone(),
+            self.text.clone(),
+            self.start,
+            self.end,
+            self.metadata.clone(),
+        )
+    }
+
+    fn __copy__(&self) -> Self {
+        self.clone()
+    }
+
+    fn __deepcopy__(&self, _memo: &Bound<'_, PyAny>) -> Self {
+        self.clone()
+    }
+}
+
+/// Escape `text` for display inside single quotes, as Python's `repr` does.
+fn escape_py_str(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '\'' => escaped.push_str("\\'"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => escaped.push_str(&format!("\\x{:02x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped
 }
 
 impl Chunk {
+    /// The fields that define chunk equality.
+    fn key(&self) -> (&str, &str, usize, usize, &ChunkMetadata) {
+        (&self.id, &self.text, self.start, self.end, &self.metadata)
+    }
+
+    /// The exact substring of `text` at `start..end`.
+    ///
+    /// `text` differs from the chunk text when the algorithm trimmed or
+    /// re-joined the source, so this is what to highlight in the original.
+    /// Fails when the span is out of range or not on character boundaries,
+    /// which also makes it a check that `start`/`end` are right.
+    pub fn original_slice<'a>(&self, text: &'a str) -> Result<&'a str, ChunkError> {
+        text.get(self.start..self.end).ok_or_else(|| {
+            ChunkError::ProcessingError(format!(
+                "chunk span {}..{} is not a valid slice of a {}-byte text",
+                self.start,
+                self.end,
+                text.len()
+            ))
+        })
+    }
+
+    /// Compute the CRC32 checksum of the chunk text as 8 lowercase hex digits.
+    pub fn compute_checksum(&self) -> String {
+        format!("{:08x}", crc32fast::hash(self.text.as_bytes()))
+    }
+
+    /// Store the checksum of the current text in the metadata.
+    pub fn set_checksum(&mut self) {
+        self.metadata.checksum = Some(self.compute_checksum());
+    }
+
+    /// Stable ID derived from the chunk text, span, method and optional doc ID.
+    pub fn content_hash_id(&self, doc_id: Option<&str>) -> String {
+        let mut hasher = Sha256::new();
+        for part in [
+            doc_id.unwrap_or(""),
+            &self.metadata.method,
+            &self.start.to_string(),
+            &self.end.to_string(),
+            &self.text,
+        ] {
+            hasher.update(part.as_bytes());
+            hasher.update([0]);
+        }
+        hasher.finalize()[..16]
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect()
+    }
+
+    /// Replace chunk IDs according to `config.id_strategy`.
+    ///
+    /// Parent references to renamed chunks are rewritten to match. Parent IDs
+    /// that do not name a chunk in `chunks` are derived from their first child.
+    pub fn assign_ids(chunks: &mut [Chunk], config: &ChunkConfig) {
+        if config.id_strategy == IdStrategy::Uuid4 {
+            return;
+        }
+
+        let mut renamed: HashMap<String, String> = HashMap::new();
+        for (index, chunk) in chunks.iter_mut().enumerate() {
+            let id = chunk.strategy_id(index, config);
+            renamed.insert(std::mem::replace(&mut chunk.id, id.clone()), id);
+        }
+
+        for chunk in chunks.iter_mut() {
+            if let Some(ref mut parent) = chunk.metadata.parent_chunk_id {
+                *parent = renamed
+                    .entry(parent.clone())
+                    .or_insert_with(|| format!("{}-parent", chunk.id))
+                    .clone();
+            }
+        }
+    }
+
+    /// Number `chunks` in order and link each to its neighbors.
+    ///
+    /// Every algorithm runs this on its final output, so nested algorithms
+    /// (e.g. recursive) number the flattened result.
+    pub fn link_sequence(mut chunks: Vec<Chunk>) -> Vec<Chunk> {
+        let total = chunks.len();
+        let ids: Vec<String> = chunks.iter().map(|c| c.id.clone()).collect();
+        for (index, chunk) in chunks.iter_mut().enumerate() {
+            chunk.metadata.chunk_index = Some(index);
+            chunk.metadata.total_chunks = Some(total);
+            chunk.metadata.prev_chunk_id = index.checked_sub(1).map(|prev| ids[prev].clone());
+            chunk.metadata.next_chunk_id = ids.get(index + 1).cloned();
+        }
+        chunks
+    }
+
+    /// Apply the config's text normalization options to the chunk text.
+    ///
+    /// Control characters are stripped, then the text is Unicode-normalized,
+    /// whitespace collapsed and trimmed. `start` and `end` keep referring to
+    /// the original span, so the text may no longer equal that slice.
+    pub fn normalize_text(&mut self, config: &ChunkConfig) {
+        if config.strip_control_chars {
+            self.text.retain(|c| !c.is_control() || c.is_whitespace());
+        }
+        if let Some(form) = config.normalize_unicode {
+            self.text = form.normalize(&self.text);
+        }
+        if config.collapse_whitespace {
+            let mut collapsed = String::with_capacity(self.text.len());
+            let mut after_space = false;
+            for c in self.text.chars() {
+                let space = c.is_whitespace();
+                if !(space && after_space) {
+                    collapsed.push(if space { ' ' } else { c });
+                }
+                after_space = space;
+            }
+            self.text = collapsed;
+        }
+        if config.trim && self.text.trim() != self.text {
+            self.text = self.text.trim().to_string();
+        }
+    }
+
+    /// Apply config-driven post-processing: text normalization, ID strategy,
+    /// then checksums.
+    ///
+    /// Neighbor links are rebuilt after the IDs are replaced.
+    pub fn finalize(mut chunks: Vec<Chunk>, config: &ChunkConfig) -> Vec<Chunk> {
+        chunks.iter_mut().for_each(|c| c.normalize_text(config));
+        Chunk::assign_ids(&mut chunks, config);
+        Chunk::finish(chunks, config)
+    }
+
+    /// Lazily apply [`finalize`](Self::finalize) to a chunk iterator.
+    ///
+    /// The total and the next chunk are unknown until iteration ends, so
+    /// `total_chunks` and `next_chunk_id` stay unset and parent IDs are kept.
+    pub fn finalize_iter(
+        chunks: impl Iterator<Item = Chunk>,
+        config: ChunkConfig,
+    ) -> impl Iterator<Item = Chunk> {
+        let mut prev_id: Option<String> = None;
+        chunks.enumerate().map(move |(index, mut chunk)| {
+            chunk.normalize_text(&config);
+            if config.id_strategy != IdStrategy::Uuid4 {
+                chunk.id = chunk.strategy_id(index, &config);
+            }
+            chunk.finish_one(index, prev_id.replace(chunk.id.clone()), &config);
+            chunk
+        })
+    }
+
+    /// The part of [`finalize`](Self::finalize) after IDs are assigned:
+    /// neighbor links, config `extra`, checksums and language detection.
+    pub(crate) fn finish(mut chunks: Vec<Chunk>, config: &ChunkConfig) -> Vec<Chunk> {
+        let total = chunks.len();
+        let ids: Vec<String> = chunks.iter().map(|c| c.id.clone()).collect();
+        for (index, chunk) in chunks.iter_mut().enumerate() {
+            let prev_id = index.checked_sub(1).map(|prev| ids[prev].clone());
+            chunk.finish_one(index, prev_id, config);
+            chunk.metadata.total_chunks = Some(total);
+            chunk.metadata.next_chunk_id = ids.get(index + 1).cloned();
+        }
+        chunks
+    }
+
+    /// Finish the chunk at `index` once its ID is assigned: set its index
+    /// and the ID of the previous chunk, then apply config `extra`,
+    /// checksums and language detection.
+    pub fn finish_one(&mut self, index: usize, prev_id: Option<String>, config: &ChunkConfig) {
+        self.metadata.chunk_index = Some(index);
+        self.metadata.prev_chunk_id = prev_id;
+        self.metadata.merge_extra(&config.extra);
+        if config.compute_checksum {
+            self.set_checksum();
+        }
+        if config.detect_language {
+            self.detect_language();
+        }
+    }
+
+    /// Set `metadata.language` and `metadata.language_confidence` from the
+    /// dominant language of the text.
+    pub fn detect_language(&mut self) {
+        let detected = crate::language::detect_language(&self.text);
+        self.metadata.language = detected.map(|(lang, _)| lang.to_string());
+        self.metadata.language_confidence = detected.map(|(_, confidence)| confidence);
+    }
+
+    /// Derive the ID of the chunk at `index` for a non-UUID `config.id_strategy`.
+    pub(crate) fn strategy_id(&self, index: usize, config: &ChunkConfig) -> String {
+        match config.id_strategy {
+            IdStrategy::ContentHash => self.content_hash_id(config.doc_id.as_deref()),
+            _ => format!("{}-{}", config.doc_id.as_deref().unwrap_or("doc"), index),
+        }
+    }
+
     /// Create a new chunk with auto-generated UUID.
     pub fn with_uuid(text: String, start: usize, end: usize, metadata: ChunkMetadata) -> Self {
         Self {
@@ -1,9 +1,20 @@
 //! Configuration types for chunking operations.
 
 use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyTuple};
+
+use crate::algorithms::compile_pattern;
+use crate::error::ChunkError;
+use crate::token_estimate::TokenEstimator;
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap};
+use std::str::FromStr;
+use unicode_normalization::{
+    is_nfc_quick, is_nfd_quick, is_nfkc_quick, is_nfkd_quick, IsNormalized, UnicodeNormalization,
+};
 
 /// Sentence detection method.
-#[pyclass(eq, eq_int)]
+#[pyclass(eq, eq_int, module = "bunkatsu")]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum SentenceDetector {
     /// Fast regex-based detection (handles common cases: . ! ?)
@@ -11,17 +22,695 @@ pub enum SentenceDetector {
     Regex,
     /// Accurate Unicode-aware segmentation
     Unicode,
+    /// Regex detection that skips abbreviations (Dr., e.g.) and single-letter initials
+    Smart,
+    /// Boundaries from an external segmenter (falls back to Regex when none is supplied)
+    Custom,
+    /// Regex detection that also ends sentences at fullwidth and non-Latin
+    /// terminators (。．！？… । ؟) without trailing whitespace, falling back to
+    /// Unicode segmentation for text without punctuation
+    Multilingual,
+}
+
+#[pymethods]
+impl SentenceDetector {
+    /// Pickle support: rebuild the variant by name.
+    fn __reduce__<'py>(
+        &self,
+        py: Python<'py>,
+    ) -> PyResult<(Bound<'py, PyAny>, Bound<'py, PyTuple>)> {
+        let getattr = py.import("builtins")?.getattr("getattr")?;
+        let name = match self {
+            SentenceDetector::Regex => "Regex",
+            SentenceDetector::Unicode => "Unicode",
+            SentenceDetector::Smart => "Smart",
+            SentenceDetector::Custom => "Custom",
+            SentenceDetector::Multilingual => "Multilingual",
+        };
+        let args = (py.get_type::<Self>(), name).into_pyobject(py)?;
+        Ok((getattr, args))
+    }
+}
+
+/// How chunk IDs are generated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IdStrategy {
+    /// Random UUIDv4 per chunk ("uuid")
+    #[default]
+    Uuid4,
+    /// Stable hash of the chunk text, span and method ("hash")
+    ContentHash,
+    /// `doc_id`-prefixed incrementing IDs such as "doc-0", "doc-1" ("sequential")
+    Sequential,
+}
+
+impl IdStrategy {
+    /// Name used for this strategy in Python.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            IdStrategy::Uuid4 => "uuid",
+            IdStrategy::ContentHash => "hash",
+            IdStrategy::Sequential => "sequential",
+        }
+    }
+}
+
+impl FromStr for IdStrategy {
+    type Err = ChunkError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "uuid" => Ok(IdStrategy::Uuid4),
+            "hash" => Ok(IdStrategy::ContentHash),
+            "sequential" => Ok(IdStrategy::Sequential),
+            _ => Err(ChunkError::InvalidConfig(format!(
+                "id_strategy must be 'uuid', 'hash' or 'sequential', got {:?}",
+                s
+            ))),
+        }
+    }
+}
+
+/// Characters that end a sentence unless `ChunkConfig::sentence_terminators` overrides them.
+pub const DEFAULT_SENTENCE_TERMINATORS: &[char] = &['.', '!', '?'];
+
+/// Default paragraph separator: a blank line (which may hold whitespace or
+/// end in `\r\n`).
+pub const DEFAULT_PARAGRAPH_SEPARATOR: &str = "\n\n";
+
+/// Unit in which sliding-window `max_size` and `overlap` are measured.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WindowUnit {
+    /// Characters ("chars")
+    #[default]
+    Chars,
+    /// Whole sentences found by the configured sentence detector ("sentences")
+    Sentences,
+}
+
+impl WindowUnit {
+    /// Name used for this unit in Python.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WindowUnit::Chars => "chars",
+            WindowUnit::Sentences => "sentences",
+        }
+    }
+}
+
+impl FromStr for WindowUnit {
+    type Err = ChunkError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "chars" => Ok(WindowUnit::Chars),
+            "sentences" => Ok(WindowUnit::Sentences),
+            _ => Err(ChunkError::InvalidConfig(format!(
+                "window unit must be 'chars' or 'sentences', got {:?}",
+                s
+            ))),
+        }
+    }
+}
+
+/// Unit in which fixed-size `max_size` is measured.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SizeMode {
+    /// Characters, or grapheme clusters with `grapheme_boundaries` ("chars")
+    #[default]
+    Chars,
+    /// Terminal display columns: full-width CJK counts 2, zero-width and
+    /// combining characters count 0 ("display_width")
+    DisplayWidth,
+}
+
+impl SizeMode {
+    /// Name used for this mode in Python.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SizeMode::Chars => "chars",
+            SizeMode::DisplayWidth => "display_width",
+        }
+    }
+}
+
+impl FromStr for SizeMode {
+    type Err = ChunkError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "chars" => Ok(SizeMode::Chars),
+            "display_width" => Ok(SizeMode::DisplayWidth),
+            _ => Err(ChunkError::InvalidConfig(format!(
+                "size mode must be 'chars' or 'display_width', got {:?}",
+                s
+            ))),
+        }
+    }
+}
+
+/// Unicode normalization form.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NormalizationForm {
+    /// Canonical composition ("nfc")
+    Nfc,
+    /// Canonical decomposition ("nfd")
+    Nfd,
+    /// Compatibility composition ("nfkc")
+    Nfkc,
+    /// Compatibility decomposition ("nfkd")
+    Nfkd,
+}
+
+impl NormalizationForm {
+    /// Name used for this form in Python.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NormalizationForm::Nfc => "nfc",
+            NormalizationForm::Nfd => "nfd",
+            NormalizationForm::Nfkc => "nfkc",
+            NormalizationForm::Nfkd => "nfkd",
+        }
+    }
+
+    /// Normalize `text` to this form.
+    pub fn normalize(&self, text: &str) -> String {
+        match self {
+            NormalizationForm::Nfc => text.nfc().collect(),
+            NormalizationForm::Nfd => text.nfd().collect(),
+            NormalizationForm::Nfkc => text.nfkc().collect(),
+            NormalizationForm::Nfkd => text.nfkd().collect(),
+        }
+    }
+
+    /// Whether `text` is certainly in this form already (a quick check
+    /// that may answer false for normalized text).
+    fn is_normalized(&self, text: &str) -> bool {
+        let quick = match self {
+            NormalizationForm::Nfc => is_nfc_quick(text.chars()),
+            NormalizationForm::Nfd => is_nfd_quick(text.chars()),
+            NormalizationForm::Nfkc => is_nfkc_quick(text.chars()),
+            NormalizationForm::Nfkd => is_nfkd_quick(text.chars()),
+        };
+        quick == IsNormalized::Yes
+    }
+}
+
+impl FromStr for NormalizationForm {
+    type Err = ChunkError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "nfc" => Ok(NormalizationForm::Nfc),
+            "nfd" => Ok(NormalizationForm::Nfd),
+            "nfkc" => Ok(NormalizationForm::Nfkc),
+            "nfkd" => Ok(NormalizationForm::Nfkd),
+            _ => Err(ChunkError::InvalidConfig(format!(
+                "normalization form must be 'nfc', 'nfd', 'nfkc' or 'nfkd', got {:?}",
+                s
+            ))),
+        }
+    }
 }
 
 /// Configuration for chunking operations.
-#[derive(Debug, Clone)]
+///
+/// Configs compare equal when every field matches. Callback fields (such as a
+/// custom token counter) cannot be compared by value; if one is added,
+/// `PartialEq` must become a manual impl that skips it or uses pointer equality.
+#[pyclass(module = "bunkatsu")]
+#[derive(Debug, Clone, PartialEq)]
 pub struct ChunkConfig {
     /// Maximum size of each chunk in characters.
+    #[pyo3(get, set)]
     pub max_size: usize,
     /// Number of overlapping characters between chunks (for sliding window).
+    #[pyo3(get, set)]
     pub overlap: usize,
     /// Sentence detection method.
+    #[pyo3(get, set)]
     pub sentence_detector: SentenceDetector,
+    /// Make fixed-size chunks end at whitespace instead of mid-word.
+    #[pyo3(get, set)]
+    pub respect_word_boundaries: bool,
+    /// Allow markdown chunks to end inside an inline code span (skips the safety check).
+    #[pyo3(get, set)]
+    pub split_inside_inline_code: bool,
+    /// Keep markdown math blocks (`$$...$$`) as atomic units.
+    #[pyo3(get, set)]
+    pub treat_math_as_atomic: bool,
+    /// Keep balanced raw HTML blocks in markdown as atomic units.
+    #[pyo3(get, set)]
+    pub preserve_html_blocks: bool,
+    /// Separator string for delimiter-based chunking.
+    #[pyo3(get, set)]
+    pub separator: Option<String>,
+    /// Keep the separator in delimiter-based chunk text.
+    #[pyo3(get, set)]
+    pub keep_separator: bool,
+    /// Never merge paragraphs separated by more than this many blank lines.
+    #[pyo3(get, set)]
+    pub max_merge_gap: Option<usize>,
+    /// Number of whole lines repeated between consecutive line-based chunks.
+    #[pyo3(get, set)]
+    pub overlap_lines: usize,
+    /// Regular expression for regex-based chunking.
+    #[pyo3(get, set)]
+    pub pattern: Option<String>,
+    /// How chunk IDs are generated (exposed to Python as a string).
+    pub id_strategy: IdStrategy,
+    /// Document identifier used to prefix sequential IDs and salt content-hash IDs.
+    #[pyo3(get, set)]
+    pub doc_id: Option<String>,
+    /// Raise an error for empty or whitespace-only input instead of returning no chunks.
+    #[pyo3(get, set)]
+    pub error_on_empty: bool,
+    /// Store a CRC32 checksum of each chunk's text in its metadata.
+    #[pyo3(get, set)]
+    pub compute_checksum: bool,
+    /// Abbreviations the smart sentence detector never splits after (None uses English defaults).
+    #[pyo3(get, set)]
+    pub abbreviations: Option<Vec<String>>,
+    /// Never end a sentence inside an open quote, parenthesis or bracket.
+    #[pyo3(get, set)]
+    pub respect_enclosures: bool,
+    /// Unit of sliding-window `max_size` and `overlap` (exposed to Python as a string).
+    pub window_unit: WindowUnit,
+    /// Characters that end a sentence for the regex and smart detectors
+    /// (CJK terminators are always recognized).
+    #[pyo3(get, set)]
+    pub sentence_terminators: Vec<char>,
+    /// Document metadata copied into every chunk's `metadata.extra`.
+    ///
+    /// Keys the chunking algorithm sets itself take precedence.
+    #[pyo3(get, set)]
+    pub extra: HashMap<String, String>,
+    /// Keep a paragraph larger than max_size whole instead of splitting it by sentence.
+    #[pyo3(get, set)]
+    pub strict_paragraphs: bool,
+    /// Separator between paragraphs for the paragraph chunkers. The default
+    /// `"\n\n"` matches any blank line; other values are matched literally.
+    #[pyo3(get, set)]
+    pub paragraph_separator: String,
+    /// Emit the verbatim source slice as chunk text, keeping the whitespace and
+    /// separators between chunks, so concatenating the chunks rebuilds the
+    /// input. Applies to the fixed_size, sentence and paragraph chunkers.
+    /// Packing still measures the trimmed text, so a chunk can exceed
+    /// max_size by the whitespace it keeps.
+    #[pyo3(get, set)]
+    pub preserve_separators: bool,
+    /// Trim leading and trailing whitespace from chunk text.
+    #[pyo3(get, set)]
+    pub trim: bool,
+    /// Replace each run of whitespace in chunk text with a single space.
+    #[pyo3(get, set)]
+    pub collapse_whitespace: bool,
+    /// Unicode normalization form for chunk text (exposed to Python as
+    /// "nfc", "nfd", "nfkc", "nfkd" or None). Applied to each chunk after
+    /// chunking, so it runs after `normalization` when both are set and
+    /// its form wins in the chunk text, while positions still refer to the
+    /// text as normalized by `normalization`.
+    pub normalize_unicode: Option<NormalizationForm>,
+    /// Remove control characters other than whitespace from chunk text.
+    #[pyo3(get, set)]
+    pub strip_control_chars: bool,
+    /// Unicode normalization form applied to the input before chunking
+    /// (exposed to Python as a string or None). Chunk positions then refer
+    /// to the normalized text, which may differ in length from the input.
+    /// Runs before `normalize_unicode`, which may renormalize chunk text.
+    pub normalization: Option<NormalizationForm>,
+    /// Count fixed-size `max_size` in extended grapheme clusters and only
+    /// end chunks between clusters, so emoji sequences and combining marks
+    /// stay whole.
+    #[pyo3(get, set)]
+    pub grapheme_boundaries: bool,
+    /// Unit of fixed-size `max_size` (exposed to Python as a string).
+    pub size_mode: SizeMode,
+    /// Size sentence, paragraph and markdown chunks in tokens instead of
+    /// bytes, up to this many per chunk. Fallback splits of single
+    /// over-long sentences still use `max_size`.
+    #[pyo3(get, set)]
+    pub max_tokens: Option<usize>,
+    /// How tokens are counted for `max_tokens` (exposed to Python as a string).
+    pub token_estimator: TokenEstimator,
+    /// Spread sentences or paragraphs so chunk sizes are as even as
+    /// possible, instead of filling each chunk greedily and leaving a small
+    /// last one. Uses as many chunks as greedy packing.
+    #[pyo3(get, set)]
+    pub balanced: bool,
+    /// Tag each chunk with its dominant language in `metadata.language`.
+    /// Uses whatlang with the `langdetect` feature and a script-range
+    /// heuristic otherwise.
+    #[pyo3(get, set)]
+    pub detect_language: bool,
+    /// Prefix markdown and heading chunks with their innermost section title
+    /// ("Installation:\n"), recorded in `metadata.extra["section_prefix"]`.
+    /// `start`/`end` still cover only the source text, and the prefix does not
+    /// count toward `max_size`.
+    #[pyo3(get, set)]
+    pub prepend_section: bool,
+    /// Drop empty segments left by back-to-back, leading or trailing delimiters
+    /// in delimiter and regex chunking. When false they are kept as empty
+    /// segments (just the separator with `keep_separator`).
+    #[pyo3(get, set)]
+    pub skip_empty_segments: bool,
+}
+
+#[pymethods]
+impl ChunkConfig {
+    /// Create a new ChunkConfig from Python.
+    #[new]
+    #[pyo3(signature = (
+        max_size=512,
+        overlap=0,
+        sentence_detector=SentenceDetector::Regex,
+        respect_word_boundaries=false,
+        split_inside_inline_code=false,
+        treat_math_as_atomic=true,
+        preserve_html_blocks=false,
+        separator=None,
+        keep_separator=false,
+        max_merge_gap=None,
+        overlap_lines=0,
+        pattern=None,
+        id_strategy="uuid",
+        doc_id=None,
+        error_on_empty=false,
+        compute_checksum=false,
+        abbreviations=None,
+        respect_enclosures=false,
+        window_unit="chars",
+        sentence_terminators=DEFAULT_SENTENCE_TERMINATORS.to_vec(),
+        extra=None,
+        strict_paragraphs=false,
+        paragraph_separator=DEFAULT_PARAGRAPH_SEPARATOR.to_string(),
+        preserve_separators=false,
+        trim=false,
+        collapse_whitespace=false,
+        normalize_unicode=None,
+        strip_control_chars=false,
+        normalization=None,
+        grapheme_boundaries=false,
+        size_mode="chars",
+        max_tokens=None,
+        token_estimator="cl100k_estimate",
+        balanced=false,
+        detect_language=false,
+        prepend_section=false,
+        skip_empty_segments=true,
+    ))]
+    #[allow(clippy::too_many_arguments)]
+    fn py_new(
+        max_size: usize,
+        overlap: usize,
+        sentence_detector: SentenceDetector,
+        respect_word_boundaries: bool,
+        split_inside_inline_code: bool,
+        treat_math_as_atomic: bool,
+        preserve_html_blocks: bool,
+        separator: Option<String>,
+        keep_separator: bool,
+        max_merge_gap: Option<usize>,
+        overlap_lines: usize,
+        pattern: Option<String>,
+        id_strategy: &str,
+        doc_id: Option<String>,
+        error_on_empty: bool,
+        compute_checksum: bool,
+        abbreviations: Option<Vec<String>>,
+        respect_enclosures: bool,
+        window_unit: &str,
+        sentence_terminators: Vec<char>,
+        extra: Option<HashMap<String, String>>,
+        strict_paragraphs: bool,
+        paragraph_separator: String,
+        preserve_separators: bool,
+        trim: bool,
+        collapse_whitespace: bool,
+        normalize_unicode: Option<&str>,
+        strip_control_chars: bool,
+        normalization: Option<&str>,
+        grapheme_boundaries: bool,
+        size_mode: &str,
+        max_tokens: Option<usize>,
+        token_estimator: &str,
+        balanced: bool,
+        detect_language: bool,
+        prepend_section: bool,
+        skip_empty_segments: bool,
+    ) -> PyResult<Self> {
+        Ok(Self {
+            max_size,
+            overlap,
+            sentence_detector,
+            respect_word_boundaries,
+            split_inside_inline_code,
+            treat_math_as_atomic,
+            preserve_html_blocks,
+            separator,
+            keep_separator,
+            max_merge_gap,
+            overlap_lines,
+            pattern,
+            id_strategy: id_strategy.parse()?,
+            doc_id,
+            error_on_empty,
+            compute_checksum,
+            abbreviations,
+            respect_enclosures,
+            window_unit: window_unit.parse()?,
+            sentence_terminators,
+            extra: extra.unwrap_or_default(),
+            strict_paragraphs,
+            paragraph_separator,
+            preserve_separators,
+            trim,
+            collapse_whitespace,
+            normalize_unicode: normalize_unicode.map(str::parse).transpose()?,
+            strip_control_chars,
+            normalization: normalization.map(str::parse).transpose()?,
+            grapheme_boundaries,
+            size_mode: size_mode.parse()?,
+            max_tokens,
+            token_estimator: token_estimator.parse()?,
+            balanced,
+            detect_language,
+            prepend_section,
+            skip_empty_segments,
+        })
+    }
+
+    /// How chunk IDs are generated: "uuid", "hash" or "sequential".
+    #[getter(id_strategy)]
+    fn py_id_strategy(&self) -> &'static str {
+        self.id_strategy.as_str()
+    }
+
+    #[setter(id_strategy)]
+    fn py_set_id_strategy(&mut self, value: &str) -> PyResult<()> {
+        self.id_strategy = value.parse()?;
+        Ok(())
+    }
+
+    /// Unit of sliding-window sizes: "chars" or "sentences".
+    #[getter(window_unit)]
+    fn py_window_unit(&self) -> &'static str {
+        self.window_unit.as_str()
+    }
+
+    /// Unicode normalization form for chunk text: "nfc", "nfd", "nfkc", "nfkd" or None.
+    #[getter(normalize_unicode)]
+    fn py_normalize_unicode(&self) -> Option<&'static str> {
+        self.normalize_unicode
+            .as_ref()
+            .map(NormalizationForm::as_str)
+    }
+
+    #[setter(normalize_unicode)]
+    fn py_set_normalize_unicode(&mut self, value: Option<&str>) -> PyResult<()> {
+        self.normalize_unicode = value.map(str::parse).transpose()?;
+        Ok(())
+    }
+
+    /// Unicode normalization form for the input text: "nfc", "nfd", "nfkc", "nfkd" or None.
+    #[getter(normalization)]
+    fn py_normalization(&self) -> Option<&'static str> {
+        self.normalization.as_ref().map(NormalizationForm::as_str)
+    }
+
+    #[setter(normalization)]
+    fn py_set_normalization(&mut self, value: Option<&str>) -> PyResult<()> {
+        self.normalization = value.map(str::parse).transpose()?;
+        Ok(())
+    }
+
+    #[setter(window_unit)]
+    fn py_set_window_unit(&mut self, value: &str) -> PyResult<()> {
+        self.window_unit = value.parse()?;
+        Ok(())
+    }
+
+    /// Unit of fixed-size `max_size`: "chars" or "display_width".
+    #[getter(size_mode)]
+    fn py_size_mode(&self) -> &'static str {
+        self.size_mode.as_str()
+    }
+
+    #[setter(size_mode)]
+    fn py_set_size_mode(&mut self, value: &str) -> PyResult<()> {
+        self.size_mode = value.parse()?;
+        Ok(())
+    }
+
+    /// Token counter for `max_tokens`: "cl100k_estimate" or "cl100k".
+    #[getter(token_estimator)]
+    fn py_token_estimator(&self) -> &'static str {
+        self.token_estimator.as_str()
+    }
+
+    #[setter(token_estimator)]
+    fn py_set_token_estimator(&mut self, value: &str) -> PyResult<()> {
+        self.token_estimator = value.parse()?;
+        Ok(())
+    }
+
+    /// Check the configuration, raising ValueError if it is invalid.
+    #[pyo3(name = "validate")]
+    fn py_validate(&self) -> PyResult<()> {
+        Ok(self.validate()?)
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "ChunkConfig(max_size={}, overlap={}, sentence_detector=SentenceDetector.{:?}, \
+             respect_word_boundaries={}, split_inside_inline_code={}, treat_math_as_atomic={}, preserve_html_blocks={}, \
+             separator={}, keep_separator={}, max_merge_gap={}, overlap_lines={}, pattern={}, id_strategy='{}', doc_id={}, error_on_empty={}, compute_checksum={}, abbreviations={}, respect_enclosures={}, window_unit='{}', sentence_terminators={:?}, extra={:?}, strict_paragraphs={}, paragraph_separator={:?}, preserve_separators={}, trim={}, collapse_whitespace={}, normalize_unicode={}, strip_control_chars={}, normalization={}, grapheme_boundaries={}, size_mode='{}', max_tokens={}, token_estimator='{}', balanced={}, detect_language={}, prepend_section={}, skip_empty_segments={})",
+            self.max_size,
+            self.overlap,
+            self.sentence_detector,
+            py_bool(self.respect_word_boundaries),
+            py_bool(self.split_inside_inline_code),
+            py_bool(self.treat_math_as_atomic),
+            py_bool(self.preserve_html_blocks),
+            self.separator
+                .as_ref()
+                .map_or("None".to_string(), |s| format!("{:?}", s)),
+            py_bool(self.keep_separator),
+            self.max_merge_gap
+                .map_or("None".to_string(), |gap| gap.to_string()),
+            self.overlap_lines,
+            self.pattern
+                .as_ref()
+                .map_or("None".to_string(), |s| format!("{:?}", s)),
+            self.id_strategy.as_str(),
+            self.doc_id
+                .as_ref()
+                .map_or("None".to_string(), |s| format!("{:?}", s)),
+            py_bool(self.error_on_empty),
+            py_bool(self.compute_checksum),
+            self.abbreviations
+                .as_ref()
+                .map_or("None".to_string(), |list| format!("{:?}", list)),
+            py_bool(self.respect_enclosures),
+            self.window_unit.as_str(),
+            self.sentence_terminators,
+            self.extra.iter().collect::<BTreeMap<_, _>>(),
+            py_bool(self.strict_paragraphs),
+            self.paragraph_separator,
+            py_bool(self.preserve_separators),
+            py_bool(self.trim),
+            py_bool(self.collapse_whitespace),
+            self.normalize_unicode
+                .map_or("None".to_string(), |form| format!("'{}'", form.as_str())),
+            py_bool(self.strip_control_chars),
+            self.normalization
+                .map_or("None".to_string(), |form| format!("'{}'", form.as_str())),
+            py_bool(self.grapheme_boundaries),
+            self.size_mode.as_str(),
+            self.max_tokens
+                .map_or("None".to_string(), |tokens| tokens.to_string()),
+            self.token_estimator.as_str(),
+            py_bool(self.balanced),
+            py_bool(self.detect_language),
+            py_bool(self.prepend_section),
+            py_bool(self.skip_empty_segments),
+        )
+    }
+
+    fn __eq__(&self, other: &Self) -> bool {
+        self == other
+    }
+
+    /// Pickle support: constructor keyword arguments.
+    fn __getnewargs_ex__<'py>(
+        &self,
+        py: Python<'py>,
+    ) -> PyResult<(Bound<'py, PyTuple>, Bound<'py, PyDict>)> {
+        let kwargs = PyDict::new(py);
+        kwargs.set_item("max_size", self.max_size)?;
+        kwargs.set_item("overlap", self.overlap)?;
+        kwargs.set_item("sentence_detector", self.sentence_detector)?;
+        kwargs.set_item("respect_word_boundaries", self.respect_word_boundaries)?;
+        kwargs.set_item("split_inside_inline_code", self.split_inside_inline_code)?;
+        kwargs.set_item("treat_math_as_atomic", self.treat_math_as_atomic)?;
+        kwargs.set_item("preserve_html_blocks", self.preserve_html_blocks)?;
+        kwargs.set_item("separator", self.separator.clone())?;
+        kwargs.set_item("keep_separator", self.keep_separator)?;
+        kwargs.set_item("max_merge_gap", self.max_merge_gap)?;
+        kwargs.set_item("overlap_lines", self.overlap_lines)?;
+        kwargs.set_item("pattern", self.pattern.clone())?;
+        kwargs.set_item("id_strategy", self.id_strategy.as_str())?;
+        kwargs.set_item("doc_id", self.doc_id.clone())?;
+        kwargs.set_item("error_on_empty", self.error_on_empty)?;
+        kwargs.set_item("compute_checksum", self.compute_checksum)?;
+        kwargs.set_item("abbreviations", self.abbreviations.clone())?;
+        kwargs.set_item("respect_enclosures", self.respect_enclosures)?;
+        kwargs.set_item("window_unit", self.window_unit.as_str())?;
+        kwargs.set_item("sentence_terminators", self.sentence_terminators.clone())?;
+        kwargs.set_item("extra", self.extra.clone())?;
+        kwargs.set_item("strict_paragraphs", self.strict_paragraphs)?;
+        kwargs.set_item("paragraph_separator", &self.paragraph_separator)?;
+        kwargs.set_item("preserve_separators", self.preserve_separators)?;
+        kwargs.set_item("trim", self.trim)?;
+        kwargs.set_item("collapse_whitespace", self.collapse_whitespace)?;
+        kwargs.set_item(
+            "normalize_unicode",
+            self.normalize_unicode
+                .as_ref()
+                .map(NormalizationForm::as_str),
+        )?;
+        kwargs.set_item("strip_control_chars", self.strip_control_chars)?;
+        kwargs.set_item(
+            "normalization",
+            self.normalization.as_ref().map(NormalizationForm::as_str),
+        )?;
+        kwargs.set_item("grapheme_boundaries", self.grapheme_boundaries)?;
+        kwargs.set_item("size_mode", self.size_mode.as_str())?;
+        kwargs.set_item("max_tokens", self.max_tokens)?;
+        kwargs.set_item("token_estimator", self.token_estimator.as_str())?;
+        kwargs.set_item("balanced", self.balanced)?;
+        kwargs.set_item("detect_language", self.detect_language)?;
+        kwargs.set_item("prepend_section", self.prepend_section)?;
+        kwargs.set_item("skip_empty_segments", self.skip_empty_segments)?;
+        Ok((PyTuple::empty(py), kwargs))
+    }
+
+    fn __copy__(&self) -> Self {
+        self.clone()
+    }
+
+    fn __deepcopy__(&self, _memo: &Bound<'_, PyAny>) -> Self {
+        self.clone()
+    }
+}
+
+/// Format a bool the way Python prints it.
+fn py_bool(value: bool) -> &'static str {
+    if value {
+        "True"
+    } else {
+        "False"
+    }
 }
 
 impl Default for ChunkConfig {
@@ -30,6 +719,40 @@ impl Default for ChunkConfig {
             max_size: 512,
             overlap: 0,
             sentence_detector: SentenceDetector::Regex,
+            respect_word_boundaries: false,
+            split_inside_inline_code: false,
+            treat_math_as_atomic: true,
+            preserve_html_blocks: false,
+            separator: None,
+            keep_separator: false,
+            max_merge_gap: None,
+            overlap_lines: 0,
+            pattern: None,
+            id_strategy: IdStrategy::Uuid4,
+            doc_id: None,
+            error_on_empty: false,
+            compute_checksum: false,
+            abbreviations: None,
+            respect_enclosures: false,
+            window_unit: WindowUnit::Chars,
+            sentence_terminators: DEFAULT_SENTENCE_TERMINATORS.to_vec(),
+            extra: HashMap::new(),
+            strict_paragraphs: false,
+            paragraph_separator: DEFAULT_PARAGRAPH_SEPARATOR.to_string(),
+            preserve_separators: false,
+            trim: false,
+            collapse_whitespace: false,
+            normalize_unicode: None,
+            strip_control_chars: false,
+            normalization: None,
+            grapheme_boundaries: false,
+            size_mode: SizeMode::Chars,
+            max_tokens: None,
+            token_estimator: TokenEstimator::Cl100kEstimate,
+            balanced: false,
+            detect_language: false,
+            prepend_section: false,
+            skip_empty_segments: true,
         }
     }
 }
@@ -43,6 +766,106 @@ impl ChunkConfig {
         }
     }
 
+    /// Create a new validated configuration with the specified max size.
+    pub fn try_new(max_size: usize) -> Result<Self, ChunkError> {
+        let config = Self::new(max_size);
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Check that the configuration values are usable for chunking.
+    pub fn validate(&self) -> Result<(), ChunkError> {
+        if self.max_size == 0 {
+            return Err(ChunkError::InvalidConfig(
+                "max_size must be greater than 0".to_string(),
+            ));
+        }
+        if self.overlap >= self.max_size {
+            return Err(ChunkError::InvalidConfig(format!(
+                "overlap ({}) must be less than max_size ({})",
+                self.overlap, self.max_size
+            )));
+        }
+        if self.max_tokens == Some(0) {
+            return Err(ChunkError::InvalidConfig(
+                "max_tokens must be greater than 0".to_string(),
+            ));
+        }
+        if self.sentence_terminators.is_empty() {
+            return Err(ChunkError::InvalidConfig(
+                "sentence_terminators must not be empty".to_string(),
+            ));
+        }
+        if self.separator.as_deref() == Some("") {
+            return Err(ChunkError::InvalidConfig(
+                "separator must not be empty".to_string(),
+            ));
+        }
+        if self.paragraph_separator.is_empty() {
+            return Err(ChunkError::InvalidConfig(
+                "paragraph_separator must not be empty".to_string(),
+            ));
+        }
+        if let Some(ref pattern) = self.pattern {
+            compile_pattern(pattern)?;
+        }
+        Ok(())
+    }
+
+    /// Size of `text` as counted against the chunk limit: its length in
+    /// bytes, or its token count when `max_tokens` is set.
+    pub fn measure(&self, text: &str) -> usize {
+        match self.max_tokens {
+            Some(_) => self.token_estimator.count(text),
+            None => text.len(),
+        }
+    }
+
+    /// Size of `a` and `b` joined by `separator`, as counted by [`measure`](Self::measure).
+    ///
+    /// Tokens are counted per part, which is close to but not exactly the
+    /// count of the joined text.
+    pub fn joined_size(&self, a: &str, separator: &str, b: &str) -> usize {
+        self.measure(a) + self.measure(separator) + self.measure(b)
+    }
+
+    /// Chunk limit in the unit of [`measure`](Self::measure): `max_tokens`
+    /// if set, otherwise `max_size`.
+    pub fn size_limit(&self) -> usize {
+        self.max_tokens.unwrap_or(self.max_size)
+    }
+
+    /// The input as chunked: `text` in the configured normalization form.
+    ///
+    /// Borrows `text` when no normalization is set or it is already normalized.
+    pub fn normalize_input<'t>(&self, text: &'t str) -> Cow<'t, str> {
+        match self.normalization {
+            Some(form) if !form.is_normalized(text) => Cow::Owned(form.normalize(text)),
+            _ => Cow::Borrowed(text),
+        }
+    }
+
+    /// Like [`normalize_input`](Self::normalize_input) for owned text, reusing it when unchanged.
+    pub(crate) fn normalize_owned(&self, text: String) -> String {
+        let normalized = match self.normalize_input(&text) {
+            Cow::Owned(normalized) => Some(normalized),
+            Cow::Borrowed(_) => None,
+        };
+        normalized.unwrap_or(text)
+    }
+
+    /// Check that `text` is acceptable input under this configuration.
+    ///
+    /// With `error_on_empty` set, empty or whitespace-only text yields
+    /// [`ChunkError::EmptyInput`] so callers can tell it apart from
+    /// non-empty input that produced no chunks.
+    pub fn check_input(&self, text: &str) -> Result<(), ChunkError> {
+        if self.error_on_empty && text.trim().is_empty() {
+            return Err(ChunkError::EmptyInput);
+        }
+        Ok(())
+    }
+
     /// Set the overlap for sliding window chunking.
     pub fn with_overlap(mut self, overlap: usize) -> Self {
         self.overlap = overlap;
@@ -54,4 +877,346 @@ impl ChunkConfig {
         self.sentence_detector = detector;
         self
     }
+
+    /// Set whether fixed-size chunks count and break at grapheme clusters.
+    pub fn with_grapheme_boundaries(mut self, graphemes: bool) -> Self {
+        self.grapheme_boundaries = graphemes;
+        self
+    }
+
+    /// Size sentence, paragraph and markdown chunks in tokens counted by `estimator`.
+    pub fn with_max_tokens(mut self, max_tokens: usize, estimator: TokenEstimator) -> Self {
+        self.max_tokens = Some(max_tokens);
+        self.token_estimator = estimator;
+        self
+    }
+
+    /// Set whether chunks are tagged with their dominant language.
+    pub fn with_detect_language(mut self, detect: bool) -> Self {
+        self.detect_language = detect;
+        self
+    }
+
+    /// Set whether markdown and heading chunks are prefixed with their section title.
+    pub fn with_prepend_section(mut self, prepend: bool) -> Self {
+        self.prepend_section = prepend;
+        self
+    }
+
+    /// Set whether delimiter and regex chunking drop empty segments.
+    pub fn with_skip_empty_segments(mut self, skip: bool) -> Self {
+        self.skip_empty_segments = skip;
+        self
+    }
+
+    /// Set whether sentence and paragraph chunks are balanced in size.
+    pub fn with_balanced(mut self, balanced: bool) -> Self {
+        self.balanced = balanced;
+        self
+    }
+
+    /// Set the unit of fixed-size `max_size`.
+    pub fn with_size_mode(mut self, mode: SizeMode) -> Self {
+        self.size_mode = mode;
+        self
+    }
+
+    /// Set whether fixed-size chunks avoid ending mid-word.
+    pub fn with_respect_word_boundaries(mut self, respect: bool) -> Self {
+        self.respect_word_boundaries = respect;
+        self
+    }
+
+    /// Allow markdown chunk boundaries to fall inside inline code spans.
+    pub fn with_split_inside_inline_code(mut self, split: bool) -> Self {
+        self.split_inside_inline_code = split;
+        self
+    }
+
+    /// Set whether markdown math blocks are kept as atomic units.
+    pub fn with_treat_math_as_atomic(mut self, atomic: bool) -> Self {
+        self.treat_math_as_atomic = atomic;
+        self
+    }
+
+    /// Set whether balanced raw HTML blocks in markdown are kept as atomic units.
+    pub fn with_preserve_html_blocks(mut self, preserve: bool) -> Self {
+        self.preserve_html_blocks = preserve;
+        self
+    }
+
+    /// Set the separator for delimiter-based chunking.
+    pub fn with_separator(mut self, separator: impl Into<String>) -> Self {
+        self.separator = Some(separator.into());
+        self
+    }
+
+    /// Set whether delimiter-based chunks keep the separator in their text.
+    pub fn with_keep_separator(mut self, keep: bool) -> Self {
+        self.keep_separator = keep;
+        self
+    }
+
+    /// Set how chunk IDs are generated.
+    pub fn with_id_strategy(mut self, strategy: IdStrategy) -> Self {
+        self.id_strategy = strategy;
+        self
+    }
+
+    /// Set the document identifier used in generated chunk IDs.
+    pub fn with_doc_id(mut self, doc_id: impl Into<String>) -> Self {
+        self.doc_id = Some(doc_id.into());
+        self
+    }
+
+    /// Set whether empty or whitespace-only input is an error.
+    pub fn with_error_on_empty(mut self, error: bool) -> Self {
+        self.error_on_empty = error;
+        self
+    }
+
+    /// Set the largest blank-line gap across which paragraphs may be merged.
+    pub fn with_max_merge_gap(mut self, max_gap: usize) -> Self {
+        self.max_merge_gap = Some(max_gap);
+        self
+    }
+
+    /// Set the number of whole lines repeated between line-based chunks.
+    pub fn with_overlap_lines(mut self, lines: usize) -> Self {
+        self.overlap_lines = lines;
+        self
+    }
+
+    /// Set the regular expression for regex-based chunking.
+    pub fn with_pattern(mut self, pattern: impl Into<String>) -> Self {
+        self.pattern = Some(pattern.into());
+        self
+    }
+
+    /// Set whether each chunk gets a CRC32 checksum of its text.
+    pub fn with_compute_checksum(mut self, compute: bool) -> Self {
+        self.compute_checksum = compute;
+        self
+    }
+
+    /// Set the abbreviations the smart sentence detector never splits after.
+    pub fn with_abbreviations<I, S>(mut self, abbreviations: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.abbreviations = Some(abbreviations.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Set whether sentences may end inside an open quote, parenthesis or bracket.
+    pub fn with_respect_enclosures(mut self, respect: bool) -> Self {
+        self.respect_enclosures = respect;
+        self
+    }
+
+    /// Set the unit of sliding-window `max_size` and `overlap`.
+    pub fn with_window_unit(mut self, unit: WindowUnit) -> Self {
+        self.window_unit = unit;
+        self
+    }
+
+    /// Set the characters that end a sentence for the regex and smart detectors.
+    pub fn with_sentence_terminators(
+        mut self,
+        terminators: impl IntoIterator<Item = char>,
+    ) -> Self {
+        self.sentence_terminators = terminators.into_iter().collect();
+        self
+    }
+
+    /// Set whether a paragraph larger than max_size is kept whole.
+    pub fn with_strict_paragraphs(mut self, strict: bool) -> Self {
+        self.strict_paragraphs = strict;
+        self
+    }
+
+    /// Set the separator between paragraphs (default: a blank line).
+    pub fn with_paragraph_separator(mut self, separator: impl Into<String>) -> Self {
+        self.paragraph_separator = separator.into();
+        self
+    }
+
+    /// Set whether chunk text is the verbatim source slice, separators included.
+    pub fn with_preserve_separators(mut self, preserve: bool) -> Self {
+        self.preserve_separators = preserve;
+        self
+    }
+
+    /// Set whether chunk text is trimmed.
+    pub fn with_trim(mut self, trim: bool) -> Self {
+        self.trim = trim;
+        self
+    }
+
+    /// Set whether whitespace runs in chunk text collapse to a single space.
+    pub fn with_collapse_whitespace(mut self, collapse: bool) -> Self {
+        self.collapse_whitespace = collapse;
+        self
+    }
+
+    /// Set the Unicode normalization form for chunk text.
+    pub fn with_normalize_unicode(mut self, form: Option<NormalizationForm>) -> Self {
+        self.normalize_unicode = form;
+        self
+    }
+
+    /// Set whether non-whitespace control characters are removed from chunk text.
+    pub fn with_strip_control_chars(mut self, strip: bool) -> Self {
+        self.strip_control_chars = strip;
+        self
+    }
+
+    /// Set the Unicode normalization form applied to the input before chunking.
+    pub fn with_normalization(mut self, form: Option<NormalizationForm>) -> Self {
+        self.normalization = form;
+        self
+    }
+
+    /// Set document metadata copied into every chunk's `metadata.extra`.
+    pub fn with_extra<K, V>(mut self, extra: impl IntoIterator<Item = (K, V)>) -> Self
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.extra = extra
+            .into_iter()
+            .map(|(key, value)| (key.into(), value.into()))
+            .collect();
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config_eq() {
+        assert_eq!(ChunkConfig::new(10), ChunkConfig::new(10));
+        assert_eq!(
+            ChunkConfig::new(10).with_overlap(2),
+            ChunkConfig {
+                overlap: 2,
+                ..ChunkConfig::new(10)
+            }
+        );
+        assert_ne!(
+            ChunkConfig::new(10),
+            ChunkConfig::new(10).with_sentence_detector(SentenceDetector::Unicode)
+        );
+    }
+
+    #[test]
+    fn test_validate_ok() {
+        assert!(ChunkConfig::new(10).with_overlap(5).validate().is_ok());
+        assert!(ChunkConfig::try_new(1).is_ok());
+    }
+
+    #[test]
+    fn test_validate_zero_max_size() {
+        let err = ChunkConfig::try_new(0).unwrap_err();
+        assert!(err.to_string().contains("max_size"));
+    }
+
+    #[test]
+    fn test_validate_overlap_too_large() {
+        let err = ChunkConfig::new(5).with_overlap(5).validate().unwrap_err();
+        assert!(matches!(err, ChunkError::InvalidConfig(_)));
+        assert!(err.to_string().contains("overlap"));
+    }
+
+    #[test]
+    fn test_validate_empty_terminators() {
+        let err = ChunkConfig::new(10)
+            .with_sentence_terminators([])
+            .validate()
+            .unwrap_err();
+        assert!(err.to_string().contains("sentence_terminators"));
+    }
+
+    #[test]
+    fn test_normalization_form_parse() {
+        assert_eq!(
+            "NFKC".parse::<NormalizationForm>().unwrap(),
+            NormalizationForm::Nfkc
+        );
+        assert_eq!(NormalizationForm::Nfc.normalize("e\u{301}"), "\u{e9}");
+        assert!("nfx".parse::<NormalizationForm>().is_err());
+    }
+
+    #[test]
+    fn test_normalize_input() {
+        let nfd = "Cafe\u{301} cre\u{300}me";
+        let config = ChunkConfig::new(10).with_normalization(Some(NormalizationForm::Nfc));
+        assert_eq!(config.normalize_input(nfd), "Caf\u{e9} cr\u{e8}me");
+        assert!(matches!(
+            config.normalize_input("plain"),
+            Cow::Borrowed("plain")
+        ));
+        assert!(matches!(
+            ChunkConfig::new(10).normalize_input(nfd),
+            Cow::Borrowed(_)
+        ));
+        let nfd_config = config.with_normalization(Some(NormalizationForm::Nfd));
+        assert_eq!(nfd_config.normalize_input("Caf\u{e9}"), "Cafe\u{301}");
+    }
+
+    #[test]
+    fn test_max_tokens_measure() {
+        let config = ChunkConfig::new(100);
+        assert_eq!(config.measure("hello world"), 11);
+        assert_eq!(config.size_limit(), 100);
+
+        let config = config.with_max_tokens(5, TokenEstimator::Cl100kEstimate);
+        assert_eq!(config.measure("hello world"), 2);
+        assert_eq!(config.size_limit(), 5);
+        assert!(config.validate().is_ok());
+        assert!(ChunkConfig::new(100)
+            .with_max_tokens(0, TokenEstimator::Cl100kEstimate)
+            .validate()
+            .is_err());
+    }
+
+    #[test]
+    fn test_validate_empty_paragraph_separator() {
+        let err = ChunkConfig::new(10)
+            .with_paragraph_separator("")
+            .validate()
+            .unwrap_err();
+        assert!(err.to_string().contains("paragraph_separator"));
+    }
+
+    #[test]
+    fn test_check_input() {
+        let strict = ChunkConfig::new(10).with_error_on_empty(true);
+        assert!(matches!(
+            strict.check_input(""),
+            Err(ChunkError::EmptyInput)
+        ));
+        assert!(matches!(
+            strict.check_input(" \n\t "),
+            Err(ChunkError::EmptyInput)
+        ));
+        assert!(strict.check_input("text").is_ok());
+        assert!(ChunkConfig::new(10).check_input("").is_ok());
+    }
+
+    #[test]
+    fn test_id_strategy_parse() {
+        assert_eq!(
+            "hash".parse::<IdStrategy>().unwrap(),
+            IdStrategy::ContentHash
+        );
+        assert_eq!(
+            "sequential".parse::<IdStrategy>().unwrap().as_str(),
+            "sequential"
+        );
+        assert!("random".parse::<IdStrategy>().is_err());
+    }
 }
@@ -1,9 +1,43 @@
 //! Error types for Bunkatsu chunking library.
 
+use pyo3::create_exception;
 use pyo3::exceptions::PyValueError;
 use pyo3::PyErr;
 use thiserror::Error;
 
+// Python exception hierarchy. The base subclasses ValueError so existing
+// `except ValueError` handlers keep working.
+create_exception!(
+    bunkatsu,
+    BunkatsuError,
+    PyValueError,
+    "Base class for bunkatsu errors."
+);
+create_exception!(
+    bunkatsu,
+    InvalidConfigError,
+    BunkatsuError,
+    "Invalid configuration or arguments."
+);
+create_exception!(
+    bunkatsu,
+    ProcessingError,
+    BunkatsuError,
+    "Error while processing the input text."
+);
+create_exception!(
+    bunkatsu,
+    EmptyInputError,
+    BunkatsuError,
+    "Input text was empty or whitespace-only with error_on_empty set."
+);
+create_exception!(
+    bunkatsu,
+    AlgorithmNotFoundError,
+    BunkatsuError,
+    "Unknown chunking method."
+);
+
 /// Errors that can occur during chunking operations.
 #[derive(Debug, Error)]
 pub enum ChunkError {
@@ -15,6 +49,10 @@ pub enum ChunkError {
     #[error("Text processing error: {0}")]
     ProcessingError(String),
 
+    /// Input text was empty or whitespace-only (with `error_on_empty` set).
+    #[error("Input text is empty")]
+    EmptyInput,
+
     /// Algorithm not found in registry.
     #[error("Algorithm not found: {0}")]
     AlgorithmNotFound(String),
@@ -22,6 +60,12 @@ pub enum ChunkError {
 
 impl From<ChunkError> for PyErr {
     fn from(err: ChunkError) -> PyErr {
-        PyValueError::new_err(err.to_string())
+        let message = err.to_string();
+        match err {
+            ChunkError::InvalidConfig(_) => InvalidConfigError::new_err(message),
+            ChunkError::ProcessingError(_) => ProcessingError::new_err(message),
+            ChunkError::EmptyInput => EmptyInputError::new_err(message),
+            ChunkError::AlgorithmNotFound(_) => AlgorithmNotFoundError::new_err(message),
+        }
     }
 }
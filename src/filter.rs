@@ -0,0 +1,89 @@
+//! Filters for selecting chunks by quality metrics.
+
+use crate::chunk::Chunk;
+
+/// A predicate used to retain or discard chunks.
+pub struct ChunkFilter {
+    predicate: Box<dyn Fn(&Chunk) -> bool + Send + Sync>,
+}
+
+impl ChunkFilter {
+    /// Create a filter from an arbitrary predicate.
+    pub fn new<F>(predicate: F) -> Self
+    where
+        F: Fn(&Chunk) -> bool + Send + Sync + 'static,
+    {
+        Self {
+            predicate: Box::new(predicate),
+        }
+    }
+
+    /// Keep only chunks whose lexical richness is at least `threshold`.
+    pub fn by_min_lexical_richness(threshold: f32) -> Self {
+        Self::new(move |chunk| chunk.lexical_richness() >= threshold)
+    }
+
+    /// Check whether a chunk passes this filter.
+    pub fn matches(&self, chunk: &Chunk) -> bool {
+        (self.predicate)(chunk)
+    }
+
+    /// Retain only the chunks that pass this filter.
+    pub fn apply(&self, chunks: Vec<Chunk>) -> Vec<Chunk> {
+        chunks.into_iter().filter(|c| self.matches(c)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chunk::ChunkMetadata;
+
+    fn chunk(text: &str) -> Chunk {
+        Chunk::with_uuid(
+            text.to_string(),
+            0,
+            text.len(),
+            ChunkMetadata::for_method("test"),
+        )
+    }
+
+    #[test]
+    fn test_lexical_richness_unique() {
+        assert_eq!(chunk("cat dog bird").lexical_richness(), 1.0);
+    }
+
+    #[test]
+    fn test_lexical_richness_repeated() {
+        // 2 unique words out of 4
+        assert_eq!(chunk("the the the dog").lexical_richness(), 0.5);
+        assert!(chunk("The THE the the dog").lexical_richness() < 0.5);
+    }
+
+    #[test]
+    fn test_lexical_richness_empty() {
+        assert_eq!(chunk("  ").lexical_richness(), 0.0);
+    }
+
+    #[test]
+    fn test_filter_by_min_lexical_richness() {
+        let chunks = vec![chunk("cat dog bird"), chunk("the the the dog")];
+        let kept = ChunkFilter::by_min_lexical_richness(0.8).apply(chunks);
+
+        assert_eq!(kept.len(), 1);
+        assert_eq!(kept[0].text, "cat dog bird");
+    }
+
+    #[test]
+    fn test_checksum_roundtrip() {
+        let mut c = chunk("hello world");
+        assert!(!c.verify_checksum());
+
+        c.set_checksum();
+        assert_eq!(c.metadata.checksum.as_deref(), Some("0d4a1185"));
+        assert!(c.verify_checksum());
+
+        c.text.push('!');
+        assert!(!c.verify_checksum());
+    }
+}
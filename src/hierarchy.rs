@@ -0,0 +1,190 @@
+//! Two-level chunking for parent document retrieval: large parent chunks,
+//! each split into small child chunks that point back at their parent.
+
+use crate::chunk::Chunk;
+use crate::config::ChunkConfig;
+use crate::traits::ChunkAlgorithm;
+use std::sync::Arc;
+
+/// Parent chunks and the child chunks split from them.
+#[derive(Debug, Clone, Default)]
+pub struct Hierarchy {
+    /// Parent chunks, in document order.
+    pub parents: Vec<Chunk>,
+    /// Child chunks of every parent, in document order. Each has
+    /// `metadata.parent_chunk_id` set to its parent's ID.
+    pub children: Vec<Chunk>,
+}
+
+impl Hierarchy {
+    /// Apply config-driven post-processing to both levels, as [`Chunk::finalize`] does.
+    ///
+    /// IDs are assigned over the parents and then the children, so
+    /// sequential IDs stay unique across levels and children keep pointing
+    /// at their renamed parents. Each level is numbered and linked on its own.
+    pub fn finalize(self, config: &ChunkConfig) -> Self {
+        let split = self.parents.len();
+        let mut chunks = self.parents;
+        chunks.extend(self.children);
+        chunks.iter_mut().for_each(|c| c.normalize_text(config));
+        Chunk::assign_ids(&mut chunks, config);
+
+        let children = chunks.split_off(split);
+        Self {
+            parents: Chunk::finish(chunks, config),
+            children: Chunk::finish(children, config),
+        }
+    }
+}
+
+/// Chunker that splits text with one algorithm and each resulting chunk
+/// again with another.
+///
+/// ```
+/// use bunkatsu::{ChunkConfig, HierarchicalChunker, ParagraphChunker, SentenceChunker};
+/// use std::sync::Arc;
+///
+/// let chunker = HierarchicalChunker::new(Arc::new(ParagraphChunker), Arc::new(SentenceChunker), 20);
+/// let text = "First sentence. Second sentence.\n\nAnother paragraph.";
+/// let hierarchy = chunker.chunk(text, &ChunkConfig::new(1000));
+/// assert_eq!(hierarchy.parents.len(), 1);
+/// assert_eq!(hierarchy.children.len(), 3);
+/// ```
+#[derive(Clone)]
+pub struct HierarchicalChunker {
+    /// Algorithm producing the parent chunks, sized by `config.max_size`.
+    pub parent: Arc<dyn ChunkAlgorithm>,
+    /// Algorithm splitting each parent into children.
+    pub child: Arc<dyn ChunkAlgorithm>,
+    /// Maximum child size, replacing `config.max_size` for the children.
+    pub child_size: usize,
+}
+
+impl HierarchicalChunker {
+    /// Create a chunker splitting `parent` chunks into `child` chunks of up to `child_size`.
+    pub fn new(
+        parent: Arc<dyn ChunkAlgorithm>,
+        child: Arc<dyn ChunkAlgorithm>,
+        child_size: usize,
+    ) -> Self {
+        Self {
+            parent,
+            child,
+            child_size,
+        }
+    }
+
+    /// Config used for the child level: `config` with `max_size` set to `child_size`.
+    pub fn child_config(&self, config: &ChunkConfig) -> ChunkConfig {
+        let mut child_config = config.clone();
+        child_config.max_size = self.child_size;
+        child_config
+    }
+
+    /// Chunk `text` into parents, then each parent's span of `text` into children.
+    ///
+    /// Children are cut from the source span of their parent rather than
+    /// its text, so both levels carry document offsets and every child lies
+    /// within its parent. Children without a section inherit the parent's.
+    /// IDs are UUIDs; see [`Hierarchy::finalize`] for the config's ID strategy.
+    pub fn chunk(&self, text: &str, config: &ChunkConfig) -> Hierarchy {
+        let parents = self.parent.chunk(text, config);
+        let child_config = self.child_config(config);
+        let mut children = Vec::new();
+
+        for parent in &parents {
+            for mut child in self
+                .child
+                .chunk(&text[parent.start..parent.end], &child_config)
+            {
+                child.start += parent.start;
+                child.end += parent.start;
+                child.metadata.parent_chunk_id = Some(parent.id.clone());
+                if child.metadata.section.is_none() {
+                    child.metadata.section = parent.metadata.section.clone();
+                    child.metadata.section_path = parent.metadata.section_path.clone();
+                }
+                children.push(child);
+            }
+        }
+
+        Hierarchy {
+            parents,
+            children: Chunk::link_sequence(children),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::{HeadingChunker, ParagraphChunker, SentenceChunker};
+    use crate::config::IdStrategy;
+    use std::collections::HashMap;
+
+    fn assert_nested(text: &str, hierarchy: &Hierarchy) {
+        let parents: HashMap<&str, &Chunk> = hierarchy
+            .parents
+            .iter()
+            .map(|p| (p.id.as_str(), p))
+            .collect();
+        for child in &hierarchy.children {
+            let parent = parents[child.metadata.parent_chunk_id.as_deref().unwrap()];
+            assert!(parent.start <= child.start && child.end <= parent.end);
+            assert_eq!(&text[child.start..child.end], child.text);
+        }
+    }
+
+    #[test]
+    fn test_hierarchy_paragraph_sentence() {
+        let text = "One fish. Two fish.\n\nRed fish. Blue fish. Old fish.\n\nNew fish.";
+        let chunker =
+            HierarchicalChunker::new(Arc::new(ParagraphChunker), Arc::new(SentenceChunker), 10);
+        let hierarchy = chunker.chunk(text, &ChunkConfig::new(45));
+
+        assert_eq!(hierarchy.parents.len(), 2);
+        let texts: Vec<&str> = hierarchy.children.iter().map(|c| c.text.as_str()).collect();
+        assert_eq!(
+            texts,
+            vec![
+                "One fish.",
+                "Two fish.",
+                "Red fish.",
+                "Blue fish.",
+                "Old fish.",
+                "New fish."
+            ]
+        );
+        assert_nested(text, &hierarchy);
+        assert_eq!(hierarchy.children[5].metadata.chunk_index, Some(5));
+        assert_eq!(
+            hierarchy.children[5].metadata.parent_chunk_id.as_ref(),
+            Some(&hierarchy.parents[1].id)
+        );
+    }
+
+    #[test]
+    fn test_hierarchy_inherits_section_and_finalizes() {
+        let text = "# Intro\n\nHello there. General Kenobi.\n\n# Usage\n\nRun it.";
+        let chunker = HierarchicalChunker::new(
+            Arc::new(HeadingChunker::default()),
+            Arc::new(SentenceChunker),
+            15,
+        );
+        let config = ChunkConfig::new(1000).with_id_strategy(IdStrategy::Sequential);
+        let hierarchy = chunker.chunk(text, &config).finalize(&config);
+        assert_nested(text, &hierarchy);
+
+        let ids: Vec<&str> = hierarchy.parents.iter().map(|c| c.id.as_str()).collect();
+        assert_eq!(ids, vec!["doc-0", "doc-1"]);
+        assert_eq!(hierarchy.children[0].id, "doc-2");
+        assert_eq!(
+            hierarchy.children[0].metadata.section.as_deref(),
+            Some("h1: Intro")
+        );
+        let last = hierarchy.children.last().unwrap();
+        assert_eq!(last.metadata.parent_chunk_id.as_deref(), Some("doc-1"));
+        assert_eq!(last.metadata.total_chunks, Some(hierarchy.children.len()));
+        assert_eq!(hierarchy.parents[1].metadata.next_chunk_id, None);
+    }
+}
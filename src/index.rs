@@ -0,0 +1,143 @@
+//! Reverse lookup from document offsets to the chunks that cover them.
+
+use crate::chunk::Chunk;
+
+/// Chunk spans sorted by start, with the running maximum end.
+///
+/// Shared by [`ChunkIndex`] and the Python `ChunkIndex`, which owns its
+/// chunk IDs instead of borrowing chunks.
+#[derive(Debug, Clone)]
+pub(crate) struct SpanIndex {
+    /// `(start, end, position in the original slice)`, sorted by start then end.
+    spans: Vec<(usize, usize, usize)>,
+    /// `max_ends[i]` is the largest end among `spans[..=i]`.
+    max_ends: Vec<usize>,
+}
+
+impl SpanIndex {
+    /// Index the spans of `chunks`.
+    pub(crate) fn new(chunks: &[Chunk]) -> Self {
+        let mut spans: Vec<(usize, usize, usize)> = chunks
+            .iter()
+            .enumerate()
+            .map(|(i, c)| (c.start, c.end, i))
+            .collect();
+        spans.sort_unstable();
+        let max_ends = spans
+            .iter()
+            .scan(0, |max, &(_, end, _)| {
+                *max = end.max(*max);
+                Some(*max)
+            })
+            .collect();
+        Self { spans, max_ends }
+    }
+
+    /// Positions of the chunks overlapping `start..end`, in span order.
+    ///
+    /// An empty range finds the chunks containing `start`.
+    pub(crate) fn find_range(&self, start: usize, end: usize) -> Vec<usize> {
+        let end = end.max(start + 1);
+        // Spans before `first` all end at or before `start`
+        let first = self.max_ends.partition_point(|&max| max <= start);
+        let last = self.spans.partition_point(|&(s, _, _)| s < end);
+        self.spans
+            .get(first..last)
+            .unwrap_or_default()
+            .iter()
+            .filter(|&&(_, e, _)| e > start)
+            .map(|&(_, _, i)| i)
+            .collect()
+    }
+}
+
+/// Index over a slice of chunks for finding the chunks at a byte offset.
+///
+/// Overlapping chunks (for instance from the sliding window) are all
+/// returned, ordered by start. Offsets are bytes, like [`Chunk::start`].
+#[derive(Debug, Clone)]
+pub struct ChunkIndex<'a> {
+    chunks: &'a [Chunk],
+    spans: SpanIndex,
+}
+
+impl<'a> ChunkIndex<'a> {
+    /// Build an index over `chunks`; they need not be sorted.
+    pub fn new(chunks: &'a [Chunk]) -> Self {
+        Self {
+            chunks,
+            spans: SpanIndex::new(chunks),
+        }
+    }
+
+    /// Chunks whose span contains `offset`.
+    pub fn find(&self, offset: usize) -> Vec<&'a Chunk> {
+        self.find_range(offset, offset + 1)
+    }
+
+    /// Chunks whose span overlaps `start..end`.
+    ///
+    /// An empty range behaves like [`find`](Self::find) at `start`.
+    pub fn find_range(&self, start: usize, end: usize) -> Vec<&'a Chunk> {
+        self.spans
+            .find_range(start, end)
+            .into_iter()
+            .map(|i| &self.chunks[i])
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::{RecursiveChunker, SlidingWindowChunker};
+    use crate::config::ChunkConfig;
+    use crate::traits::ChunkAlgorithm;
+
+    #[test]
+    fn test_index_sliding_window_overlaps() {
+        let chunks =
+            SlidingWindowChunker.chunk("hello world!", &ChunkConfig::new(5).with_overlap(2));
+        let index = ChunkIndex::new(&chunks);
+        let texts = |found: Vec<&Chunk>| found.iter().map(|c| c.text.clone()).collect::<Vec<_>>();
+
+        // "hello" 0..5, "lo wo" 3..8, "world" 6..11, "ld!" 9..12
+        assert_eq!(texts(index.find(0)), vec!["hello"]);
+        assert_eq!(texts(index.find(3)), vec!["hello", "lo wo"]);
+        assert_eq!(texts(index.find(5)), vec!["lo wo"]);
+        assert_eq!(texts(index.find(10)), vec!["world", "ld!"]);
+        assert!(index.find(12).is_empty());
+        assert_eq!(
+            texts(index.find_range(4, 7)),
+            vec!["hello", "lo wo", "world"]
+        );
+        assert_eq!(texts(index.find_range(9, 9)), vec!["world", "ld!"]);
+    }
+
+    #[test]
+    fn test_index_recursive_gaps() {
+        let text = "First paragraph here.\n\n\n\nSecond paragraph here.";
+        let chunks = RecursiveChunker::default().chunk(text, &ChunkConfig::new(25));
+        assert_eq!(chunks.len(), 2);
+        let index = ChunkIndex::new(&chunks);
+
+        assert_eq!(index.find(0)[0].id, chunks[0].id);
+        // The blank lines between paragraphs belong to no chunk
+        assert!(index.find(chunks[0].end + 1).is_empty());
+        assert!(index.find_range(chunks[0].end, chunks[1].start).is_empty());
+        assert_eq!(index.find(chunks[1].start)[0].id, chunks[1].id);
+        assert_eq!(index.find_range(0, text.len()).len(), 2);
+    }
+
+    #[test]
+    fn test_index_unsorted_and_empty() {
+        let mut chunks =
+            SlidingWindowChunker.chunk("abcdefgh", &ChunkConfig::new(3).with_overlap(1));
+        chunks.reverse();
+        let index = ChunkIndex::new(&chunks);
+        let starts: Vec<usize> = index.find_range(0, 8).iter().map(|c| c.start).collect();
+        assert_eq!(starts, vec![0, 2, 4, 6]);
+
+        assert!(ChunkIndex::new(&[]).find(0).is_empty());
+    }
+}
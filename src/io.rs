@@ -0,0 +1,99 @@
+//! JSON Lines (JSONL / NDJSON) export and import of chunk batches.
+//!
+//! Each line holds one chunk as a JSON object with the same field names as
+//! [`Chunk`] and [`ChunkMetadata`](crate::ChunkMetadata). Unset optional
+//! fields are written as `null`; on read, missing metadata fields take their
+//! defaults and unknown fields are ignored, so files written by other
+//! versions still load.
+
+use crate::chunk::Chunk;
+use crate::error::ChunkError;
+use std::fmt::Display;
+use std::io::{BufRead, Write};
+
+/// Write `chunks` to `w`, one JSON object per line.
+pub fn write_jsonl<W: Write>(chunks: &[Chunk], mut w: W) -> Result<(), ChunkError> {
+    for chunk in chunks {
+        serde_json::to_writer(&mut w, chunk).map_err(write_error)?;
+        w.write_all(b"\n").map_err(write_error)?;
+    }
+    w.flush().map_err(write_error)
+}
+
+fn write_error(e: impl Display) -> ChunkError {
+    ChunkError::ProcessingError(format!("JSONL write failed: {}", e))
+}
+
+/// Read chunks written by [`write_jsonl`] from `r`, skipping blank lines.
+pub fn read_jsonl<R: BufRead>(r: R) -> Result<Vec<Chunk>, ChunkError> {
+    let mut chunks = Vec::new();
+    for (index, line) in r.lines().enumerate() {
+        let line =
+            line.map_err(|e| ChunkError::ProcessingError(format!("JSONL read failed: {}", e)))?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let chunk = serde_json::from_str(&line).map_err(|e| {
+            ChunkError::ProcessingError(format!("invalid JSONL on line {}: {}", index + 1, e))
+        })?;
+        chunks.push(chunk);
+    }
+    Ok(chunks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::MarkdownChunker;
+    use crate::chunk::ChunkMetadata;
+    use crate::config::ChunkConfig;
+    use crate::traits::ChunkAlgorithm;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_jsonl_round_trip() {
+        let text = "# Guide\n\nIntro.\n\n## Setup\n\n| a | b |\n|---|---|\n| 1 | 2 |";
+        let config = ChunkConfig::new(1000).with_compute_checksum(true);
+        let mut chunks = Chunk::finalize(MarkdownChunker::default().chunk(text, &config), &config);
+        chunks[0]
+            .metadata
+            .extra
+            .insert("source".to_string(), "a.md".to_string());
+        chunks[1].metadata.language = Some("en".to_string());
+        chunks[1].metadata.language_confidence = Some(0.75);
+        chunks[1].metadata.merged_from = Some(vec!["x".to_string(), "y".to_string()]);
+
+        let mut buf = Vec::new();
+        write_jsonl(&chunks, &mut buf).unwrap();
+        assert_eq!(buf.iter().filter(|&&b| b == b'\n').count(), chunks.len());
+
+        let read = read_jsonl(buf.as_slice()).unwrap();
+        assert_eq!(read.len(), chunks.len());
+        for (a, b) in chunks.iter().zip(&read) {
+            assert_eq!(
+                (&a.id, &a.text, a.start, a.end),
+                (&b.id, &b.text, b.start, b.end)
+            );
+            assert_eq!(format!("{:?}", a.metadata), format!("{:?}", b.metadata));
+        }
+        assert_eq!(read[0].metadata.overlap_chars, None);
+        assert!(read.iter().all(|c| c.metadata.checksum.is_some()));
+    }
+
+    #[test]
+    fn test_read_jsonl_unknown_and_missing_fields() {
+        let input = r#"{"id":"a","text":"hi","start":0,"end":2,"score":1.5,"metadata":{"method":"line","future":[1]}}
+
+{"id":"b","text":"yo","start":3,"end":5,"metadata":{"method":"line","section":null}}
+"#;
+        let chunks = read_jsonl(input.as_bytes()).unwrap();
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[0].metadata.method, "line");
+        assert_eq!(chunks[0].metadata.extra, HashMap::new());
+        let expected = ChunkMetadata::for_method("line");
+        assert_eq!(chunks[1].metadata, expected);
+
+        let err = read_jsonl("{\"id\":\"a\"}\nnot json".as_bytes()).unwrap_err();
+        assert!(err.to_string().contains("line 1"));
+    }
+}
@@ -0,0 +1,344 @@
+//! Dominant-language detection for chunk metadata.
+//!
+//! Without features this tells languages apart by Unicode script, and
+//! Latin-script languages by common function words. The `langdetect`
+//! feature switches to whatlang, which covers about 70 languages.
+
+#[cfg(feature = "langdetect")]
+use whatlang::Lang;
+
+/// Detect the dominant language of `text` as an ISO 639-1 code and a
+/// confidence from 0.0 to 1.0, or `None` when no language can be told
+/// (e.g. empty text or only digits and punctuation).
+///
+/// Mixed-language text reports the language of its most common script, with
+/// a lower confidence.
+pub fn detect_language(text: &str) -> Option<(&'static str, f64)> {
+    #[cfg(feature = "langdetect")]
+    if let Some(info) = whatlang::detect(text) {
+        return Some((iso639_1(info.lang()), info.confidence()));
+    }
+    detect_by_script(text)
+}
+
+/// Scripts told apart by [`detect_by_script`], with the language reported
+/// for each (Latin and Han are refined further).
+const SCRIPTS: [&str; 16] = [
+    "la", "el", "ru", "hy", "he", "ar", "hi", "bn", "ta", "th", "ka", "ko", "ja", "zh", "te", "km",
+];
+const LATIN: usize = 0;
+const KANA: usize = 12;
+const HAN: usize = 13;
+
+/// Index into [`SCRIPTS`] of the script a letter is written in.
+fn script(c: char) -> Option<usize> {
+    let index = match c as u32 {
+        0x0041..=0x024F | 0x1E00..=0x1EFF => LATIN,
+        0x0370..=0x03FF | 0x1F00..=0x1FFF => 1,
+        0x0400..=0x052F => 2,
+        0x0530..=0x058F => 3,
+        0x0590..=0x05FF => 4,
+        0x0600..=0x06FF | 0x0750..=0x077F => 5,
+        0x0900..=0x097F => 6,
+        0x0980..=0x09FF => 7,
+        0x0B80..=0x0BFF => 8,
+        0x0E00..=0x0E7F => 9,
+        0x10A0..=0x10FF => 10,
+        0x1100..=0x11FF | 0x3130..=0x318F | 0xAC00..=0xD7AF => 11,
+        0x3040..=0x30FF | 0x31F0..=0x31FF | 0xFF66..=0xFF9F => KANA,
+        0x3400..=0x4DBF | 0x4E00..=0x9FFF | 0xF900..=0xFAFF => HAN,
+        0x0C00..=0x0C7F => 14,
+        0x1780..=0x17FF => 15,
+        _ => return None,
+    };
+    c.is_alphabetic().then_some(index)
+}
+
+/// Function words of common Latin-script languages, used to tell them
+/// apart when only the script is known.
+const STOPWORDS: [(&str, &[&str]); 7] = [
+    (
+        "en",
+        &[
+            "the", "and", "of", "to", "that", "with", "for", "was", "are", "this", "from", "which",
+            "can", "each", "its", "into", "have", "be", "it", "not", "so", "you",
+        ],
+    ),
+    (
+        "fr",
+        &[
+            "le", "la", "les", "des", "est", "et", "une", "dans", "que", "pour", "pas", "sur",
+            "qui", "avec", "ce", "du", "au", "sont",
+        ],
+    ),
+    (
+        "es",
+        &[
+            "el", "los", "las", "es", "y", "una", "por", "con", "para", "que", "del", "se", "como",
+            "pero", "más", "muy",
+        ],
+    ),
+    (
+        "pt",
+        &[
+            "o", "os", "é", "um", "uma", "não", "com", "para", "do", "da", "em", "que", "são",
+            "mais",
+        ],
+    ),
+    (
+        "de",
+        &[
+            "der", "die", "und", "das", "ist", "nicht", "ein", "eine", "mit", "den", "zu", "auf",
+            "ich", "sich", "auch", "sind",
+        ],
+    ),
+    (
+        "it",
+        &[
+            "il", "di", "che", "è", "e", "un", "per", "non", "sono", "della", "con", "gli", "nel",
+            "molto",
+        ],
+    ),
+    (
+        "nl",
+        &[
+            "de", "het", "een", "en", "van", "is", "niet", "op", "met", "zijn", "voor", "ook",
+        ],
+    ),
+];
+
+/// Detect the dominant language from the Unicode script of its letters.
+///
+/// Japanese is told from Chinese by kana (Han letters count as Japanese once
+/// any kana appears), and Latin-script text by [`STOPWORDS`]. Latin text
+/// without any known function word is reported as `None`. Confidence is the
+/// share of letters in the dominant script, scaled for Latin text by the
+/// share of function-word hits of the best language.
+pub(crate) fn detect_by_script(text: &str) -> Option<(&'static str, f64)> {
+    let mut counts = [0usize; SCRIPTS.len()];
+    for index in text.chars().filter_map(script) {
+        counts[index] += 1;
+    }
+    let total: usize = counts.iter().sum();
+    if counts[KANA] > 0 {
+        counts[KANA] += std::mem::take(&mut counts[HAN]);
+    }
+    let (dominant, &count) = counts
+        .iter()
+        .enumerate()
+        .max_by_key(|&(i, &n)| (n, usize::MAX - i))?;
+    if count == 0 {
+        return None;
+    }
+    let share = count as f64 / total as f64;
+    if dominant != LATIN {
+        return Some((SCRIPTS[dominant], share));
+    }
+
+    let mut hits = [0usize; STOPWORDS.len()];
+    for word in text
+        .split(|c: char| !c.is_alphabetic())
+        .filter(|w| !w.is_empty())
+    {
+        let word = word.to_lowercase();
+        for (i, (_, words)) in STOPWORDS.iter().enumerate() {
+            if words.contains(&word.as_str()) {
+                hits[i] += 1;
+            }
+        }
+    }
+    let (best, &best_hits) = hits
+        .iter()
+        .enumerate()
+        .max_by_key(|&(i, &n)| (n, usize::MAX - i))?;
+    if best_hits == 0 {
+        return None;
+    }
+    let hit_total: usize = hits.iter().sum();
+    Some((
+        STOPWORDS[best].0,
+        share * best_hits as f64 / hit_total as f64,
+    ))
+}
+
+/// ISO 639-1 code of a language detected by whatlang.
+#[cfg(feature = "langdetect")]
+fn iso639_1(lang: Lang) -> &'static str {
+    match lang {
+        Lang::Epo => "eo",
+        Lang::Eng => "en",
+        Lang::Rus => "ru",
+        Lang::Cmn => "zh",
+        Lang::Spa => "es",
+        Lang::Por => "pt",
+        Lang::Ita => "it",
+        Lang::Ben => "bn",
+        Lang::Fra => "fr",
+        Lang::Deu => "de",
+        Lang::Ukr => "uk",
+        Lang::Kat => "ka",
+        Lang::Ara => "ar",
+        Lang::Hin => "hi",
+        Lang::Jpn => "ja",
+        Lang::Heb => "he",
+        Lang::Yid => "yi",
+        Lang::Pol => "pl",
+        Lang::Amh => "am",
+        Lang::Jav => "jv",
+        Lang::Kor => "ko",
+        Lang::Nob => "nb",
+        Lang::Dan => "da",
+        Lang::Swe => "sv",
+        Lang::Fin => "fi",
+        Lang::Tur => "tr",
+        Lang::Nld => "nl",
+        Lang::Hun => "hu",
+        Lang::Ces => "cs",
+        Lang::Ell => "el",
+        Lang::Bul => "bg",
+        Lang::Bel => "be",
+        Lang::Mar => "mr",
+        Lang::Kan => "kn",
+        Lang::Ron => "ro",
+        Lang::Slv => "sl",
+        Lang::Hrv => "hr",
+        Lang::Srp => "sr",
+        Lang::Mkd => "mk",
+        Lang::Lit => "lt",
+        Lang::Lav => "lv",
+        Lang::Est => "et",
+        Lang::Tam => "ta",
+        Lang::Vie => "vi",
+        Lang::Urd => "ur",
+        Lang::Tha => "th",
+        Lang::Guj => "gu",
+        Lang::Uzb => "uz",
+        Lang::Pan => "pa",
+        Lang::Aze => "az",
+        Lang::Ind => "id",
+        Lang::Tel => "te",
+        Lang::Pes => "fa",
+        Lang::Mal => "ml",
+        Lang::Ori => "or",
+        Lang::Mya => "my",
+        Lang::Nep => "ne",
+        Lang::Sin => "si",
+        Lang::Khm => "km",
+        Lang::Tuk => "tk",
+        Lang::Aka => "ak",
+        Lang::Zul => "zu",
+        Lang::Sna => "sn",
+        Lang::Afr => "af",
+        Lang::Lat => "la",
+        Lang::Slk => "sk",
+        Lang::Cat => "ca",
+        Lang::Tgl => "tl",
+        Lang::Hye => "hy",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_language() {
+        let english = "Chunking splits long documents into smaller passages so that each one \
+                       can be embedded and retrieved on its own.";
+        let (lang, confidence) = detect_language(english).unwrap();
+        assert_eq!(lang, "en");
+        assert!(confidence > 0.9);
+
+        let japanese = "東京は日本の首都です。今日は天気が良いので、公園を散歩しました。";
+        assert_eq!(detect_language(japanese).unwrap().0, "ja");
+
+        // Mixed text reports the dominant language
+        let mostly_japanese =
+            "Hello. 今日は天気が良いので、公園を散歩しました。文章を分割して検索の精度を高めます。";
+        assert_eq!(detect_language(mostly_japanese).unwrap().0, "ja");
+        let mostly_english = "Chunking splits long documents into smaller passages so that each \
+                              one can be embedded. The word 分割 means split.";
+        assert_eq!(detect_language(mostly_english).unwrap().0, "en");
+
+        assert_eq!(detect_language(""), None);
+        assert_eq!(detect_language("12345 !!!"), None);
+    }
+
+    #[test]
+    fn test_finalize_detects_chunk_languages() {
+        use crate::algorithms::ParagraphChunker;
+        use crate::chunk::Chunk;
+        use crate::config::ChunkConfig;
+        use crate::registry::AlgorithmRegistry;
+        use crate::traits::ChunkAlgorithm;
+
+        let text = "Chunking splits long documents into smaller passages so that each one \
+                    can be embedded and retrieved on its own.\n\n\
+                    東京は日本の首都です。今日は天気が良いので、公園を散歩しました。";
+        let config = ChunkConfig::new(200).with_detect_language(true);
+        let chunks = Chunk::finalize(ParagraphChunker.chunk(text, &config), &config);
+        let languages: Vec<Option<&str>> = chunks
+            .iter()
+            .map(|c| c.metadata.language.as_deref())
+            .collect();
+        assert_eq!(languages, vec![Some("en"), Some("ja")]);
+        assert!(chunks[0].metadata.language_confidence.unwrap() > 0.9);
+
+        // Every entry point detects through the shared finalize step
+        let finalized = ParagraphChunker.chunk_finalized(text, &config).unwrap();
+        assert_eq!(finalized[1].metadata.language.as_deref(), Some("ja"));
+        let registered = AlgorithmRegistry::new()
+            .chunk_with("sentence", text, &config, "")
+            .unwrap();
+        assert_eq!(registered[0].metadata.language.as_deref(), Some("en"));
+        assert!(ParagraphChunker.chunk(text, &config)[0]
+            .metadata
+            .language
+            .is_none());
+
+        // Off by default
+        let chunks = Chunk::finalize(
+            ParagraphChunker.chunk(text, &config),
+            &ChunkConfig::new(200),
+        );
+        assert!(chunks.iter().all(|c| c.metadata.language.is_none()));
+    }
+
+    #[test]
+    fn test_detect_by_script() {
+        let cases = [
+            ("Привет, как дела? Сегодня хорошая погода.", "ru"),
+            ("오늘은 날씨가 좋아서 공원을 산책했습니다.", "ko"),
+            ("今天天气很好，我们去公园散步。", "zh"),
+            ("カタカナとひらがなと漢字。", "ja"),
+            ("Καλημέρα, τι κάνεις σήμερα;", "el"),
+            ("Der Hund und die Katze sind nicht im Haus.", "de"),
+            ("Le chat est dans la maison avec les enfants.", "fr"),
+            ("El perro y el gato están en la casa con los niños.", "es"),
+            ("The dog and the cat are in the house.", "en"),
+        ];
+        for (text, expected) in cases {
+            let (lang, confidence) = detect_by_script(text).unwrap();
+            assert_eq!(lang, expected, "{}", text);
+            assert!(confidence > 0.5, "{}", text);
+        }
+
+        // Mixed scripts lower the confidence
+        let (lang, confidence) = detect_by_script("東京 is the capital").unwrap();
+        assert_eq!(lang, "en");
+        assert!(confidence < 1.0);
+
+        // Latin text without known function words cannot be told
+        assert_eq!(detect_by_script("Lorem ipsum dolor"), None);
+        assert_eq!(detect_by_script("12345 !!!"), None);
+        assert_eq!(detect_by_script(""), None);
+    }
+
+    #[cfg(feature = "langdetect")]
+    #[test]
+    fn test_iso639_1_covers_every_language() {
+        for &lang in Lang::all() {
+            assert_eq!(iso639_1(lang).len(), 2, "{:?}", lang);
+        }
+    }
+}
@@ -12,31 +12,82 @@
 use pyo3::prelude::*;
 
 pub mod algorithms;
+pub mod builder;
 pub mod chunk;
 pub mod config;
 pub mod error;
+pub mod filter;
+pub mod hierarchy;
+pub mod index;
+pub mod io;
+pub mod language;
+pub mod merge;
 pub mod py_bindings;
 pub mod registry;
+pub mod stats;
+#[cfg(feature = "async")]
+pub mod stream;
+pub mod token_estimate;
 pub mod traits;
 
 // Re-exports
+#[cfg(feature = "xml")]
+pub use algorithms::XmlChunker;
 pub use algorithms::{
-    FixedSizeChunker, HeadingChunker, MarkdownChunker, ParagraphChunker, RecursiveChunker,
-    RecursiveStrategy, SentenceChunker, SlidingWindowChunker,
+    DelimiterChunker, FixedSizeChunker, FrontMatter, HeadingChunker, HtmlChunker, JsonChunker,
+    KeepDelimiter, LatexChunker, LineChunker, LineMode, MarkdownChunker, OutlineEntry,
+    ParagraphChunker, ParagraphSentenceChunker, RecursiveChunker, RecursiveStrategy, RegexChunker,
+    SentenceChunker, SlidingWindowChunker, WordChunker,
 };
+pub use builder::{BoundChunker, ChunkerBuilder};
 pub use chunk::{Chunk, ChunkMetadata};
-pub use config::{ChunkConfig, SentenceDetector};
+pub use config::{
+    ChunkConfig, IdStrategy, NormalizationForm, SentenceDetector, SizeMode, WindowUnit,
+};
 pub use error::ChunkError;
-pub use py_bindings::Chunker;
+pub use filter::ChunkFilter;
+pub use hierarchy::{HierarchicalChunker, Hierarchy};
+pub use index::ChunkIndex;
+pub use io::{read_jsonl, write_jsonl};
+pub use language::detect_language;
+pub use merge::merge_small;
+pub use py_bindings::{ChunkIterator, Chunker};
 pub use registry::AlgorithmRegistry;
+pub use stats::{chunk_stats, verify_coverage, ChunkStats, CoverageReport};
+#[cfg(feature = "async")]
+pub use stream::{chunk_stream, ChunkStream};
+#[cfg(feature = "tiktoken")]
+pub use token_estimate::count_tokens_cl100k;
+pub use token_estimate::{estimate_tokens_cl100k, TokenEstimator};
 pub use traits::ChunkAlgorithm;
 
 /// A Python module implemented in Rust.
 #[pymodule]
 fn _bunkatsu(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<Chunker>()?;
+    m.add_class::<ChunkIterator>()?;
     m.add_class::<Chunk>()?;
     m.add_class::<ChunkMetadata>()?;
     m.add_class::<SentenceDetector>()?;
+    m.add_class::<ChunkConfig>()?;
+    m.add_class::<py_bindings::PyChunkIndex>()?;
+    m.add_function(wrap_pyfunction!(py_bindings::stats, m)?)?;
+    m.add_function(wrap_pyfunction!(py_bindings::coverage, m)?)?;
+    m.add_function(wrap_pyfunction!(py_bindings::merge, m)?)?;
+    m.add_function(wrap_pyfunction!(py_bindings::save_jsonl, m)?)?;
+    m.add_function(wrap_pyfunction!(py_bindings::load_jsonl, m)?)?;
+
+    let py = m.py();
+    m.add("BunkatsuError", py.get_type::<error::BunkatsuError>())?;
+    m.add(
+        "InvalidConfigError",
+        py.get_type::<error::InvalidConfigError>(),
+    )?;
+    m.add("ProcessingError", py.get_type::<error::ProcessingError>())?;
+    m.add("EmptyInputError", py.get_type::<error::EmptyInputError>())?;
+    m.add(
+        "AlgorithmNotFoundError",
+        py.get_type::<error::AlgorithmNotFoundError>(),
+    )?;
     Ok(())
 }
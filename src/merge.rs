@@ -0,0 +1,113 @@
+//! Post-processing that packs small adjacent chunks together.
+
+use crate::chunk::Chunk;
+
+/// Separator placed between the texts of merged chunks.
+const MERGE_SEPARATOR: &str = "\n\n";
+
+/// Greedily merge adjacent chunks toward `target_size` without exceeding `max_size`.
+///
+/// A chunk joins the one before it when the merged text fits in `max_size`
+/// and is closer to `target_size` than the text so far. Merged chunks get a
+/// new ID, the first member's metadata, a span covering every member, and
+/// the member IDs in `merged_from`. Sizes are in bytes, like `max_size` in
+/// [`ChunkConfig`](crate::ChunkConfig). Positions and neighbor links are
+/// renumbered afterwards.
+pub fn merge_small(chunks: Vec<Chunk>, target_size: usize, max_size: usize) -> Vec<Chunk> {
+    let mut groups: Vec<Vec<Chunk>> = Vec::new();
+    let mut current_len = 0;
+
+    for chunk in chunks {
+        if let Some(group) = groups.last_mut() {
+            let merged_len = current_len + MERGE_SEPARATOR.len() + chunk.text.len();
+            if merged_len <= max_size
+                && merged_len.abs_diff(target_size) < current_len.abs_diff(target_size)
+            {
+                current_len = merged_len;
+                group.push(chunk);
+                continue;
+            }
+        }
+        current_len = chunk.text.len();
+        groups.push(vec![chunk]);
+    }
+
+    Chunk::link_sequence(groups.into_iter().map(merge_group).collect())
+}
+
+/// Combine a group of adjacent chunks into one.
+fn merge_group(mut group: Vec<Chunk>) -> Chunk {
+    if group.len() == 1 {
+        return group.remove(0);
+    }
+
+    let text = group
+        .iter()
+        .map(|c| c.text.as_str())
+        .collect::<Vec<_>>()
+        .join(MERGE_SEPARATOR);
+    let start = group.iter().map(|c| c.start).min().unwrap_or(0);
+    let end = group.iter().map(|c| c.end).max().unwrap_or(0);
+    let merged_from = group.iter().map(|c| c.id.clone()).collect();
+
+    let first = group.swap_remove(0);
+    let mut metadata = first.metadata;
+    metadata.merged_from = Some(merged_from);
+    let had_checksum = metadata.checksum.take().is_some();
+
+    let mut merged = Chunk::with_uuid(text, start, end, metadata);
+    if had_checksum {
+        merged.set_checksum();
+    }
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::HeadingChunker;
+    use crate::config::ChunkConfig;
+    use crate::traits::ChunkAlgorithm;
+
+    #[test]
+    fn test_merge_small_headings() {
+        let text = "# A\n\nOne.\n\n# B\n\nTwo.\n\n# C\n\nThree.\n\n# D\n\nA much longer closing section body.";
+        let chunks = HeadingChunker::default().chunk(text, &ChunkConfig::new(1000));
+        assert_eq!(chunks.len(), 4);
+        let ids: Vec<String> = chunks.iter().map(|c| c.id.clone()).collect();
+
+        let merged = merge_small(chunks, 30, 45);
+
+        // A, B and C are tiny; D alone is already near the target
+        assert_eq!(merged.len(), 2);
+        assert_eq!(
+            merged[0].text,
+            "# A\n\nOne.\n\n# B\n\nTwo.\n\n# C\n\nThree."
+        );
+        assert_eq!(merged[0].metadata.section, Some("h1: A".to_string()));
+        assert_eq!(merged[0].metadata.merged_from, Some(ids[..3].to_vec()));
+        assert_eq!(&text[merged[0].start..merged[0].end], merged[0].text);
+        assert_eq!(merged[1].id, ids[3]);
+        assert_eq!(merged[1].metadata.merged_from, None);
+        assert_eq!(merged[1].metadata.chunk_index, Some(1));
+        assert_eq!(merged[1].metadata.total_chunks, Some(2));
+        assert_eq!(
+            merged[0].metadata.next_chunk_id.as_ref(),
+            Some(&merged[1].id)
+        );
+    }
+
+    #[test]
+    fn test_merge_small_respects_max_size() {
+        let mut chunks =
+            HeadingChunker::default().chunk("# A\n\nOne.\n\n# B\n\nTwo.", &ChunkConfig::new(1000));
+        chunks.iter_mut().for_each(Chunk::set_checksum);
+
+        // "# A\n\nOne." plus separator plus "# B\n\nTwo." is 20 bytes
+        assert_eq!(merge_small(chunks.clone(), 100, 19).len(), 2);
+        let merged = merge_small(chunks, 100, 20);
+        assert_eq!(merged.len(), 1);
+        assert!(merged[0].verify_checksum());
+        assert!(merge_small(Vec::new(), 10, 20).is_empty());
+    }
+}
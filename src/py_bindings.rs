@@ -1,109 +1,931 @@
 //! Python bindings for the Bunkatsu chunking library.
 
+use pyo3::exceptions::PyTypeError;
 use pyo3::prelude::*;
+use pyo3::types::PyDict;
 
+#[cfg(feature = "xml")]
+use crate::algorithms::XmlChunker;
 use crate::algorithms::{
-    FixedSizeChunker, HeadingChunker, MarkdownChunker, ParagraphChunker, RecursiveChunker,
-    SentenceChunker, SlidingWindowChunker,
+    HeadingChunker, JsonChunker, LatexChunker, LineChunker, LineMode, MarkdownChunker,
+    OutlineEntry, RecursiveChunker, RecursiveStrategy, RegexChunker, SentenceChunker,
 };
 use crate::chunk::Chunk;
-use crate::config::{ChunkConfig, SentenceDetector};
+use crate::config::{ChunkConfig, IdStrategy, NormalizationForm, SentenceDetector, WindowUnit};
+use crate::error::ChunkError;
+use crate::hierarchy::HierarchicalChunker;
+use crate::index::SpanIndex;
+use crate::io::{read_jsonl, write_jsonl};
+use crate::merge::merge_small;
+use crate::registry::AlgorithmRegistry;
+use crate::stats::{chunk_stats, verify_coverage};
 use crate::traits::ChunkAlgorithm;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::path::PathBuf;
 
 /// Main chunker class for Python.
 #[pyclass]
 pub struct Chunker {
-    fixed_size: FixedSizeChunker,
-    sliding_window: SlidingWindowChunker,
-    sentence: SentenceChunker,
-    paragraph: ParagraphChunker,
-    markdown: MarkdownChunker,
-    heading: HeadingChunker,
-    recursive: RecursiveChunker,
+    /// Built-in algorithms, looked up by method name.
+    registry: AlgorithmRegistry,
+    /// Callable returning sentence boundary byte offsets, used by `SentenceDetector.Custom`.
+    #[pyo3(get, set)]
+    segmenter: Option<Py<PyAny>>,
+}
+
+impl Chunker {
+    /// Create a new Chunker instance without a custom sentence segmenter.
+    pub fn new() -> Self {
+        Self {
+            registry: AlgorithmRegistry::new(),
+            segmenter: None,
+        }
+    }
+
+    /// Chunk `text` with the registered algorithm `method`, releasing the GIL.
+    fn run(
+        &self,
+        py: Python<'_>,
+        method: &str,
+        text: &str,
+        config: &ChunkConfig,
+    ) -> PyResult<Vec<Chunk>> {
+        let algorithm = self
+            .registry
+            .get(method)
+            .ok_or_else(|| ChunkError::AlgorithmNotFound(method.to_string()))?;
+        detached(py, || algorithm.chunk_finalized(text, config))
+    }
 }
 
 #[pymethods]
 impl Chunker {
     /// Create a new Chunker instance.
+    ///
+    /// `segmenter` is called with the text and returns the byte offsets at
+    /// which sentences end; it is used when the sentence detector is
+    /// `SentenceDetector.Custom`.
     #[new]
-    pub fn new() -> Self {
+    #[pyo3(signature = (segmenter=None))]
+    fn py_new(segmenter: Option<Py<PyAny>>) -> Self {
         Self {
-            fixed_size: FixedSizeChunker,
-            sliding_window: SlidingWindowChunker,
-            sentence: SentenceChunker,
-            paragraph: ParagraphChunker,
-            markdown: MarkdownChunker,
-            heading: HeadingChunker::default(),
-            recursive: RecursiveChunker::default(),
+            segmenter,
+            ..Self::new()
         }
     }
 
     /// Chunk text using fixed-size character-based chunking.
-    #[pyo3(signature = (text, max_size=512))]
-    pub fn chunk_fixed(&self, text: &str, max_size: usize) -> Vec<Chunk> {
-        let config = ChunkConfig::new(max_size);
-        self.fixed_size.chunk(text, &config)
+    ///
+    /// With `graphemes=True`, `max_size` counts grapheme clusters and chunks
+    /// never split one. With `size_mode="display_width"`, it counts terminal
+    /// columns, so full-width CJK characters count 2.
+    #[pyo3(signature = (text, max_size=None, config=None, respect_words=None, graphemes=None, size_mode=None, **kwargs))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn chunk_fixed(
+        &self,
+        py: Python<'_>,
+        text: &str,
+        max_size: Option<usize>,
+        config: Option<ChunkConfig>,
+        respect_words: Option<bool>,
+        graphemes: Option<bool>,
+        size_mode: Option<&str>,
+        kwargs: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<Vec<Chunk>> {
+        let mut config = resolve_config(config, max_size, 512, PostOptions::from_kwargs(kwargs)?)?;
+        if let Some(respect_words) = respect_words {
+            config.respect_word_boundaries = respect_words;
+        }
+        if let Some(graphemes) = graphemes {
+            config.grapheme_boundaries = graphemes;
+        }
+        if let Some(size_mode) = size_mode {
+            config.size_mode = size_mode.parse()?;
+        }
+        let text = &*prepare(&config, text)?;
+        self.run(py, "fixed_size", text, &config)
     }
 
     /// Chunk text using sliding window with overlap.
-    #[pyo3(signature = (text, max_size=512, overlap=64))]
-    pub fn chunk_sliding(&self, text: &str, max_size: usize, overlap: usize) -> Vec<Chunk> {
-        let config = ChunkConfig::new(max_size).with_overlap(overlap);
-        self.sliding_window.chunk(text, &config)
+    ///
+    /// With `unit="sentences"`, `max_size` and `overlap` count whole sentences
+    /// and the overlap defaults to 0 instead of 64 characters.
+    #[pyo3(signature = (text, max_size=None, overlap=None, config=None, unit=None, **kwargs))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn chunk_sliding(
+        &self,
+        py: Python<'_>,
+        text: &str,
+        max_size: Option<usize>,
+        overlap: Option<usize>,
+        config: Option<ChunkConfig>,
+        unit: Option<&str>,
+        kwargs: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<Vec<Chunk>> {
+        let has_config = config.is_some();
+        let mut config = resolve_config(config, max_size, 512, PostOptions::from_kwargs(kwargs)?)?;
+        if let Some(unit) = unit {
+            config.window_unit = unit.parse()?;
+        }
+        if let Some(overlap) = overlap {
+            config.overlap = overlap;
+        } else if !has_config && config.window_unit == WindowUnit::Chars {
+            config.overlap = 64;
+        }
+        let text = &*prepare(&config, text)?;
+        self.run(py, "sliding_window", text, &config)
     }
 
     /// Chunk text by sentence boundaries.
-    #[pyo3(signature = (text, max_size=512, detector=SentenceDetector::Regex))]
+    #[pyo3(signature = (text, max_size=None, detector=None, config=None, terminators=None, **kwargs))]
+    #[allow(clippy::too_many_arguments)]
     pub fn chunk_sentences(
         &self,
+        py: Python<'_>,
         text: &str,
-        max_size: usize,
-        detector: SentenceDetector,
-    ) -> Vec<Chunk> {
-        let config = ChunkConfig::new(max_size).with_sentence_detector(detector);
-        self.sentence.chunk(text, &config)
+        max_size: Option<usize>,
+        detector: Option<SentenceDetector>,
+        config: Option<ChunkConfig>,
+        terminators: Option<Vec<char>>,
+        kwargs: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<Vec<Chunk>> {
+        let mut config = resolve_config(config, max_size, 512, PostOptions::from_kwargs(kwargs)?)?;
+        if let Some(detector) = detector {
+            config.sentence_detector = detector;
+        }
+        if let Some(terminators) = terminators {
+            config.sentence_terminators = terminators;
+        }
+        let text = &*prepare(&config, text)?;
+        if config.sentence_detector != SentenceDetector::Custom {
+            return self.run(py, "sentence", text, &config);
+        }
+
+        let segmenter = self.segmenter.as_ref().ok_or_else(|| {
+            ChunkError::InvalidConfig(
+                "SentenceDetector.Custom requires Chunker(segmenter=...)".to_string(),
+            )
+        })?;
+        let boundaries: Vec<usize> = segmenter.call1(py, (text,))?.extract(py)?;
+        detached(py, || {
+            SentenceChunker
+                .chunk_with_boundaries(text, &boundaries, &config)
+                .map(|chunks| Chunk::finalize(chunks, &config))
+        })
     }
 
     /// Chunk text by paragraph boundaries.
-    #[pyo3(signature = (text, max_size=512))]
-    pub fn chunk_paragraphs(&self, text: &str, max_size: usize) -> Vec<Chunk> {
-        let config = ChunkConfig::new(max_size);
-        self.paragraph.chunk(text, &config)
+    ///
+    /// A paragraph larger than max_size is split by sentence unless
+    /// `strict_paragraphs` is true.
+    #[pyo3(signature = (text, max_size=None, config=None, strict_paragraphs=None, **kwargs))]
+    pub fn chunk_paragraphs(
+        &self,
+        py: Python<'_>,
+        text: &str,
+        max_size: Option<usize>,
+        config: Option<ChunkConfig>,
+        strict_paragraphs: Option<bool>,
+        kwargs: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<Vec<Chunk>> {
+        let mut config = resolve_config(config, max_size, 512, PostOptions::from_kwargs(kwargs)?)?;
+        if let Some(strict) = strict_paragraphs {
+            config.strict_paragraphs = strict;
+        }
+        let text = &*prepare(&config, text)?;
+        self.run(py, "paragraph", text, &config)
+    }
+
+    /// Chunk text by paragraphs, splitting only oversized paragraphs by sentence.
+    #[pyo3(signature = (text, max_size=None, overlap=None, config=None, **kwargs))]
+    pub fn chunk_paragraph_sentences(
+        &self,
+        py: Python<'_>,
+        text: &str,
+        max_size: Option<usize>,
+        overlap: Option<usize>,
+        config: Option<ChunkConfig>,
+        kwargs: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<Vec<Chunk>> {
+        let mut config = resolve_config(config, max_size, 512, PostOptions::from_kwargs(kwargs)?)?;
+        if let Some(overlap) = overlap {
+            config.overlap = overlap;
+        }
+        let text = &*prepare(&config, text)?;
+        self.run(py, "paragraph_sentence", text, &config)
     }
 
     /// Chunk markdown text preserving code blocks and splitting at headings.
-    #[pyo3(signature = (text, max_size=1000))]
-    pub fn chunk_markdown(&self, text: &str, max_size: usize) -> Vec<Chunk> {
-        let config = ChunkConfig::new(max_size);
-        self.markdown.chunk(text, &config)
+    ///
+    /// `split_levels` picks the heading levels that start a new chunk (default
+    /// all); other headings stay inline in the current chunk.
+    #[pyo3(signature = (text, max_size=None, config=None, split_levels=None, **kwargs))]
+    pub fn chunk_markdown(
+        &self,
+        py: Python<'_>,
+        text: &str,
+        max_size: Option<usize>,
+        config: Option<ChunkConfig>,
+        split_levels: Option<Vec<usize>>,
+        kwargs: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<Vec<Chunk>> {
+        let config = resolve_config(config, max_size, 1000, PostOptions::from_kwargs(kwargs)?)?;
+        let chunker = split_levels.map(MarkdownChunker::try_new).transpose()?;
+        let text = &*prepare(&config, text)?;
+        match chunker {
+            Some(chunker) => detached(py, || chunker.chunk_finalized(text, &config)),
+            None => self.run(py, "markdown", text, &config),
+        }
     }
 
     /// Chunk text by heading boundaries.
-    #[pyo3(signature = (text, max_size=1000))]
-    pub fn chunk_headings(&self, text: &str, max_size: usize) -> Vec<Chunk> {
-        let config = ChunkConfig::new(max_size);
-        self.heading.chunk(text, &config)
+    ///
+    /// `levels` picks the heading levels that start a new chunk (default h1 and h2).
+    /// With `breadcrumbs`, `section` lists every enclosing heading ("h1: Guide > h2: Install").
+    /// With `respect_max_size`, sections over `max_size` are split by paragraphs and sentences.
+    #[pyo3(signature = (text, max_size=None, config=None, levels=None, breadcrumbs=false, respect_max_size=false, **kwargs))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn chunk_headings(
+        &self,
+        py: Python<'_>,
+        text: &str,
+        max_size: Option<usize>,
+        config: Option<ChunkConfig>,
+        levels: Option<Vec<usize>>,
+        breadcrumbs: bool,
+        respect_max_size: bool,
+        kwargs: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<Vec<Chunk>> {
+        let config = resolve_config(config, max_size, 1000, PostOptions::from_kwargs(kwargs)?)?;
+        let chunker = match (levels, breadcrumbs, respect_max_size) {
+            (None, false, false) => None,
+            (levels, breadcrumbs, respect_max_size) => Some(
+                levels
+                    .map_or_else(|| Ok(HeadingChunker::default()), HeadingChunker::try_new)?
+                    .with_breadcrumbs(breadcrumbs)
+                    .with_respect_max_size(respect_max_size),
+            ),
+        };
+        let text = &*prepare(&config, text)?;
+        match chunker {
+            Some(chunker) => detached(py, || chunker.chunk_finalized(text, &config)),
+            None => self.run(py, "heading", text, &config),
+        }
     }
 
     /// Chunk text recursively using multiple strategies.
-    #[pyo3(signature = (text, max_size=512))]
-    pub fn chunk_recursive(&self, text: &str, max_size: usize) -> Vec<Chunk> {
-        let config = ChunkConfig::new(max_size);
-        self.recursive.chunk(text, &config)
+    ///
+    /// `strategy` picks the first level: "paragraph" (default), "sentence",
+    /// or "markdown" to split at headings before paragraphs and sentences.
+    #[pyo3(signature = (text, max_size=None, config=None, strategy=None, **kwargs))]
+    pub fn chunk_recursive(
+        &self,
+        py: Python<'_>,
+        text: &str,
+        max_size: Option<usize>,
+        config: Option<ChunkConfig>,
+        strategy: Option<&str>,
+        kwargs: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<Vec<Chunk>> {
+        let config = resolve_config(config, max_size, 512, PostOptions::from_kwargs(kwargs)?)?;
+        let strategy = strategy.map(str::parse::<RecursiveStrategy>).transpose()?;
+        let text = &*prepare(&config, text)?;
+        match strategy {
+            Some(strategy) => {
+                let chunker = RecursiveChunker::new(strategy);
+                detached(py, || chunker.chunk_finalized(text, &config))
+            }
+            None => self.run(py, "recursive", text, &config),
+        }
+    }
+
+    /// Chunk HTML by block-level elements, stripping markup.
+    #[pyo3(signature = (text, max_size=None, config=None, **kwargs))]
+    pub fn chunk_html(
+        &self,
+        py: Python<'_>,
+        text: &str,
+        max_size: Option<usize>,
+        config: Option<ChunkConfig>,
+        kwargs: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<Vec<Chunk>> {
+        let config = resolve_config(config, max_size, 1000, PostOptions::from_kwargs(kwargs)?)?;
+        let text = &*prepare(&config, text)?;
+        self.run(py, "html", text, &config)
+    }
+
+    /// Chunk LaTeX at sectioning commands, keeping math, float and verbatim
+    /// environments whole.
+    #[pyo3(signature = (text, max_size=None, config=None, strip_comments=true, **kwargs))]
+    pub fn chunk_latex(
+        &self,
+        py: Python<'_>,
+        text: &str,
+        max_size: Option<usize>,
+        config: Option<ChunkConfig>,
+        strip_comments: bool,
+        kwargs: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<Vec<Chunk>> {
+        let config = resolve_config(config, max_size, 1500, PostOptions::from_kwargs(kwargs)?)?;
+        let text = &*prepare(&config, text)?;
+        if strip_comments {
+            return self.run(py, "latex", text, &config);
+        }
+        let chunker = LatexChunker::new(false);
+        detached(py, || chunker.chunk_finalized(text, &config))
+    }
+
+    /// Chunk text on a custom separator string, packing segments up to max_size.
+    #[pyo3(signature = (text, separator, max_size=None, keep_separator=None, config=None, **kwargs))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn chunk_delimiter(
+        &self,
+        py: Python<'_>,
+        text: &str,
+        separator: String,
+        max_size: Option<usize>,
+        keep_separator: Option<bool>,
+        config: Option<ChunkConfig>,
+        kwargs: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<Vec<Chunk>> {
+        let mut config = resolve_config(config, max_size, 512, PostOptions::from_kwargs(kwargs)?)?;
+        config.separator = Some(separator);
+        if let Some(keep_separator) = keep_separator {
+            config.keep_separator = keep_separator;
+        }
+        let text = &*prepare(&config, text)?;
+        self.run(py, "delimiter", text, &config)
+    }
+
+    /// Chunk text by whole lines, with optional overlap measured in lines.
+    ///
+    /// With `lines_per_chunk`, each chunk holds exactly that many lines
+    /// (the last may hold fewer) instead of packing lines up to max_size,
+    /// and consecutive chunks share `overlap` lines. `overlap_lines` applies
+    /// only when packing.
+    #[pyo3(signature = (text, max_size=None, overlap_lines=None, config=None, lines_per_chunk=None, overlap=None, **kwargs))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn chunk_lines(
+        &self,
+        py: Python<'_>,
+        text: &str,
+        max_size: Option<usize>,
+        overlap_lines: Option<usize>,
+        config: Option<ChunkConfig>,
+        lines_per_chunk: Option<usize>,
+        overlap: Option<usize>,
+        kwargs: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<Vec<Chunk>> {
+        let mut config = resolve_config(config, max_size, 512, PostOptions::from_kwargs(kwargs)?)?;
+
+        let Some(lines_per_chunk) = lines_per_chunk else {
+            if overlap.is_some() {
+                return Err(ChunkError::InvalidConfig(
+                    "overlap requires lines_per_chunk; use overlap_lines when packing lines"
+                        .to_string(),
+                )
+                .into());
+            }
+            if let Some(overlap_lines) = overlap_lines {
+                config.overlap_lines = overlap_lines;
+            }
+            let text = &*prepare(&config, text)?;
+            return self.run(py, "line", text, &config);
+        };
+
+        if overlap_lines.is_some() {
+            return Err(ChunkError::InvalidConfig(
+                "overlap_lines applies when packing lines; use overlap with lines_per_chunk"
+                    .to_string(),
+            )
+            .into());
+        }
+        config.max_size = lines_per_chunk;
+        if let Some(overlap) = overlap {
+            config.overlap = overlap;
+        }
+        LineChunker::check_count(&config)?;
+        let text = &*prepare(&config, text)?;
+        let chunker = LineChunker::new(LineMode::Count);
+        detached(py, || chunker.chunk_finalized(text, &config))
+    }
+
+    /// Chunk text into groups of at most `words_per_chunk` words.
+    #[pyo3(signature = (text, words_per_chunk=None, overlap=None, config=None, **kwargs))]
+    pub fn chunk_words(
+        &self,
+        py: Python<'_>,
+        text: &str,
+        words_per_chunk: Option<usize>,
+        overlap: Option<usize>,
+        config: Option<ChunkConfig>,
+        kwargs: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<Vec<Chunk>> {
+        let mut config = resolve_config(
+            config,
+            words_per_chunk,
+            200,
+            PostOptions::from_kwargs(kwargs)?,
+        )?;
+        if let Some(overlap) = overlap {
+            config.overlap = overlap;
+        }
+        let text = &*prepare(&config, text)?;
+        self.run(py, "word", text, &config)
+    }
+
+    /// Chunk text at each match of a regular expression, packing segments up to max_size.
+    ///
+    /// `keep_delimiter` puts each match at the "start" of the following
+    /// segment (default), at the "end" of the preceding one, or "drop"s it.
+    #[pyo3(signature = (text, pattern, max_size=None, config=None, keep_delimiter="start", **kwargs))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn chunk_regex(
+        &self,
+        py: Python<'_>,
+        text: &str,
+        pattern: String,
+        max_size: Option<usize>,
+        config: Option<ChunkConfig>,
+        keep_delimiter: &str,
+        kwargs: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<Vec<Chunk>> {
+        let mut config = resolve_config(config, max_size, 512, PostOptions::from_kwargs(kwargs)?)?;
+        config.pattern = Some(pattern);
+        let chunker = RegexChunker::new(keep_delimiter.parse()?);
+        let text = &*prepare(&config, text)?;
+        detached(py, || chunker.chunk_finalized(text, &config))
+    }
+
+    /// Alias of [`chunk_regex`](Self::chunk_regex).
+    #[pyo3(signature = (text, pattern, max_size=None, config=None, keep_delimiter="start", **kwargs))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn chunk_by_pattern(
+        &self,
+        py: Python<'_>,
+        text: &str,
+        pattern: String,
+        max_size: Option<usize>,
+        config: Option<ChunkConfig>,
+        keep_delimiter: &str,
+        kwargs: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<Vec<Chunk>> {
+        self.chunk_regex(py, text, pattern, max_size, config, keep_delimiter, kwargs)
+    }
+
+    /// Chunk JSON keeping top-level array elements and object entries intact.
+    #[pyo3(signature = (text, max_size=None, config=None, **kwargs))]
+    pub fn chunk_json(
+        &self,
+        py: Python<'_>,
+        text: &str,
+        max_size: Option<usize>,
+        config: Option<ChunkConfig>,
+        kwargs: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<Vec<Chunk>> {
+        let config = resolve_config(config, max_size, 1000, PostOptions::from_kwargs(kwargs)?)?;
+        let text = &*prepare(&config, text)?;
+        detached(py, || JsonChunker.chunk_finalized(text, &config))
+    }
+
+    /// Chunk XML by extracting the text of the given element paths.
+    #[cfg(feature = "xml")]
+    #[pyo3(signature = (text, element_paths, max_size=None, config=None, **kwargs))]
+    pub fn chunk_xml(
+        &self,
+        py: Python<'_>,
+        text: &str,
+        element_paths: Vec<String>,
+        max_size: Option<usize>,
+        config: Option<ChunkConfig>,
+        kwargs: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<Vec<Chunk>> {
+        let config = resolve_config(config, max_size, 1000, PostOptions::from_kwargs(kwargs)?)?;
+        let text = &*prepare(&config, text)?;
+        detached(py, || {
+            XmlChunker::new(element_paths).chunk_finalized(text, &config)
+        })
+    }
+
+    /// Chunk `text` with any registered `method`, configured by an `options` dict.
+    ///
+    /// Called as `chunk(method, text, config)`, so one config can be reused
+    /// across methods.
+    /// `options` sets algorithm knobs such as `{"levels": [1, 2, 3]}` for
+    /// heading, `{"strategy": "sentence_first"}` for recursive,
+    /// `{"mode": "count"}` for line, `{"strip_comments": False}` for latex or
+    /// `{"element_paths": [...]}` for xml. Unknown keys raise InvalidConfigError.
+    #[pyo3(signature = (method, text, config=None, max_size=None, options=None, **kwargs))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn chunk(
+        &self,
+        py: Python<'_>,
+        method: &str,
+        text: &str,
+        config: Option<ChunkConfig>,
+        max_size: Option<usize>,
+        options: Option<Bound<'_, PyDict>>,
+        kwargs: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<Vec<Chunk>> {
+        let config = resolve_config(config, max_size, 512, PostOptions::from_kwargs(kwargs)?)?;
+        let text = &*prepare(&config, text)?;
+        let options_json: String = match options {
+            Some(options) => py
+                .import("json")?
+                .call_method1("dumps", (options,))?
+                .extract()?,
+            None => String::new(),
+        };
+        let registry = &self.registry;
+        detached(py, || {
+            registry.chunk_with(method, text, &config, &options_json)
+        })
+    }
+
+    /// Chunk text into parents and child chunks for parent document retrieval.
+    ///
+    /// `method_parent` splits the text into parents of up to `parent_size`
+    /// bytes; `method_child` splits each parent's span into children of up
+    /// to `child_size` bytes. Returns `(parents, children)`: each child's
+    /// `metadata.parent_chunk_id` is the ID of the parent containing it, and
+    /// IDs are unique across both lists.
+    #[pyo3(signature = (text, parent_size=None, child_size=None, config=None, method_parent="paragraph", method_child="sentence", **kwargs))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn chunk_hierarchical(
+        &self,
+        py: Python<'_>,
+        text: &str,
+        parent_size: Option<usize>,
+        child_size: Option<usize>,
+        config: Option<ChunkConfig>,
+        method_parent: &str,
+        method_child: &str,
+        kwargs: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<(Vec<Chunk>, Vec<Chunk>)> {
+        let config = resolve_config(config, parent_size, 2048, PostOptions::from_kwargs(kwargs)?)?;
+        let lookup = |method: &str| {
+            self.registry
+                .get(method)
+                .ok_or_else(|| ChunkError::AlgorithmNotFound(method.to_string()))
+        };
+        let chunker = HierarchicalChunker::new(
+            lookup(method_parent)?,
+            lookup(method_child)?,
+            child_size.unwrap_or(512),
+        );
+        let text = &*prepare(&config, text)?;
+        chunker.child_config(&config).validate()?;
+        let hierarchy = py.detach(|| chunker.chunk(text, &config).finalize(&config));
+        Ok((hierarchy.parents, hierarchy.children))
+    }
+
+    /// Iterate over chunks of `text` produced by `method`, converting them on demand.
+    ///
+    /// The fixed_size, sliding_window, sentence and line methods compute each
+    /// chunk lazily, so stopping early skips the rest of the work. Other methods
+    /// chunk the whole text up front but still yield chunks one at a time, as
+    /// does sentence with `SentenceDetector.Custom`. Methods read their
+    /// settings (e.g. `separator` or `pattern`) from `config`, as `chunk` does.
+    #[pyo3(signature = (text, method, max_size=None, config=None, **kwargs))]
+    pub fn iter_chunks(
+        &self,
+        py: Python<'_>,
+        text: String,
+        method: &str,
+        max_size: Option<usize>,
+        config: Option<ChunkConfig>,
+        kwargs: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<ChunkIterator> {
+        let custom_sentences = config
+            .as_ref()
+            .is_some_and(|c| c.sentence_detector == SentenceDetector::Custom);
+        // External segmenters see the whole text at once
+        if method == "sentence" && custom_sentences {
+            let chunks = self.chunk_sentences(py, &text, max_size, None, config, None, kwargs)?;
+            return Ok(ChunkIterator {
+                chunks: Box::new(chunks.into_iter()),
+            });
+        }
+
+        let has_config = config.is_some();
+        let mut config = resolve_config(config, max_size, 512, PostOptions::from_kwargs(kwargs)?)?;
+        if method == "sliding_window" && !has_config {
+            config.overlap = 64;
+        }
+        let algorithm = self
+            .registry
+            .get(method)
+            .ok_or_else(|| ChunkError::AlgorithmNotFound(method.to_string()))?;
+        let text = config.normalize_owned(text);
+        prepare(&config, &text)?;
+        let chunks = py.detach(|| algorithm.chunk_finalized_iter(text, &config))?;
+        Ok(ChunkIterator { chunks })
+    }
+
+    /// Heading tree of a markdown document as nested dicts.
+    ///
+    /// Each entry has "level", "title", "section", "start", "end" and
+    /// "children"; "section" and "start" match the chunks' metadata.section
+    /// and start.
+    pub fn outline<'py>(&self, py: Python<'py>, text: &str) -> PyResult<Vec<Bound<'py, PyDict>>> {
+        MarkdownChunker::default()
+            .outline(text)
+            .iter()
+            .map(|entry| outline_dict(py, entry))
+            .collect()
+    }
+
+    /// YAML or TOML front matter at the start of a markdown document.
+    ///
+    /// Returns a dict with "format" ("yaml" or "toml"), "content" and "end"
+    /// (byte offset where the body begins), or None. `chunk_markdown` leaves
+    /// the front matter out of its chunks.
+    pub fn front_matter<'py>(
+        &self,
+        py: Python<'py>,
+        text: &str,
+    ) -> PyResult<Option<Bound<'py, PyDict>>> {
+        MarkdownChunker::default()
+            .front_matter(text)
+            .map(|front_matter| {
+                let dict = PyDict::new(py);
+                dict.set_item("format", front_matter.format)?;
+                dict.set_item("content", front_matter.content)?;
+                dict.set_item("end", front_matter.end)?;
+                Ok(dict)
+            })
+            .transpose()
     }
 
     /// List available chunking methods.
     pub fn available_methods(&self) -> Vec<String> {
-        vec![
-            "fixed_size".to_string(),
-            "sliding_window".to_string(),
-            "sentence".to_string(),
-            "paragraph".to_string(),
-            "markdown".to_string(),
-            "heading".to_string(),
-            "recursive".to_string(),
-        ]
+        self.registry.list()
+    }
+}
+
+/// Python iterator over chunks, returned by [`Chunker::iter_chunks`].
+#[pyclass]
+pub struct ChunkIterator {
+    chunks: Box<dyn Iterator<Item = Chunk> + Send + Sync>,
+}
+
+#[pymethods]
+impl ChunkIterator {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(mut slf: PyRefMut<'_, Self>) -> Option<Chunk> {
+        slf.chunks.next()
+    }
+}
+
+/// Lookup from byte offsets to the IDs of the chunks covering them.
+///
+/// Exposed to Python as `ChunkIndex`; see [`crate::ChunkIndex`].
+#[pyclass(name = "ChunkIndex")]
+pub struct PyChunkIndex {
+    ids: Vec<String>,
+    spans: SpanIndex,
+}
+
+#[pymethods]
+impl PyChunkIndex {
+    #[new]
+    fn new(chunks: Vec<Chunk>) -> Self {
+        Self {
+            spans: SpanIndex::new(&chunks),
+            ids: chunks.into_iter().map(|c| c.id).collect(),
+        }
     }
+
+    /// IDs of the chunks whose span contains byte `offset`, ordered by start.
+    fn find(&self, offset: usize) -> Vec<String> {
+        self.find_range(offset, offset + 1)
+    }
+
+    /// IDs of the chunks overlapping bytes `start..end`, ordered by start.
+    fn find_range(&self, start: usize, end: usize) -> Vec<String> {
+        self.spans
+            .find_range(start, end)
+            .into_iter()
+            .map(|i| self.ids[i].clone())
+            .collect()
+    }
+
+    fn __len__(&self) -> usize {
+        self.ids.len()
+    }
+}
+
+/// Summarize chunk sizes, coverage and ordering as a dict.
+///
+/// "coverage" needs `original_len` (in bytes) and "exceeding" needs
+/// `max_size`; both are None otherwise.
+#[pyfunction]
+#[pyo3(signature = (chunks, original_len=None, max_size=None))]
+pub fn stats<'py>(
+    py: Python<'py>,
+    chunks: Vec<Chunk>,
+    original_len: Option<usize>,
+    max_size: Option<usize>,
+) -> PyResult<Bound<'py, PyDict>> {
+    let stats = chunk_stats(&chunks);
+    let dict = PyDict::new(py);
+    dict.set_item("count", stats.count)?;
+    dict.set_item("min_len", stats.min_len)?;
+    dict.set_item("max_len", stats.max_len)?;
+    dict.set_item("mean_len", stats.mean_len)?;
+    dict.set_item("median_len", stats.median_len)?;
+    dict.set_item("std_dev", stats.std_dev)?;
+    dict.set_item("exceeding", max_size.map(|limit| stats.exceeding(limit)))?;
+    dict.set_item("covered_bytes", stats.covered_bytes)?;
+    dict.set_item("coverage", original_len.map(|len| stats.coverage(len)))?;
+    dict.set_item("gap_bytes", stats.gap_bytes)?;
+    dict.set_item("overlap_bytes", stats.overlap_bytes)?;
+    dict.set_item("overlapping", stats.overlapping)?;
+    dict.set_item("out_of_order", stats.out_of_order)?;
+    Ok(dict)
+}
+
+/// Report which byte ranges of `text` the chunks cover, as a dict.
+///
+/// "gaps" and "overlaps" are lists of `(start, end)` byte ranges.
+#[pyfunction]
+#[pyo3(name = "verify_coverage")]
+pub fn coverage<'py>(
+    py: Python<'py>,
+    text: &str,
+    chunks: Vec<Chunk>,
+) -> PyResult<Bound<'py, PyDict>> {
+    let report = verify_coverage(text, &chunks);
+    let dict = PyDict::new(py);
+    dict.set_item("text_len", report.text_len)?;
+    dict.set_item("covered_chars", report.covered_chars)?;
+    dict.set_item("covered_bytes", report.covered_bytes)?;
+    dict.set_item("complete", report.is_complete())?;
+    dict.set_item("gaps", report.gaps)?;
+    dict.set_item("overlaps", report.overlaps)?;
+    Ok(dict)
+}
+
+/// Merge adjacent small chunks toward `target_size` bytes, up to `max_size` bytes.
+///
+/// Merged chunks list their member IDs in `metadata.merged_from`.
+#[pyfunction]
+#[pyo3(name = "merge_small")]
+pub fn merge(chunks: Vec<Chunk>, target_size: usize, max_size: usize) -> Vec<Chunk> {
+    merge_small(chunks, target_size, max_size)
+}
+
+/// Write chunks to the file at `path` as JSON Lines, one chunk per line.
+#[pyfunction]
+pub fn save_jsonl(py: Python<'_>, chunks: Vec<Chunk>, path: PathBuf) -> PyResult<()> {
+    let file = File::create(path)?;
+    py.detach(|| write_jsonl(&chunks, BufWriter::new(file)))?;
+    Ok(())
+}
+
+/// Read chunks from a JSON Lines file written by `save_jsonl`.
+#[pyfunction]
+pub fn load_jsonl(py: Python<'_>, path: PathBuf) -> PyResult<Vec<Chunk>> {
+    let file = File::open(path)?;
+    Ok(py.detach(|| read_jsonl(BufReader::new(file)))?)
+}
+
+/// Convert an outline entry and its children into a dict.
+fn outline_dict<'py>(py: Python<'py>, entry: &OutlineEntry) -> PyResult<Bound<'py, PyDict>> {
+    let dict = PyDict::new(py);
+    dict.set_item("level", entry.level)?;
+    dict.set_item("title", &entry.title)?;
+    dict.set_item("section", &entry.section)?;
+    dict.set_item("start", entry.start)?;
+    dict.set_item("end", entry.end)?;
+    let children = entry
+        .children
+        .iter()
+        .map(|child| outline_dict(py, child))
+        .collect::<PyResult<Vec<_>>>()?;
+    dict.set_item("children", children)?;
+    Ok(dict)
+}
+
+/// Run chunking with the GIL released.
+///
+/// `chunk` must only touch Rust data and return finalized chunks;
+/// converting the result to Python objects happens after the GIL is
+/// reacquired.
+fn detached<F>(py: Python<'_>, chunk: F) -> PyResult<Vec<Chunk>>
+where
+    F: Send + FnOnce() -> Result<Vec<Chunk>, ChunkError>,
+{
+    Ok(py.detach(chunk)?)
+}
+
+/// Post-processing keyword arguments shared by every chunk method.
+///
+/// Unset fields (or keywords passed as `None`) keep the config's value.
+#[derive(Default)]
+struct PostOptions {
+    id_strategy: Option<IdStrategy>,
+    doc_id: Option<String>,
+    normalize: Option<NormalizationForm>,
+    trim: Option<bool>,
+    collapse_whitespace: Option<bool>,
+    normalize_unicode: Option<NormalizationForm>,
+    strip_control_chars: Option<bool>,
+    detect_language: Option<bool>,
+    extra: Option<HashMap<String, String>>,
+}
+
+impl PostOptions {
+    /// Parse the `**kwargs` of a chunk method, rejecting unknown keywords.
+    fn from_kwargs(kwargs: Option<&Bound<'_, PyDict>>) -> PyResult<Self> {
+        let mut options = Self::default();
+        for (key, value) in kwargs.into_iter().flatten() {
+            let key: String = key.extract()?;
+            if value.is_none() {
+                continue;
+            }
+            match key.as_str() {
+                "id_strategy" => options.id_strategy = Some(value.extract::<String>()?.parse()?),
+                "doc_id" => options.doc_id = Some(value.extract()?),
+                "normalize" => options.normalize = Some(value.extract::<String>()?.parse()?),
+                "trim" => options.trim = Some(value.extract()?),
+                "collapse_whitespace" => options.collapse_whitespace = Some(value.extract()?),
+                "normalize_unicode" => {
+                    options.normalize_unicode = Some(value.extract::<String>()?.parse()?)
+                }
+                "strip_control_chars" => options.strip_control_chars = Some(value.extract()?),
+                "detect_language" => options.detect_language = Some(value.extract()?),
+                "extra" => options.extra = Some(value.extract()?),
+                _ => {
+                    return Err(PyTypeError::new_err(format!(
+                        "unexpected keyword argument '{}'",
+                        key
+                    )))
+                }
+            }
+        }
+        Ok(options)
+    }
+}
+
+/// Build the effective config for a chunk method.
+///
+/// Post-processing options override fields of the given config, which in
+/// turn overrides the method's default `max_size`.
+fn resolve_config(
+    config: Option<ChunkConfig>,
+    max_size: Option<usize>,
+    default_max_size: usize,
+    options: PostOptions,
+) -> PyResult<ChunkConfig> {
+    let mut config = config.unwrap_or_else(|| ChunkConfig::new(default_max_size));
+    if let Some(max_size) = max_size {
+        config.max_size = max_size;
+    }
+    if let Some(id_strategy) = options.id_strategy {
+        config.id_strategy = id_strategy;
+    }
+    if options.doc_id.is_some() {
+        config.doc_id = options.doc_id;
+    }
+    if options.normalize.is_some() {
+        config.normalization = options.normalize;
+    }
+    if let Some(trim) = options.trim {
+        config.trim = trim;
+    }
+    if let Some(collapse_whitespace) = options.collapse_whitespace {
+        config.collapse_whitespace = collapse_whitespace;
+    }
+    if options.normalize_unicode.is_some() {
+        config.normalize_unicode = options.normalize_unicode;
+    }
+    if let Some(strip_control_chars) = options.strip_control_chars {
+        config.strip_control_chars = strip_control_chars;
+    }
+    if let Some(detect_language) = options.detect_language {
+        config.detect_language = detect_language;
+    }
+    if let Some(extra) = options.extra {
+        config.extra.extend(extra);
+    }
+    Ok(config)
+}
+
+/// Validate the config, normalize the input text and check it before chunking.
+///
+/// Chunk positions refer to the returned text.
+fn prepare<'t>(config: &ChunkConfig, text: &'t str) -> Result<Cow<'t, str>, ChunkError> {
+    config.validate()?;
+    let text = config.normalize_input(text);
+    config.check_input(&text)?;
+    Ok(text)
 }
 
 impl Default for Chunker {
@@ -1,9 +1,17 @@
 //! Algorithm registry for managing chunking strategies.
 
+#[cfg(feature = "xml")]
+use crate::algorithms::XmlChunker;
 use crate::algorithms::{
-    FixedSizeChunker, ParagraphChunker, SentenceChunker, SlidingWindowChunker,
+    DelimiterChunker, FixedSizeChunker, HeadingChunker, HtmlChunker, JsonChunker, LatexChunker,
+    LineChunker, MarkdownChunker, ParagraphChunker, ParagraphSentenceChunker, RecursiveChunker,
+    RegexChunker, SentenceChunker, SlidingWindowChunker, WordChunker,
 };
+use crate::chunk::Chunk;
+use crate::config::ChunkConfig;
+use crate::error::ChunkError;
 use crate::traits::ChunkAlgorithm;
+use serde_json::Value;
 use std::collections::HashMap;
 use std::sync::Arc;
 
@@ -30,6 +38,20 @@ impl AlgorithmRegistry {
         registry.register(Arc::new(SlidingWindowChunker));
         registry.register(Arc::new(SentenceChunker));
         registry.register(Arc::new(ParagraphChunker));
+        registry.register(Arc::new(ParagraphSentenceChunker));
+        registry.register(Arc::new(MarkdownChunker::default()));
+        registry.register(Arc::new(HeadingChunker::default()));
+        registry.register(Arc::new(RecursiveChunker::default()));
+        registry.register(Arc::new(HtmlChunker));
+        registry.register(Arc::new(LatexChunker::default()));
+        registry.register(Arc::new(JsonChunker));
+        registry.register(Arc::new(DelimiterChunker));
+        registry.register(Arc::new(RegexChunker::default()));
+        registry.register(Arc::new(LineChunker::default()));
+        registry.register(Arc::new(WordChunker));
+        // Extracts nothing until given element paths by registration or options
+        #[cfg(feature = "xml")]
+        registry.register(Arc::new(XmlChunker::new(Vec::new())));
 
         registry
     }
@@ -40,13 +62,202 @@ impl AlgorithmRegistry {
             .insert(algorithm.name().to_string(), algorithm);
     }
 
+    /// Remove an algorithm by name, returning it if it was registered.
+    pub fn unregister(&mut self, name: &str) -> Option<Arc<dyn ChunkAlgorithm>> {
+        self.algorithms.remove(name)
+    }
+
+    /// Check whether an algorithm is registered under `name`.
+    pub fn contains(&self, name: &str) -> bool {
+        self.algorithms.contains_key(name)
+    }
+
     /// Get an algorithm by name.
     pub fn get(&self, name: &str) -> Option<Arc<dyn ChunkAlgorithm>> {
         self.algorithms.get(name).cloned()
     }
 
-    /// List all registered algorithm names.
+    /// Chunk `text` with the algorithm `name`, configured by JSON options.
+    ///
+    /// `options_json` is a JSON object of algorithm knobs, e.g.
+    /// `{"levels": [1, 2, 3]}` for heading. An empty string, `null` or `{}`
+    /// uses the registered algorithm as is. Chunks are finalized with
+    /// [`ChunkAlgorithm::chunk_finalized`], so the config's ID strategy and
+    /// post-processing apply. Errors from
+    /// [`ChunkAlgorithm::try_chunk`], such as a missing regex pattern, are
+    /// returned rather than producing no chunks.
+    pub fn chunk_with(
+        &self,
+        name: &str,
+        text: &str,
+        config: &ChunkConfig,
+        options_json: &str,
+    ) -> Result<Vec<Chunk>, ChunkError> {
+        let algorithm = self
+            .get(name)
+            .ok_or_else(|| ChunkError::AlgorithmNotFound(name.to_string()))?;
+        if options_json.trim().is_empty() {
+            return algorithm.chunk_finalized(text, config);
+        }
+
+        let options: Value = serde_json::from_str(options_json).map_err(|e| {
+            ChunkError::InvalidConfig(format!("invalid {} options JSON: {}", name, e))
+        })?;
+        match options {
+            Value::Null => algorithm.chunk_finalized(text, config),
+            Value::Object(ref map) if map.is_empty() => algorithm.chunk_finalized(text, config),
+            Value::Object(_) => algorithm
+                .with_options(&options)?
+                .chunk_finalized(text, config),
+            _ => Err(ChunkError::InvalidConfig(format!(
+                "{} options must be a JSON object, got {}",
+                name, options
+            ))),
+        }
+    }
+
+    /// List all registered algorithm names in alphabetical order.
     pub fn list(&self) -> Vec<String> {
-        self.algorithms.keys().cloned().collect()
+        let mut names: Vec<String> = self.algorithms.keys().cloned().collect();
+        names.sort_unstable();
+        names
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry_builtins() {
+        let registry = AlgorithmRegistry::new();
+        let names = registry.list();
+
+        for name in [
+            "fixed_size",
+            "sliding_window",
+            "sentence",
+            "paragraph",
+            "markdown",
+            "heading",
+            "recursive",
+            "latex",
+        ] {
+            assert!(names.contains(&name.to_string()), "missing {}", name);
+            assert_eq!(
+                registry.get(name).map(|a| a.name().to_string()).as_deref(),
+                Some(name)
+            );
+        }
+        assert!(registry.get("unknown").is_none());
+    }
+
+    #[test]
+    fn test_registry_unregister() {
+        let mut registry = AlgorithmRegistry::new();
+        registry.register(Arc::new(HeadingChunker::new(vec![1])));
+        assert!(registry.contains("heading"));
+
+        let removed = registry.unregister("heading");
+        assert_eq!(
+            removed.map(|a| a.name().to_string()).as_deref(),
+            Some("heading")
+        );
+        assert!(!registry.contains("heading"));
+        assert!(registry.get("heading").is_none());
+        assert!(registry.unregister("heading").is_none());
+    }
+
+    #[test]
+    fn test_registry_chunk_with_options() {
+        let registry = AlgorithmRegistry::new();
+        let config = ChunkConfig::new(1000);
+        let text = "# A\n\nOne.\n\n## B\n\nTwo.\n\n### C\n\nThree.";
+
+        assert_eq!(
+            registry
+                .chunk_with("heading", text, &config, "")
+                .unwrap()
+                .len(),
+            2
+        );
+        let chunks = registry
+            .chunk_with("heading", text, &config, r#"{"levels": [1, 2, 3]}"#)
+            .unwrap();
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(chunks[0].metadata.chunk_index, Some(0));
+
+        let chunks = registry
+            .chunk_with(
+                "recursive",
+                "One. Two.",
+                &config,
+                r#"{"strategy": "sentence_first"}"#,
+            )
+            .unwrap();
+        assert_eq!(chunks.len(), 1);
+        assert!(registry
+            .chunk_with("fixed_size", text, &config, "{}")
+            .is_ok());
+    }
+
+    #[test]
+    fn test_registry_chunk_with_finalizes() {
+        use crate::config::IdStrategy;
+
+        let registry = AlgorithmRegistry::new();
+        let text = "hello world, hello chunks";
+        let config = ChunkConfig::new(5).with_id_strategy(IdStrategy::ContentHash);
+        let ids = || -> Vec<String> {
+            registry
+                .chunk_with("fixed_size", text, &config, "")
+                .unwrap()
+                .into_iter()
+                .map(|c| c.id)
+                .collect()
+        };
+
+        let first = ids();
+        assert_eq!(first, ids());
+        assert_eq!(first.len(), 5);
+        assert!(first.iter().all(|id| id.len() == 32));
+
+        let sequential = ChunkConfig::new(5).with_id_strategy(IdStrategy::Sequential);
+        let chunks = registry
+            .chunk_with("fixed_size", text, &sequential, "{}")
+            .unwrap();
+        assert_eq!(chunks[1].id, "doc-1");
+        assert_eq!(chunks[1].metadata.prev_chunk_id.as_deref(), Some("doc-0"));
+    }
+
+    #[test]
+    fn test_registry_chunk_with_bad_options() {
+        let registry = AlgorithmRegistry::new();
+        let config = ChunkConfig::new(100);
+        let err = |name: &str, options: &str| {
+            registry
+                .chunk_with(name, "text", &config, options)
+                .unwrap_err()
+                .to_string()
+        };
+
+        assert!(err("heading", r#"{"level": [1]}"#).contains("unknown field `level`"));
+        assert!(err("heading", r#"{"levels": [9]}"#).contains("between 1 and 6"));
+        assert!(err("fixed_size", r#"{"size": 3}"#).contains("does not take options"));
+        assert!(err("recursive", r#"{"strategy": "words"}"#).contains("unknown variant"));
+        assert!(err("heading", "[1]").contains("must be a JSON object"));
+        assert!(err("heading", "{").contains("invalid heading options JSON"));
+        assert!(matches!(
+            registry.chunk_with("nope", "text", &config, ""),
+            Err(ChunkError::AlgorithmNotFound(_))
+        ));
+        // Algorithms that can fail report it instead of producing no chunks
+        assert!(err("regex", "").contains("pattern is required"));
+        let invalid = config.clone().with_pattern("(");
+        assert!(matches!(
+            registry.chunk_with("regex", "text", &invalid, "{}"),
+            Err(ChunkError::InvalidConfig(_))
+        ));
+        assert!(err("json", "").contains("invalid JSON"));
     }
 }
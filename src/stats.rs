@@ -0,0 +1,290 @@
+//! Size and coverage statistics for a list of chunks.
+
+use crate::chunk::Chunk;
+
+/// Summary statistics for a list of chunks.
+///
+/// Lengths are measured in characters; coverage, gaps and overlaps come from
+/// the chunks' `start`/`end` byte offsets.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ChunkStats {
+    /// Number of chunks.
+    pub count: usize,
+    /// Shortest chunk length.
+    pub min_len: usize,
+    /// Longest chunk length.
+    pub max_len: usize,
+    /// Mean chunk length.
+    pub mean_len: f64,
+    /// Median chunk length.
+    pub median_len: f64,
+    /// Population standard deviation of chunk lengths.
+    pub std_dev: f64,
+    /// Bytes of the original text covered by at least one chunk.
+    pub covered_bytes: usize,
+    /// Bytes between consecutive chunk spans that no chunk covers.
+    pub gap_bytes: usize,
+    /// Bytes covered by more than one chunk.
+    pub overlap_bytes: usize,
+    /// Number of chunks that start inside an earlier chunk's span.
+    pub overlapping: usize,
+    /// Number of chunks that start before the chunk listed before them.
+    pub out_of_order: usize,
+    /// Chunk lengths in ascending order.
+    lengths: Vec<usize>,
+}
+
+impl ChunkStats {
+    /// Number of chunks longer than `limit` characters.
+    pub fn exceeding(&self, limit: usize) -> usize {
+        self.lengths.len() - self.lengths.partition_point(|&len| len <= limit)
+    }
+
+    /// Fraction of an original text of `original_len` bytes covered by the chunks.
+    ///
+    /// Returns 1.0 for an empty original text.
+    pub fn coverage(&self, original_len: usize) -> f64 {
+        if original_len == 0 {
+            return 1.0;
+        }
+        self.covered_bytes as f64 / original_len as f64
+    }
+}
+
+/// Compute size, coverage and ordering statistics for `chunks`.
+pub fn chunk_stats(chunks: &[Chunk]) -> ChunkStats {
+    let mut stats = ChunkStats {
+        count: chunks.len(),
+        ..Default::default()
+    };
+    if chunks.is_empty() {
+        return stats;
+    }
+
+    let mut lengths: Vec<usize> = chunks.iter().map(|c| c.text.chars().count()).collect();
+    lengths.sort_unstable();
+    let n = lengths.len() as f64;
+    stats.min_len = lengths[0];
+    stats.max_len = lengths[lengths.len() - 1];
+    stats.mean_len = lengths.iter().sum::<usize>() as f64 / n;
+    let mid = lengths.len() / 2;
+    stats.median_len = if lengths.len().is_multiple_of(2) {
+        (lengths[mid - 1] + lengths[mid]) as f64 / 2.0
+    } else {
+        lengths[mid] as f64
+    };
+    stats.std_dev = (lengths
+        .iter()
+        .map(|&len| (len as f64 - stats.mean_len).powi(2))
+        .sum::<f64>()
+        / n)
+        .sqrt();
+    stats.lengths = lengths;
+
+    stats.out_of_order = chunks
+        .windows(2)
+        .filter(|pair| pair[1].start < pair[0].start)
+        .count();
+
+    let mut spans: Vec<(usize, usize)> = chunks.iter().map(|c| (c.start, c.end)).collect();
+    spans.sort_unstable();
+    let mut reach = spans[0].0;
+    for (start, end) in spans {
+        if start < reach {
+            stats.overlapping += 1;
+            stats.overlap_bytes += end.min(reach) - start;
+        } else {
+            stats.gap_bytes += start - reach;
+        }
+        stats.covered_bytes += end.saturating_sub(start.max(reach));
+        reach = reach.max(end);
+    }
+
+    stats
+}
+
+/// Which parts of a text a list of chunks covers.
+///
+/// Ranges are half-open byte ranges into the text, sorted by start. Overlaps
+/// are expected from overlap-based strategies and are only described here.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct CoverageReport {
+    /// Length of the text in bytes.
+    pub text_len: usize,
+    /// Characters of the text covered by at least one chunk.
+    pub covered_chars: usize,
+    /// Bytes of the text covered by at least one chunk.
+    pub covered_bytes: usize,
+    /// Ranges no chunk covers, including before the first and after the last chunk.
+    pub gaps: Vec<(usize, usize)>,
+    /// Ranges covered by more than one chunk.
+    pub overlaps: Vec<(usize, usize)>,
+}
+
+impl CoverageReport {
+    /// Whether every byte of the text is covered by some chunk.
+    pub fn is_complete(&self) -> bool {
+        self.gaps.is_empty()
+    }
+}
+
+/// Report the ranges of `text` that `chunks` cover, leave uncovered, or cover twice.
+///
+/// Chunk spans are clamped to the text, so offsets past its end are ignored.
+pub fn verify_coverage(text: &str, chunks: &[Chunk]) -> CoverageReport {
+    let len = text.len();
+    let mut report = CoverageReport {
+        text_len: len,
+        ..Default::default()
+    };
+
+    let mut spans: Vec<(usize, usize)> = chunks
+        .iter()
+        .map(|c| (c.start.min(len), c.end.min(len)))
+        .filter(|(start, end)| start < end)
+        .collect();
+    spans.sort_unstable();
+
+    let mut reach = 0;
+    for (start, end) in spans {
+        if start > reach {
+            report.gaps.push((reach, start));
+        } else if start < reach {
+            let overlap_end = end.min(reach);
+            match report.overlaps.last_mut() {
+                Some(last) if last.1 >= start => last.1 = last.1.max(overlap_end),
+                _ => report.overlaps.push((start, overlap_end)),
+            }
+        }
+        if end > reach {
+            let new_start = start.max(reach);
+            report.covered_bytes += end - new_start;
+            // Count UTF-8 leading bytes so mid-character offsets don't panic
+            report.covered_chars += text.as_bytes()[new_start..end]
+                .iter()
+                .filter(|&&b| (b as i8) >= -0x40)
+                .count();
+            reach = end;
+        }
+    }
+    if reach < len {
+        report.gaps.push((reach, len));
+    }
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::{FixedSizeChunker, SlidingWindowChunker};
+    use crate::chunk::ChunkMetadata;
+    use crate::config::ChunkConfig;
+    use crate::traits::ChunkAlgorithm;
+
+    fn span(start: usize, end: usize) -> Chunk {
+        Chunk::with_uuid(
+            "x".repeat(end - start),
+            start,
+            end,
+            ChunkMetadata::for_method("test"),
+        )
+    }
+
+    #[test]
+    fn test_stats_fixed_size() {
+        let text = "hello world";
+        let chunks = FixedSizeChunker.chunk(text, &ChunkConfig::new(5));
+        let stats = chunk_stats(&chunks);
+
+        // "hello", " worl", "d"
+        assert_eq!(stats.count, 3);
+        assert_eq!((stats.min_len, stats.max_len), (1, 5));
+        assert!((stats.mean_len - 11.0 / 3.0).abs() < 1e-9);
+        assert_eq!(stats.median_len, 5.0);
+        assert_eq!(stats.covered_bytes, text.len());
+        assert_eq!(stats.coverage(text.len()), 1.0);
+        assert_eq!((stats.gap_bytes, stats.overlap_bytes), (0, 0));
+        assert_eq!((stats.overlapping, stats.out_of_order), (0, 0));
+        assert_eq!(stats.exceeding(4), 2);
+        assert_eq!(stats.exceeding(5), 0);
+    }
+
+    #[test]
+    fn test_stats_sliding_window() {
+        let text = "hello world!";
+        let config = ChunkConfig::new(5).with_overlap(2);
+        let chunks = SlidingWindowChunker.chunk(text, &config);
+        let stats = chunk_stats(&chunks);
+
+        // "hello", "lo wo", "world", "ld!" each share 2 bytes with the previous
+        assert_eq!(stats.count, 4);
+        assert_eq!(stats.median_len, 5.0);
+        assert_eq!(stats.overlapping, 3);
+        assert_eq!(stats.overlap_bytes, 6);
+        assert_eq!(stats.covered_bytes, text.len());
+        assert_eq!(stats.gap_bytes, 0);
+    }
+
+    #[test]
+    fn test_stats_gaps_and_order() {
+        let chunks = vec![span(10, 20), span(0, 5), span(15, 30)];
+        let stats = chunk_stats(&chunks);
+
+        assert_eq!(stats.out_of_order, 1);
+        assert_eq!(stats.gap_bytes, 5);
+        assert_eq!(stats.overlap_bytes, 5);
+        assert_eq!(stats.overlapping, 1);
+        assert_eq!(stats.covered_bytes, 25);
+        assert_eq!(stats.coverage(50), 0.5);
+        // Lengths 10, 5 and 15 around a mean of 10
+        assert!((stats.std_dev - (50.0f64 / 3.0).sqrt()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_coverage_fixed_size() {
+        let text = "日本語 and more text";
+        let chunks = FixedSizeChunker.chunk(text, &ChunkConfig::new(4));
+        let report = verify_coverage(text, &chunks);
+
+        assert!(report.is_complete());
+        assert!(report.gaps.is_empty());
+        assert!(report.overlaps.is_empty());
+        assert_eq!(report.covered_bytes, text.len());
+        assert_eq!(report.covered_chars, text.chars().count());
+    }
+
+    #[test]
+    fn test_coverage_sliding_window() {
+        let text = "hello world!";
+        let config = ChunkConfig::new(5).with_overlap(2);
+        let chunks = SlidingWindowChunker.chunk(text, &config);
+        let report = verify_coverage(text, &chunks);
+
+        // "hello", "lo wo", "world", "ld!" each share 2 bytes with the previous
+        assert!(report.is_complete());
+        assert_eq!(report.overlaps, vec![(3, 5), (6, 8), (9, 11)]);
+        assert_eq!(report.covered_bytes, text.len());
+    }
+
+    #[test]
+    fn test_coverage_gaps() {
+        let text = "x".repeat(40);
+        let chunks = vec![span(10, 20), span(5, 12), span(15, 30), span(18, 25)];
+        let report = verify_coverage(&text, &chunks);
+
+        assert!(!report.is_complete());
+        assert_eq!(report.gaps, vec![(0, 5), (30, 40)]);
+        assert_eq!(report.overlaps, vec![(10, 12), (15, 25)]);
+        assert_eq!((report.covered_bytes, report.covered_chars), (25, 25));
+        assert_eq!(verify_coverage("", &[]), CoverageReport::default());
+    }
+
+    #[test]
+    fn test_stats_empty() {
+        let stats = chunk_stats(&[]);
+        assert_eq!(stats.count, 0);
+        assert_eq!(stats.exceeding(0), 0);
+        assert_eq!(stats.coverage(0), 1.0);
+    }
+}
@@ -0,0 +1,241 @@
+//! Async chunk streams for tokio services (`async` feature).
+//!
+//! [`chunk_stream`] runs a chunking algorithm on tokio's blocking thread pool
+//! and hands the chunks to the caller through a bounded channel, so a slow
+//! consumer applies backpressure instead of buffering the whole document.
+//!
+//! ```
+//! use bunkatsu::{chunk_stream, ChunkConfig, SentenceChunker};
+//! use futures_util::StreamExt;
+//! use std::sync::Arc;
+//!
+//! # tokio::runtime::Runtime::new().unwrap().block_on(async {
+//! let mut stream = chunk_stream(
+//!     "First sentence. Second sentence.",
+//!     Arc::new(SentenceChunker),
+//!     ChunkConfig::new(16),
+//!     8,
+//! );
+//! while let Some(chunk) = stream.next().await {
+//!     println!("{}", chunk?.text);
+//! }
+//! # Ok::<(), bunkatsu::ChunkError>(())
+//! # }).unwrap();
+//! ```
+
+use crate::chunk::Chunk;
+use crate::config::ChunkConfig;
+use crate::error::ChunkError;
+use crate::traits::ChunkAlgorithm;
+use futures_core::Stream;
+use std::panic::{self, AssertUnwindSafe};
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use tokio::sync::mpsc;
+
+/// Stream of chunks returned by [`chunk_stream`].
+///
+/// Chunks arrive in document order. A chunking error or panic is yielded as
+/// a single `Err` item, after which the stream ends. Dropping the stream
+/// stops the background task at its next send.
+pub struct ChunkStream {
+    receiver: mpsc::Receiver<Result<Chunk, ChunkError>>,
+}
+
+impl Stream for ChunkStream {
+    type Item = Result<Chunk, ChunkError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.receiver.poll_recv(cx)
+    }
+}
+
+/// Chunk `text` with `algorithm` on the blocking thread pool, yielding chunks as a stream.
+///
+/// At most `buffer` chunks (minimum 1) are queued ahead of the consumer. The
+/// config is validated, the text normalized, and chunks are finalized with
+/// [`ChunkAlgorithm::chunk_finalized_iter`]: algorithms that produce chunks on
+/// demand (fixed_size, sliding_window, sentence, line) compute each chunk
+/// only when there is room for it, without `total_chunks` or `next_chunk_id`.
+///
+/// # Panics
+///
+/// Panics if called outside a tokio runtime.
+pub fn chunk_stream(
+    text: impl Into<String>,
+    algorithm: Arc<dyn ChunkAlgorithm>,
+    config: ChunkConfig,
+    buffer: usize,
+) -> ChunkStream {
+    let text = text.into();
+    let (sender, receiver) = mpsc::channel(buffer.max(1));
+
+    tokio::task::spawn_blocking(move || {
+        let text = config.normalize_owned(text);
+        let chunks = config
+            .validate()
+            .and_then(|()| config.check_input(&text))
+            .and_then(|()| {
+                catch_panic(algorithm.name(), || {
+                    algorithm.chunk_finalized_iter(text, &config)
+                })
+            });
+        let mut chunks = match chunks {
+            Ok(chunks) => chunks,
+            Err(err) => {
+                let _ = sender.blocking_send(Err(err));
+                return;
+            }
+        };
+
+        // Lazy algorithms do their work in `next`, so it can panic too
+        loop {
+            match catch_panic(algorithm.name(), || Ok(chunks.next())) {
+                Ok(Some(chunk)) => {
+                    // The receiver was dropped: stop early
+                    if sender.blocking_send(Ok(chunk)).is_err() {
+                        return;
+                    }
+                }
+                Ok(None) => return,
+                Err(err) => {
+                    let _ = sender.blocking_send(Err(err));
+                    return;
+                }
+            }
+        }
+    });
+
+    ChunkStream { receiver }
+}
+
+/// Run `f`, turning a panic into a [`ChunkError::ProcessingError`] naming the chunker.
+fn catch_panic<T>(name: &str, f: impl FnOnce() -> Result<T, ChunkError>) -> Result<T, ChunkError> {
+    panic::catch_unwind(AssertUnwindSafe(f)).unwrap_or_else(|payload| {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown panic".to_string());
+        Err(ChunkError::ProcessingError(format!(
+            "{} chunker panicked: {}",
+            name, message
+        )))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::{FixedSizeChunker, RegexChunker};
+    use crate::config::IdStrategy;
+    use futures_util::StreamExt;
+    use std::time::Duration;
+
+    /// Panics on every call.
+    struct Exploding;
+
+    impl ChunkAlgorithm for Exploding {
+        fn chunk(&self, _text: &str, _config: &ChunkConfig) -> Vec<Chunk> {
+            panic!("boom")
+        }
+
+        fn name(&self) -> &str {
+            "exploding"
+        }
+    }
+
+    #[tokio::test]
+    async fn test_stream_in_order() {
+        let text = "abcdefghij".repeat(100);
+        let config = ChunkConfig::new(7);
+        let expected = FixedSizeChunker.chunk(&text, &config);
+
+        let chunks: Vec<Chunk> = chunk_stream(text.clone(), Arc::new(FixedSizeChunker), config, 2)
+            .map(Result::unwrap)
+            .collect()
+            .await;
+
+        assert_eq!(chunks.len(), expected.len());
+        assert!(chunks
+            .iter()
+            .zip(&expected)
+            .all(|(a, b)| a.text == b.text && a.start == b.start));
+    }
+
+    #[tokio::test]
+    async fn test_stream_cancel_stops_task() {
+        let algorithm: Arc<dyn ChunkAlgorithm> = Arc::new(FixedSizeChunker);
+        let mut stream = chunk_stream(
+            "x".repeat(100_000),
+            Arc::clone(&algorithm),
+            ChunkConfig::new(10),
+            1,
+        );
+
+        for _ in 0..3 {
+            assert!(stream.next().await.unwrap().is_ok());
+        }
+        drop(stream);
+
+        // The blocking task releases its handle on the algorithm once it stops
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while Arc::strong_count(&algorithm) > 1 {
+                tokio::time::sleep(Duration::from_millis(5)).await;
+            }
+        })
+        .await
+        .expect("blocking task kept running after the stream was dropped");
+    }
+
+    #[tokio::test]
+    async fn test_stream_panic_is_error() {
+        let mut stream = chunk_stream("text", Arc::new(Exploding), ChunkConfig::new(10), 1);
+
+        let err = stream.next().await.unwrap().unwrap_err();
+        assert!(matches!(err, ChunkError::ProcessingError(_)));
+        assert!(err.to_string().contains("boom"));
+        assert!(stream.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_stream_finalizes_chunks() {
+        let config = ChunkConfig::new(5).with_id_strategy(IdStrategy::Sequential);
+        let chunks: Vec<Chunk> = chunk_stream("hello world", Arc::new(FixedSizeChunker), config, 1)
+            .map(Result::unwrap)
+            .collect()
+            .await;
+
+        let ids: Vec<&str> = chunks.iter().map(|c| c.id.as_str()).collect();
+        assert_eq!(ids, ["doc-0", "doc-1", "doc-2"]);
+        assert_eq!(chunks[2].metadata.prev_chunk_id.as_deref(), Some("doc-1"));
+    }
+
+    #[tokio::test]
+    async fn test_stream_chunk_error_is_error() {
+        // Regex chunking without a pattern fails in `try_chunk`
+        let mut stream = chunk_stream(
+            "a. b.",
+            Arc::new(RegexChunker::default()),
+            ChunkConfig::new(10),
+            1,
+        );
+
+        assert!(matches!(
+            stream.next().await,
+            Some(Err(ChunkError::InvalidConfig(_)))
+        ));
+        assert!(stream.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_stream_invalid_config() {
+        let mut stream = chunk_stream("text", Arc::new(FixedSizeChunker), ChunkConfig::new(0), 1);
+
+        assert!(matches!(
+            stream.next().await,
+            Some(Err(ChunkError::InvalidConfig(_)))
+        ));
+    }
+}
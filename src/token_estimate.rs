@@ -0,0 +1,246 @@
+//! Token-count estimates for sizing chunks to LLM context windows.
+//!
+//! [`estimate_tokens_cl100k`] approximates the `cl100k_base` tokenizer used by
+//! GPT-4 and GPT-3.5 (and is a reasonable proxy for other modern BPE
+//! tokenizers) in a single pass without loading a vocabulary. With the
+//! `tiktoken` feature, [`count_tokens_cl100k`] gives the exact count.
+
+use crate::error::ChunkError;
+use std::str::FromStr;
+
+/// Whether `c` is a CJK ideograph, kana, Hangul or full-width form, which
+/// `cl100k_base` encodes at about one token per character.
+fn is_cjk(c: char) -> bool {
+    matches!(
+        c as u32,
+        0x1100..=0x11FF
+            | 0x2E80..=0x9FFF
+            | 0xA960..=0xA97F
+            | 0xAC00..=0xD7FF
+            | 0xF900..=0xFAFF
+            | 0xFF00..=0xFFEF
+            | 0x20000..=0x3FFFF
+    )
+}
+
+/// Estimate the number of `cl100k_base` tokens in `text`.
+///
+/// Counts runs of characters: words take one token per 7 ASCII letters or
+/// 2 other letters, digits one per 3, CJK characters one each and
+/// punctuation one per 2. A single space merges into the following word;
+/// longer whitespace runs and line breaks take one token.
+///
+/// On English prose, Markdown, source code, CJK and European-language text
+/// the estimate is typically within 20% of the exact count, and tends to
+/// overestimate code and underestimate accented or non-Latin words. Expect
+/// larger errors on unusual input such as base64, long numbers or emoji.
+pub fn estimate_tokens_cl100k(text: &str) -> usize {
+    let is_word = |c: char| c.is_alphabetic() && !is_cjk(c);
+    let word_weight = |c: char| -> usize {
+        if c.is_ascii() {
+            2
+        } else {
+            7
+        }
+    };
+    let mut tokens = 0;
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if is_word(c) {
+            let mut weight = word_weight(c);
+            while let Some(next) = chars.next_if(|&n| is_word(n)) {
+                weight += word_weight(next);
+            }
+            tokens += weight.div_ceil(14);
+        } else if c.is_ascii_digit() {
+            let mut len = 1usize;
+            while chars.next_if(char::is_ascii_digit).is_some() {
+                len += 1;
+            }
+            tokens += len.div_ceil(3);
+        } else if c.is_whitespace() {
+            let mut line_break = c == '\n';
+            let mut len = 1;
+            while let Some(next) = chars.next_if(|n| n.is_whitespace()) {
+                line_break |= next == '\n';
+                len += 1;
+            }
+            if line_break || len > 1 {
+                tokens += 1;
+            }
+        } else if is_cjk(c) {
+            tokens += 1;
+        } else {
+            let mut len = 1usize;
+            while chars
+                .next_if(|n| !n.is_alphanumeric() && !n.is_whitespace())
+                .is_some()
+            {
+                len += 1;
+            }
+            tokens += len.div_ceil(2);
+        }
+    }
+
+    tokens
+}
+
+/// Count the exact number of `cl100k_base` tokens in `text`.
+#[cfg(feature = "tiktoken")]
+pub fn count_tokens_cl100k(text: &str) -> usize {
+    tiktoken_rs::cl100k_base_singleton()
+        .encode_ordinary(text)
+        .len()
+}
+
+/// How token counts are computed when chunks are sized in tokens.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TokenEstimator {
+    /// [`estimate_tokens_cl100k`] ("cl100k_estimate")
+    #[default]
+    Cl100kEstimate,
+    /// Exact [`count_tokens_cl100k`] ("cl100k"); needs the `tiktoken` feature
+    #[cfg(feature = "tiktoken")]
+    Cl100k,
+}
+
+impl TokenEstimator {
+    /// Name used for this estimator in Python.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TokenEstimator::Cl100kEstimate => "cl100k_estimate",
+            #[cfg(feature = "tiktoken")]
+            TokenEstimator::Cl100k => "cl100k",
+        }
+    }
+
+    /// Number of tokens in `text`.
+    pub fn count(&self, text: &str) -> usize {
+        match self {
+            TokenEstimator::Cl100kEstimate => estimate_tokens_cl100k(text),
+            #[cfg(feature = "tiktoken")]
+            TokenEstimator::Cl100k => count_tokens_cl100k(text),
+        }
+    }
+}
+
+impl FromStr for TokenEstimator {
+    type Err = ChunkError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "cl100k_estimate" => Ok(TokenEstimator::Cl100kEstimate),
+            #[cfg(feature = "tiktoken")]
+            "cl100k" => Ok(TokenEstimator::Cl100k),
+            #[cfg(not(feature = "tiktoken"))]
+            "cl100k" => Err(ChunkError::InvalidConfig(
+                "token estimator 'cl100k' requires the tiktoken feature".to_string(),
+            )),
+            _ => Err(ChunkError::InvalidConfig(format!(
+                "token estimator must be 'cl100k_estimate' or 'cl100k', got {:?}",
+                s
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Fixture texts with their exact `cl100k_base` token counts.
+    const FIXTURES: &[(&str, usize)] = &[
+        (
+            "The quick brown fox jumps over the lazy dog. Retrieval-augmented generation \
+             splits documents into chunks, embeds each chunk, and stores the vectors in an \
+             index so that a query can find the most relevant passages before the model answers.",
+            48,
+        ),
+        (
+            "# Installation\n\nRun `pip install bunkatsu` and import the `Chunker` class.\n\n\
+             ## Usage\n\n- Create a chunker\n- Call `chunk_markdown(text, max_size=512)`\n\
+             - Store the chunks\n",
+            46,
+        ),
+        (
+            "fn main() {\n    let chunks = FixedSizeChunker.chunk(&text, &ChunkConfig::new(512));\n    \
+             for chunk in chunks.iter().filter(|c| c.text.len() > 10) {\n        \
+             println!(\"{}: {}\", chunk.start, chunk.text);\n    }\n}\n",
+            59,
+        ),
+        (
+            "東京は日本の首都です。今日は天気が良いので、公園を散歩しました。文章を分割して検索の精度を高めます。",
+            53,
+        ),
+        (
+            "Быстрая коричневая лиса прыгает через ленивую собаку. Разбиение текста на фрагменты.",
+            42,
+        ),
+    ];
+
+    #[test]
+    fn test_estimate_within_tolerance() {
+        for &(text, exact) in FIXTURES {
+            let estimate = estimate_tokens_cl100k(text);
+            assert!(
+                estimate.abs_diff(exact) * 5 <= exact,
+                "estimate {} too far from {} for {:?}",
+                estimate,
+                exact,
+                text
+            );
+        }
+        assert_eq!(estimate_tokens_cl100k(""), 0);
+        assert_eq!(estimate_tokens_cl100k("hello world"), 2);
+    }
+
+    #[cfg(feature = "tiktoken")]
+    #[test]
+    fn test_count_exact() {
+        for &(text, exact) in FIXTURES {
+            assert_eq!(count_tokens_cl100k(text), exact);
+        }
+        assert_eq!(TokenEstimator::Cl100k.count("hello world"), 2);
+    }
+
+    #[test]
+    fn test_max_tokens_sizes_chunks() {
+        use crate::algorithms::{MarkdownChunker, ParagraphChunker, SentenceChunker};
+        use crate::config::ChunkConfig;
+        use crate::traits::ChunkAlgorithm;
+
+        let text = "One fish swims here. Two fish swim there.\n\n\
+                    Red fish are bright. Blue fish are calm. Old fish rest.\n\n\
+                    New fish arrive today.";
+        let config = ChunkConfig::new(10_000).with_max_tokens(10, TokenEstimator::Cl100kEstimate);
+        let algorithms: [&dyn ChunkAlgorithm; 3] = [
+            &SentenceChunker,
+            &ParagraphChunker,
+            &MarkdownChunker::default(),
+        ];
+        for algorithm in algorithms {
+            let chunks = algorithm.chunk(text, &config);
+            assert!(chunks.len() > 1, "{} kept one chunk", algorithm.name());
+            assert!(chunks.iter().all(|c| estimate_tokens_cl100k(&c.text) <= 10));
+        }
+        // Without max_tokens the same config packs by bytes into one chunk
+        assert_eq!(
+            SentenceChunker.chunk(text, &ChunkConfig::new(10_000)).len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_token_estimator_parse() {
+        assert_eq!(
+            "cl100k_estimate".parse::<TokenEstimator>().unwrap(),
+            TokenEstimator::Cl100kEstimate
+        );
+        assert_eq!(
+            "cl100k".parse::<TokenEstimator>().is_ok(),
+            cfg!(feature = "tiktoken")
+        );
+        assert!("gpt2".parse::<TokenEstimator>().is_err());
+    }
+}
@@ -2,12 +2,73 @@
 
 use crate::chunk::Chunk;
 use crate::config::ChunkConfig;
+use crate::error::ChunkError;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
 
 /// Trait for implementing chunking algorithms.
 pub trait ChunkAlgorithm: Send + Sync {
     /// Chunk the given text according to the algorithm's strategy.
+    ///
+    /// This is the algorithm's raw output: chunks get random UUIDs and the
+    /// config's post-processing is not applied. Use
+    /// [`chunk_finalized`](Self::chunk_finalized) for the finished chunks.
     fn chunk(&self, text: &str, config: &ChunkConfig) -> Vec<Chunk>;
 
+    /// Chunk the given text, returning an error when the input or config
+    /// cannot be chunked (e.g. invalid JSON or a missing regex pattern)
+    /// where [`chunk`](Self::chunk) returns no chunks.
+    fn try_chunk(&self, text: &str, config: &ChunkConfig) -> Result<Vec<Chunk>, ChunkError> {
+        Ok(self.chunk(text, config))
+    }
+
+    /// Chunk the given text and apply the config's post-processing with
+    /// [`Chunk::finalize`]: ID strategy, text normalization, `extra`,
+    /// checksums and language detection.
+    ///
+    /// Every public entry point (the registry, [`ChunkerBuilder`], the
+    /// Python bindings) chunks through this method.
+    ///
+    /// [`ChunkerBuilder`]: crate::ChunkerBuilder
+    fn chunk_finalized(&self, text: &str, config: &ChunkConfig) -> Result<Vec<Chunk>, ChunkError> {
+        Ok(Chunk::finalize(self.try_chunk(text, config)?, config))
+    }
+
+    /// Chunk the given text like [`chunk_finalized`](Self::chunk_finalized),
+    /// yielding the chunks from an iterator.
+    ///
+    /// The default chunks the whole text up front. Algorithms that can
+    /// produce chunks on demand override it with [`Chunk::finalize_iter`], so
+    /// stopping early skips the rest of the work.
+    fn chunk_finalized_iter(
+        &self,
+        text: String,
+        config: &ChunkConfig,
+    ) -> Result<Box<dyn Iterator<Item = Chunk> + Send + Sync>, ChunkError> {
+        Ok(Box::new(self.chunk_finalized(&text, config)?.into_iter()))
+    }
+
     /// Get the name of this algorithm.
     fn name(&self) -> &str;
+
+    /// Build a copy of this algorithm with its knobs set from a JSON object.
+    ///
+    /// Keys left out keep this instance's values. Unknown keys are an error,
+    /// and algorithms without options reject every key.
+    fn with_options(&self, options: &Value) -> Result<Box<dyn ChunkAlgorithm>, ChunkError> {
+        Err(ChunkError::InvalidConfig(format!(
+            "{} does not take options, got {}",
+            self.name(),
+            options
+        )))
+    }
+}
+
+/// Deserialize the options object of the algorithm `name`.
+pub(crate) fn parse_options<T: DeserializeOwned>(
+    name: &str,
+    options: &Value,
+) -> Result<T, ChunkError> {
+    T::deserialize(options)
+        .map_err(|e| ChunkError::InvalidConfig(format!("invalid {} options: {}", name, e)))
 }
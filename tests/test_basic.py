@@ -1,6 +1,12 @@
 """Tests for basic chunking algorithms (v0.1)."""
 
-from bunkatsu import SentenceDetector
+import time
+from itertools import islice
+
+import pytest
+
+import bunkatsu
+from bunkatsu import ChunkConfig, Chunker, SentenceDetector
 
 
 class TestFixedSize:
@@ -16,12 +22,68 @@ class TestFixedSize:
         chunks = chunker.chunk_fixed("hello", 10)
         assert chunks[0].metadata.method == "fixed_size"
 
+    def test_respect_words(self, chunker):
+        text = "The quick brown fox jumps over the lazy dog. " * 10
+        chunks = chunker.chunk_fixed(text, 20, respect_words=True)
+        for chunk in chunks:
+            assert len(chunk.text) <= 20
+            assert chunk.end == len(text) or " " in (text[chunk.end - 1], text[chunk.end])
+        assert "".join(c.text for c in chunks) == text
+
+    def test_graphemes(self, chunker):
+        family = "\U0001F468\u200d\U0001F469\u200d\U0001F467"
+        text = family * 3 + "e\u0301" * 3
+        chunks = chunker.chunk_fixed(text, 2, graphemes=True)
+        assert [c.text for c in chunks] == [family * 2, family + "e\u0301", "e\u0301" * 2]
+        encoded = text.encode()
+        assert all(encoded[c.start:c.end].decode() == c.text for c in chunks)
+
+        config = ChunkConfig(max_size=2, grapheme_boundaries=True)
+        lazy = list(chunker.iter_chunks(text, "fixed_size", config=config))
+        assert [c.text for c in lazy] == [c.text for c in chunks]
+        assert chunker.chunk_fixed(text, 2)[0].text == "\U0001F468\u200d"
+
+    def test_display_width(self, chunker):
+        text = "ab\u65e5\u672c\u8a9ecd"
+        chunks = chunker.chunk_fixed(text, 4, size_mode="display_width")
+        assert [c.text for c in chunks] == ["ab\u65e5", "\u672c\u8a9e", "cd"]
+
+        config = ChunkConfig(max_size=4, size_mode="display_width")
+        assert "size_mode='display_width'" in repr(config)
+        lazy = list(chunker.iter_chunks(text, "fixed_size", config=config))
+        assert [c.text for c in lazy] == [c.text for c in chunks]
+        with pytest.raises(ValueError, match="size mode"):
+            chunker.chunk_fixed(text, 4, size_mode="tokens")
+
 
 class TestSlidingWindow:
     def test_overlap(self, chunker):
         chunks = chunker.chunk_sliding("hello world!", 5, 2)
         assert chunks[0].metadata.overlap_chars is None
         assert chunks[1].metadata.overlap_chars == 2
+        assert chunks[1].overlap_text == "lo"
+        assert chunks[0].overlap_text is None
+
+    def test_final_window_overlap(self, chunker):
+        text = "hello world!"
+        chunks = chunker.chunk_sliding(text, 5, 2)
+        assert chunks[-1].text == "ld!"
+        assert chunks[-1].overlap_text == text[chunks[-1].start:chunks[-2].end] == "ld"
+        assert chunks[-1].metadata.overlap_chars == 2
+
+    def test_sentence_windows(self, chunker):
+        text = " ".join(f"Sentence number {i}." for i in range(1, 21))
+        chunks = chunker.chunk_sliding(text, max_size=5, overlap=2, unit="sentences")
+        assert len(chunks) == 6
+        assert chunks[1].text.startswith("Sentence number 4.")
+        assert chunks[-1].text.endswith("Sentence number 20.")
+        for prev, chunk in zip(chunks, chunks[1:]):
+            assert text[chunk.start:chunk.end] == chunk.text
+            assert chunk.metadata.overlap_chars == len(text[chunk.start:prev.end])
+
+    def test_invalid_unit(self, chunker):
+        with pytest.raises(ValueError):
+            chunker.chunk_sliding("a. b.", unit="words")
 
 
 class TestSentence:
@@ -33,8 +95,305 @@ class TestSentence:
         chunks = chunker.chunk_sentences("Hi.", 100, SentenceDetector.Unicode)
         assert len(chunks) >= 1
 
+    def test_cjk_terminators(self, chunker):
+        chunks = chunker.chunk_sentences("これは一文目です。これは二文目です。", 30)
+        assert [c.text for c in chunks] == ["これは一文目です。", "これは二文目です。"]
+
+    def test_multilingual_detector(self, chunker):
+        text = "See you soon. また明日…元気でね！"
+        chunks = chunker.chunk_sentences(text, 1, SentenceDetector.Multilingual)
+        assert [c.text for c in chunks] == ["See you soon.", "また明日…", "元気でね！"]
+        assert all(text.encode()[c.start:c.end].decode() == c.text for c in chunks)
+
+    def test_smart_detector(self, chunker):
+        text = "Dr. Smith went to Washington D.C. He arrived at 3.5 p.m."
+        chunks = chunker.chunk_sentences(text, 1, SentenceDetector.Smart)
+        assert [c.text for c in chunks] == [
+            "Dr. Smith went to Washington D.C.",
+            "He arrived at 3.5 p.m.",
+        ]
+
+    def test_custom_abbreviations(self, chunker):
+        config = ChunkConfig(max_size=1, sentence_detector=SentenceDetector.Smart, abbreviations=["Acme"])
+        chunks = chunker.chunk_sentences("Ask Acme. Corp. staff.", config=config)
+        assert [c.text for c in chunks] == ["Ask Acme. Corp.", "staff."]
+        assert config.abbreviations == ["Acme"]
+
+    def test_custom_segmenter(self):
+        text = "one two three four five"
+        seen = []
+
+        def segmenter(t):
+            seen.append(t)
+            return [13, 7]
+
+        chunker = Chunker(segmenter=segmenter)
+        config = ChunkConfig(max_size=9, sentence_detector=SentenceDetector.Custom)
+        chunks = chunker.chunk_sentences(text, config=config)
+        assert [c.text for c in chunks] == ["one two", "three", "four five"]
+        assert [(c.start, c.end) for c in chunks] == [(0, 7), (8, 13), (14, 23)]
+        assert seen == [text]
+        assert [c.text for c in chunker.iter_chunks(text, "sentence", config=config)] == [c.text for c in chunks]
+
+    def test_custom_segmenter_missing(self, chunker):
+        config = ChunkConfig(sentence_detector=SentenceDetector.Custom)
+        with pytest.raises(ValueError):
+            chunker.chunk_sentences("a. b.", config=config)
+
+    def test_hindi_danda(self, chunker):
+        text = "यह पहला वाक्य है। यह दूसरा है।"
+        assert len(chunker.chunk_sentences(text, 1)) == 1
+        chunks = chunker.chunk_sentences(text, 1, terminators=["।"])
+        assert [c.text for c in chunks] == ["यह पहला वाक्य है।", "यह दूसरा है।"]
+        assert chunks[1].start == text.encode().index("यह दूसरा".encode())
+
+    def test_empty_terminators(self, chunker):
+        with pytest.raises(ValueError):
+            chunker.chunk_sentences("a. b.", terminators=[])
+
+    def test_respect_enclosures(self, chunker):
+        text = 'He said "Go now. Run." and left. Then "oops. Bye.'
+        config = ChunkConfig(max_size=1, respect_enclosures=True)
+        chunks = chunker.chunk_sentences(text, config=config)
+        assert [c.text for c in chunks] == ['He said "Go now. Run." and left.', 'Then "oops.', "Bye."]
+        assert len(chunker.chunk_sentences(text, 1)) == 4
+
 
 class TestParagraph:
     def test_split(self, chunker):
         chunks = chunker.chunk_paragraphs("A.\n\nB.", 10)
         assert len(chunks) >= 1
+
+    def test_crlf_and_whitespace_blank_lines(self, chunker):
+        text = "First.\r\n\r\nSecond.\n \t\nThird."
+        chunks = chunker.chunk_paragraphs(text, 8)
+        assert [c.text for c in chunks] == ["First.", "Second.", "Third."]
+        assert all(text[c.start:c.end] == c.text for c in chunks)
+
+    def test_paragraph_separator(self, chunker):
+        text = "One line.\nTwo line.\nThree."
+        assert len(chunker.chunk_paragraphs(text, 100)) == 1
+        config = ChunkConfig(max_size=19, paragraph_separator="\n")
+        chunks = chunker.chunk_paragraphs(text, config=config)
+        assert [c.text for c in chunks] == ["One line.\nTwo line.", "Three."]
+        assert all(text[c.start:c.end] == c.text for c in chunks)
+
+    def test_preserve_separators_round_trip(self, chunker):
+        text = "  Intro line.\n\n\nFirst sentence. Second one!\n \nLast bit.  \n"
+        config = ChunkConfig(max_size=20, preserve_separators=True)
+        for chunks in [
+            chunker.chunk_paragraphs(text, config=config),
+            chunker.chunk_sentences(text, config=config),
+            chunker.chunk_fixed(text, config=config),
+            list(chunker.iter_chunks(text, "sentence", config=config)),
+        ]:
+            assert len(chunks) > 1
+            assert "".join(c.text for c in chunks) == text
+            assert all(c.original_slice(text) == c.text for c in chunks)
+
+    def test_balanced(self, chunker):
+        text = "\n\n".join(["Paragraph one here."] * 4 + ["End."])
+        greedy = chunker.chunk_paragraphs(text, 61)
+        balanced = chunker.chunk_paragraphs(text, config=ChunkConfig(max_size=61, balanced=True))
+        assert [len(c.text) for c in greedy] == [61, 25]
+        assert [len(c.text) for c in balanced] == [40, 46]
+
+    def test_max_merge_gap(self, chunker):
+        text = "Topic A.\n\n\n\n\n\nTopic B."
+        assert len(chunker.chunk_paragraphs(text, 100)) == 1
+        config = ChunkConfig(max_size=100, max_merge_gap=3)
+        assert [c.text for c in chunker.chunk_paragraphs(text, config=config)] == [
+            "Topic A.",
+            "Topic B.",
+        ]
+
+    def test_oversized_paragraph(self, chunker):
+        text = "Intro.\n\n日本語の文。 Second sentence here. Third sentence here."
+        chunks = chunker.chunk_paragraphs(text, 25)
+        assert [c.text for c in chunks] == [
+            "Intro.",
+            "日本語の文。",
+            "Second sentence here.",
+            "Third sentence here.",
+        ]
+        encoded = text.encode()
+        for chunk in chunks:
+            assert encoded[chunk.start : chunk.end].decode() == chunk.text
+        assert chunks[1].metadata.parent_chunk_id == chunks[3].metadata.parent_chunk_id
+
+    def test_strict_paragraphs(self, chunker):
+        text = "Intro.\n\nSecond sentence here. Third sentence here."
+        chunks = chunker.chunk_paragraphs(text, 25, strict_paragraphs=True)
+        assert [c.text for c in chunks] == ["Intro.", text[8:]]
+        config = ChunkConfig(max_size=25, strict_paragraphs=True)
+        assert len(chunker.chunk_paragraphs(text, config=config)) == 2
+
+
+class TestParagraphSentence:
+    def test_oversized_paragraph(self, chunker):
+        text = "Intro.\n\nOne alpha. Two bravo. Three charlie. Four delta."
+        chunks = chunker.chunk_paragraph_sentences(text, 30, overlap=15)
+        assert chunks[0].text == "Intro."
+        assert chunks[0].metadata.parent_chunk_id is None
+        assert len(chunks) > 2
+        parents = {c.metadata.parent_chunk_id for c in chunks[1:]}
+        assert len(parents) == 1 and None not in parents
+
+
+class TestDelimiter:
+    def test_split(self, chunker):
+        chunks = chunker.chunk_delimiter("a<<SPLIT>>b<<SPLIT>><<SPLIT>>c", "<<SPLIT>>", 1)
+        assert [c.text for c in chunks] == ["a", "b", "c"]
+
+    def test_keep_separator(self, chunker):
+        text = "one\n---\ntwo"
+        chunks = chunker.chunk_delimiter(text, "\n---\n", 100, keep_separator=True)
+        assert chunks[0].text == text
+
+    def test_keep_empty_segments(self, chunker):
+        config = ChunkConfig(max_size=1, skip_empty_segments=False)
+        chunks = chunker.chunk_delimiter("|a||b|", "|", config=config)
+        assert [c.text for c in chunks] == ["", "a", "", "b", ""]
+        chunks = chunker.chunk_delimiter("|a||b|", "|", 1)
+        assert [c.text for c in chunks] == ["a", "b"]
+
+    def test_empty_separator(self, chunker):
+        with pytest.raises(ValueError, match="separator"):
+            chunker.chunk_delimiter("abc", "", 10)
+
+
+class TestRegex:
+    def test_split(self, chunker):
+        text = "[09:00] hi\n[09:01] hello\n[09:02] bye"
+        chunks = chunker.chunk_regex(text, r"\[\d{2}:\d{2}\]", 12)
+        assert [c.text for c in chunks] == ["[09:00] hi", "[09:01] hello", "[09:02] bye"]
+
+    def test_invalid_pattern(self, chunker):
+        with pytest.raises(ValueError, match="pattern"):
+            chunker.chunk_regex("abc", "(unclosed", 10)
+        with pytest.raises(ValueError, match="keep_delimiter"):
+            chunker.chunk_regex("abc", "b", 10, keep_delimiter="middle")
+
+    def test_chunk_by_pattern(self, chunker):
+        text = "Article 1. Scope.\nArticle 2. Terms."
+        chunks = chunker.chunk_by_pattern(text, r"Article \d+\.", 10)
+        assert [c.text for c in chunks] == ["Article 1. Scope.", "Article 2. Terms."]
+        chunks = chunker.chunk_by_pattern(text, r"Article \d+\.", 10, keep_delimiter="drop")
+        assert [c.text for c in chunks] == ["Scope.", "Terms."]
+        assert len(chunker.chunk_by_pattern(text, r"Section \d+", 100)) == 1
+
+
+class TestLines:
+    def test_crlf_and_trailing_line(self, chunker):
+        text = "a,b\r\n1,2\r\n3,4"
+        chunks = chunker.chunk_lines(text, 10)
+        assert [c.text for c in chunks] == ["a,b\r\n1,2\r\n", "3,4"]
+        assert chunks[1].end == len(text)
+
+    def test_overlap_lines(self, chunker):
+        chunks = chunker.chunk_lines("aa\nbb\ncc\ndd\n", 9, overlap_lines=1)
+        assert [c.text for c in chunks] == ["aa\nbb\ncc\n", "cc\ndd\n"]
+
+    def test_lines_per_chunk(self, chunker):
+        text = "1\r\n2\r\n3\r\n4\r\n5"
+        chunks = chunker.chunk_lines(text, lines_per_chunk=2, overlap=1)
+        assert [c.text for c in chunks] == ["1\r\n2\r\n", "2\r\n3\r\n", "3\r\n4\r\n", "4\r\n5"]
+        assert chunks[-1].end == len(text)
+        counted = chunker.chunk("line", text, max_size=2, config=ChunkConfig(overlap=1), options={"mode": "count"})
+        assert [c.text for c in counted] == [c.text for c in chunks]
+
+    def test_lines_per_chunk_overlap_too_large(self, chunker):
+        with pytest.raises(ValueError, match=r"overlap \(2 lines\) must be less than lines_per_chunk \(2\)"):
+            chunker.chunk_lines("a\nb", lines_per_chunk=2, overlap=2)
+        with pytest.raises(ValueError, match="overlap_lines"):
+            chunker.chunk_lines("a\nb", lines_per_chunk=2, overlap_lines=1)
+        with pytest.raises(ValueError, match="lines_per_chunk"):
+            chunker.chunk_lines("a\nb", 10, overlap=1)
+
+
+class TestWords:
+    def test_word_limit(self, chunker):
+        text = "Lorem ipsum  dolor sit amet, consectetur adipiscing elit. " * 30
+        chunks = chunker.chunk_words(text, 25, overlap=5)
+        assert len(chunks) > 1
+        assert all(len(c.text.split()) <= 25 for c in chunks)
+        assert chunks[0].text.startswith("Lorem ipsum  dolor")
+
+
+class TestIterChunks:
+    def test_matches_list(self, chunker):
+        text = "First sentence. Second sentence! Third one? Fourth."
+        lazy = list(chunker.iter_chunks(text, "sentence", max_size=20))
+        eager = chunker.chunk_sentences(text, max_size=20)
+        assert [c.text for c in lazy] == [c.text for c in eager]
+
+    def test_buffered_method(self, chunker):
+        config = ChunkConfig(max_size=3, separator="|")
+        chunks = list(chunker.iter_chunks("aa|bb|cc", "delimiter", config=config))
+        expected = chunker.chunk_delimiter("aa|bb|cc", "|", config=config)
+        assert [c.text for c in chunks] == [c.text for c in expected]
+
+    def test_sequential_ids(self, chunker):
+        chunks = list(chunker.iter_chunks("abcdef", "fixed_size", max_size=2, id_strategy="sequential"))
+        assert [c.id for c in chunks] == ["doc-0", "doc-1", "doc-2"]
+
+    def test_large_document_partial(self, chunker):
+        text = "The quick brown fox jumps over the lazy dog. " * 1_200_000  # ~54 MB
+        for method in ("fixed_size", "sliding_window", "sentence", "line"):
+            started = time.perf_counter()
+            chunks = list(islice(chunker.iter_chunks(text, method, max_size=100), 3))
+            assert len(chunks) == 3
+            assert time.perf_counter() - started < 2.0
+
+    def test_unknown_method(self, chunker):
+        with pytest.raises(ValueError):
+            chunker.iter_chunks("text", "nope")
+
+
+class TestValidation:
+    def test_error_classes(self, chunker):
+        with pytest.raises(bunkatsu.AlgorithmNotFoundError):
+            chunker.iter_chunks("text", "nope")
+        with pytest.raises(bunkatsu.InvalidConfigError, match="max_size"):
+            chunker.chunk_fixed("hello", 0)
+        with pytest.raises(bunkatsu.EmptyInputError):
+            chunker.chunk_fixed("  ", config=ChunkConfig(error_on_empty=True))
+        with pytest.raises(bunkatsu.ProcessingError):
+            Chunker(segmenter=lambda t: [99]).chunk_sentences(
+                "hi", config=ChunkConfig(sentence_detector=SentenceDetector.Custom)
+            )
+        for cls in (bunkatsu.InvalidConfigError, bunkatsu.AlgorithmNotFoundError):
+            assert issubclass(cls, bunkatsu.BunkatsuError)
+            assert issubclass(cls, ValueError)
+
+    def test_zero_max_size(self, chunker):
+        with pytest.raises(ValueError, match="max_size"):
+            chunker.chunk_fixed("hello", 0)
+
+    def test_overlap_not_less_than_max_size(self, chunker):
+        with pytest.raises(ValueError, match="overlap"):
+            chunker.chunk_sliding("hello world", 5, 5)
+
+    def test_every_entry_point_validates(self, chunker):
+        calls = [
+            (chunker.chunk_fixed, ()),
+            (chunker.chunk_sliding, ()),
+            (chunker.chunk_sentences, ()),
+            (chunker.chunk_paragraphs, ()),
+            (chunker.chunk_paragraph_sentences, ()),
+            (chunker.chunk_markdown, ()),
+            (chunker.chunk_headings, ()),
+            (chunker.chunk_recursive, ()),
+            (chunker.chunk_html, ()),
+            (chunker.chunk_json, ()),
+            (chunker.chunk_lines, ()),
+            (chunker.chunk_words, ()),
+            (chunker.chunk_delimiter, (",",)),
+            (chunker.chunk_regex, (",",)),
+        ]
+        for config in (ChunkConfig(max_size=0), ChunkConfig(max_size=4, overlap=4)):
+            for method, args in calls:
+                with pytest.raises(ValueError):
+                    method("[1, 2]", *args, config=config)
+            with pytest.raises(ValueError):
+                chunker.iter_chunks("a, b", "word", config=config)
@@ -1,5 +1,28 @@
 """Tests for Chunk object properties."""
 
+import copy
+import pickle
+import threading
+import time
+from concurrent.futures import ProcessPoolExecutor, ThreadPoolExecutor
+
+import pytest
+
+from bunkatsu import (
+    AlgorithmNotFoundError,
+    Chunk,
+    ChunkConfig,
+    ChunkIndex,
+    ChunkMetadata,
+    InvalidConfigError,
+    ProcessingError,
+    load_jsonl,
+    merge_small,
+    save_jsonl,
+    stats,
+    verify_coverage,
+)
+
 
 class TestChunk:
     def test_has_id(self, chunker):
@@ -15,6 +38,97 @@ class TestChunk:
         chunks = chunker.chunk_fixed("hello", 10)
         assert len(chunks[0]) == 5
 
+    def test_pickle_round_trip(self, chunker):
+        text = "# Guide\n\nIntro.\n\n## Setup\n\nRun it."
+        config = ChunkConfig(max_size=1000, compute_checksum=True, id_strategy="sequential")
+        chunks = chunker.chunk_markdown(text, config=config, extra={"source": "a.md"})
+        for chunk in chunks:
+            loaded = pickle.loads(pickle.dumps(chunk))
+            assert loaded == chunk
+            assert loaded.id == chunk.id
+            assert loaded.metadata.to_dict() == chunk.metadata.to_dict()
+            assert loaded.metadata.prev_chunk_id == chunk.metadata.prev_chunk_id
+        assert pickle.loads(pickle.dumps(chunks[0])).metadata.prev_chunk_id is None
+        metadata = ChunkMetadata("line", language="en", language_confidence=0.5, merged_from=["a", "b"])
+        loaded = pickle.loads(pickle.dumps(metadata))
+        assert loaded == metadata
+        assert (loaded.language_confidence, loaded.merged_from) == (0.5, ["a", "b"])
+
+    def test_copy(self, chunker):
+        chunk = chunker.chunk_paragraphs("One.\n\nTwo.", extra={"k": "v"})[0]
+        for clone in (copy.copy(chunk), copy.deepcopy(chunk)):
+            assert clone == chunk and clone.id == chunk.id
+            assert clone.metadata.extra == {"k": "v"}
+        assert copy.deepcopy(chunk.metadata) == chunk.metadata
+
+    def test_process_pool(self, chunker):
+        chunks = chunker.chunk_sentences("One fish. Two fish. Red fish.", 10)
+        with ProcessPoolExecutor(max_workers=2) as executor:
+            results = list(executor.map(copy.copy, chunks))
+        assert results == chunks
+        assert [c.id for c in results] == [c.id for c in chunks]
+
+    def test_repr_multibyte(self):
+        text = "日本語の文章を分割します。" * 16
+        chunk = Chunk("a", text[:200], 0, 600, ChunkMetadata("fixed_size"))
+        assert repr(chunk) == f"Chunk(id='a', text='{text[:50]}...', start=0, end=600)"
+        assert str(chunk) == text[:200]
+        short = Chunk("b", "😀" * 50, 0, 200, ChunkMetadata("fixed_size"))
+        assert "..." not in repr(short)
+
+    def test_repr_escapes(self):
+        text = "It's one line\nand\tanother \\ here"
+        chunk = Chunk("a", text, 0, len(text), ChunkMetadata("line"))
+        expected = r"Chunk(id='a', text='It\'s one line\nand\tanother \\ here', start=0, "
+        assert repr(chunk) == expected + f"end={len(text)})"
+        assert "\n" not in repr(chunk)
+        assert str(chunk) == text
+
+    def test_eq_includes_id(self, chunker):
+        first = chunker.chunk_fixed("hello world", 5)
+        second = chunker.chunk_fixed("hello world", 5)
+        assert first[0].id != second[0].id
+        assert first[0] != second[0]
+        assert len(set(first + second)) == 6
+        # Deterministic IDs make separate runs compare equal
+        first = chunker.chunk_fixed("hello world", 5, id_strategy="sequential")
+        second = chunker.chunk_fixed("hello world", 5, id_strategy="sequential")
+        assert first == second
+        assert hash(first[0]) == hash(second[0])
+        assert first[0] != first[1]
+        assert first[0] != "hello"
+        assert len(set(first + second)) == 3
+        renamed = Chunk("other", first[0].text, first[0].start, first[0].end, first[0].metadata)
+        assert renamed != first[0]
+
+    def test_metadata_eq(self):
+        a = ChunkMetadata("fixed_size", extra={"b": "2", "a": "1"})
+        b = ChunkMetadata("fixed_size", extra={"a": "1", "b": "2"})
+        assert a == b
+        assert hash(a) == hash(b)
+        assert a != ChunkMetadata("fixed_size", section="s")
+        assert {a, b} == {a}
+
+    def test_metadata_eq_ignores_chunk_links(self, chunker):
+        text = "Intro.\n\nOne alpha. Two bravo. Three charlie. Four delta."
+        first = chunker.chunk_paragraph_sentences(text, 30, overlap=15)
+        second = chunker.chunk_paragraph_sentences(text, 30, overlap=15)
+        assert first[1].metadata.parent_chunk_id != second[1].metadata.parent_chunk_id
+        assert first[1].metadata == second[1].metadata
+        assert hash(first[1].metadata) == hash(second[1].metadata)
+        a = ChunkMetadata("fixed_size", parent_chunk_id="p1")
+        b = ChunkMetadata("fixed_size", parent_chunk_id="p2")
+        assert a == b and hash(a) == hash(b)
+
+
+    def test_original_slice(self, chunker):
+        text = "First paragraph.\n\n   Second paragraph."
+        chunks = chunker.chunk_paragraphs(text, 20)
+        assert chunks[1].original_slice(text) == "Second paragraph."
+        assert all(c.original_slice(text) == text[c.start:c.end] for c in chunks)
+        with pytest.raises(ProcessingError, match="not a valid slice"):
+            chunks[1].original_slice("short")
+
 
 class TestChunkerMethods:
     def test_available_methods(self, chunker):
@@ -24,9 +138,304 @@ class TestChunkerMethods:
             "sliding_window",
             "sentence",
             "paragraph",
+            "paragraph_sentence",
             "markdown",
             "heading",
             "recursive",
+            "html",
+            "json",
+            "delimiter",
+            "regex",
+            "line",
+            "word",
+            "latex",
+        ]
+        # The list comes from the algorithm registry; xml is behind a feature flag
+        assert set(methods) - {"xml"} == set(expected)
+        assert methods == sorted(methods)
+
+    def test_available_methods_dispatch(self, chunker):
+        config = ChunkConfig(separator="|", pattern=r"\.")
+        for method in chunker.available_methods():
+            if method == "xml":
+                # Needs element paths; only reachable through chunk_xml
+                continue
+            text = '["a", "b"]' if method == "json" else "Some text. More | text."
+            chunks = list(chunker.iter_chunks(text, method, config=config))
+            assert chunks, method
+
+    def test_chunk_with_options(self, chunker):
+        text = "# A\n\nOne.\n\n## B\n\nTwo.\n\n### C\n\nThree."
+        assert len(chunker.chunk("heading", text, max_size=1000)) == 2
+        chunks = chunker.chunk("heading", text, max_size=1000, options={"levels": [1, 2, 3]})
+        assert [c.metadata.section for c in chunks] == ["h1: A", "h2: B", "h3: C"]
+
+        xml = "<doc><p>One</p><p>Two</p></doc>"
+        chunks = chunker.chunk("xml", xml, max_size=5, options={"element_paths": ["doc/p"]})
+        assert [c.text for c in chunks] == ["One", "Two"]
+
+    def test_chunk_unknown_options(self, chunker):
+        with pytest.raises(InvalidConfigError, match="unknown field `level`"):
+            chunker.chunk("heading", "# A", options={"level": [1]})
+        with pytest.raises(InvalidConfigError, match="does not take options"):
+            chunker.chunk("fixed_size", "text", options={"size": 3})
+        with pytest.raises(AlgorithmNotFoundError):
+            chunker.chunk("nope", "text")
+
+    def test_chunk_regex_pattern_errors(self, chunker):
+        with pytest.raises(InvalidConfigError, match="pattern is required"):
+            chunker.chunk("regex", "a. b.")
+        with pytest.raises(InvalidConfigError):
+            chunker.chunk("regex", "a. b.", config=ChunkConfig(pattern="("))
+        chunks = chunker.chunk("regex", "a. b.", max_size=3, config=ChunkConfig(pattern=r"\. "))
+        assert [c.text for c in chunks] == ["a", ". b."]
+
+
+class TestChunkMetrics:
+    def test_lexical_richness(self, chunker):
+        assert chunker.chunk_fixed("cat dog bird", 100)[0].lexical_richness == 1.0
+        assert chunker.chunk_fixed("the the the the dog", 100)[0].lexical_richness < 0.5
+
+
+class TestChunkMetadata:
+    def test_section_path(self, chunker):
+        text = "# Installation\n\n## Linux\n\n### Troubleshooting\n\nFix it."
+        chunk = chunker.chunk_markdown(text)[-1]
+        assert chunk.metadata.section_path == ["Installation", "Linux", "Troubleshooting"]
+        assert chunk.metadata.section == "h3: Troubleshooting"
+        assert chunk.metadata.to_dict()["section_path"] == chunk.metadata.section_path
+
+    def test_block_type(self, chunker):
+        table = "| a | b |\n|---|---|\n| 1 | 2 |\n| 3 | 4 |"
+        chunks = chunker.chunk_markdown("Intro.\n\n" + table + "\n\nOutro.", max_size=20)
+        assert [c.metadata.block_type for c in chunks] == [None, "table", None]
+        assert chunks[1].text == table
+        assert chunks[1].metadata.to_dict()["block_type"] == "table"
+
+    def test_document_extra(self, chunker):
+        extra = {"source": "docs/日本語.md", "作者": "山田"}
+        chunks = chunker.chunk_fixed("hello world", 5, extra=extra)
+        assert all(c.metadata.extra == extra for c in chunks)
+        assert chunks[0].metadata.to_dict()["extra"] == extra
+        assert chunks[0].metadata.method == "fixed_size"
+
+    def test_document_extra_empty(self, chunker):
+        chunks = chunker.chunk_paragraphs("One.\n\nTwo.", extra={})
+        assert all(c.metadata.extra == {} for c in chunks)
+        assert "extra" not in chunks[0].metadata.to_dict()
+
+    def test_document_extra_config(self, chunker):
+        config = ChunkConfig(max_size=5, extra={"source": "a.txt", "lang": "en"})
+        chunks = chunker.chunk_fixed("hello world", config=config, extra={"lang": "ja"})
+        assert chunks[0].metadata.extra == {"source": "a.txt", "lang": "ja"}
+        lazy = list(chunker.iter_chunks("hello world", "fixed_size", config=config))
+        assert [c.metadata.extra for c in lazy] == [{"source": "a.txt", "lang": "en"}] * 3
+
+    def test_document_extra_keeps_library_keys(self, chunker):
+        chunks = chunker.chunk_lines("ok\nthis line is long", 5, extra={"split_line": "no"})
+        assert chunks[0].metadata.extra == {"split_line": "no"}
+        assert chunks[1].metadata.extra == {"split_line": "true"}
+
+    def test_chunk_positions(self, chunker):
+        text = "Short intro paragraph.\n\nFirst long sentence goes here. Second long sentence follows it."
+        chunks = chunker.chunk_recursive(text, 40, id_strategy="sequential")
+        assert [c.metadata.chunk_index for c in chunks] == [0, 1, 2]
+        assert {c.metadata.total_chunks for c in chunks} == {3}
+        assert chunks[1].metadata.prev_chunk_id == chunks[0].id == "doc-0"
+        assert chunks[1].metadata.next_chunk_id == chunks[2].id
+        assert chunks[2].metadata.next_chunk_id is None
+        assert chunks[0].metadata.to_dict()["total_chunks"] == 3
+        assert "prev_chunk_id" not in chunks[0].metadata.to_dict()
+
+    def test_lazy_chunk_positions(self, chunker):
+        chunks = list(chunker.iter_chunks("hello world", "fixed_size", 5))
+        assert [c.metadata.chunk_index for c in chunks] == [0, 1, 2]
+        assert chunks[2].metadata.prev_chunk_id == chunks[1].id
+        assert chunks[0].metadata.total_chunks is None
+
+
+    def test_detect_language(self, chunker):
+        text = (
+            "Chunking splits long documents into smaller passages so that each one "
+            "can be embedded and retrieved on its own.\n\n"
+            "東京は日本の首都です。今日は天気が良いので、公園を散歩しました。"
+        )
+        chunks = chunker.chunk_paragraphs(text, 200, detect_language=True)
+        assert [c.metadata.language for c in chunks] == ["en", "ja"]
+        assert chunks[0].metadata.language_confidence > 0.9
+        assert chunks[1].metadata.to_dict()["language"] == "ja"
+        lazy = list(chunker.iter_chunks(text, "sentence", 200, detect_language=True))
+        assert [c.metadata.language for c in lazy] == ["en", "ja"]
+        assert chunker.chunk_paragraphs(text, 200)[0].metadata.language is None
+
+
+class TestStats:
+    def test_fixed(self, chunker):
+        text = "hello world"
+        result = stats(chunker.chunk_fixed(text, 5), original_len=len(text), max_size=4)
+        assert result["count"] == 3
+        assert (result["min_len"], result["max_len"], result["median_len"]) == (1, 5, 5.0)
+        assert result["coverage"] == 1.0
+        assert result["exceeding"] == 2
+        assert result["gap_bytes"] == result["overlap_bytes"] == result["out_of_order"] == 0
+
+    def test_sliding(self, chunker):
+        result = stats(chunker.chunk_sliding("hello world!", 5, 2))
+        assert result["overlapping"] == 3
+        assert result["overlap_bytes"] == 6
+        assert result["coverage"] is None
+        assert result["exceeding"] is None
+
+    def test_out_of_order(self, chunker):
+        chunks = chunker.chunk_fixed("hello world", 5)
+        assert stats(chunks[::-1])["out_of_order"] == 2
+
+    def test_empty(self):
+        assert stats([])["count"] == 0
+
+
+class TestVerifyCoverage:
+    def test_fixed(self, chunker):
+        text = "hello world"
+        report = verify_coverage(text, chunker.chunk_fixed(text, 5))
+        assert report["complete"]
+        assert report["gaps"] == report["overlaps"] == []
+        assert report["covered_chars"] == len(text)
+
+    def test_sliding(self, chunker):
+        text = "hello world!"
+        report = verify_coverage(text, chunker.chunk_sliding(text, 5, 2))
+        assert report["complete"]
+        assert report["overlaps"] == [(3, 5), (6, 8), (9, 11)]
+
+    def test_gaps(self, chunker):
+        text = "First.\n\n\nSecond."
+        report = verify_coverage(text, chunker.chunk_paragraphs(text, 6))
+        assert not report["complete"]
+        assert report["gaps"] == [(6, 9)]
+
+
+class TestJsonl:
+    def test_round_trip(self, chunker, tmp_path):
+        text = "# Guide\n\nIntro.\n\n## Setup\n\nRun it."
+        chunks = chunker.chunk_markdown(text, id_strategy="sequential", extra={"source": "a.md"})
+        path = tmp_path / "chunks.jsonl"
+        save_jsonl(chunks, path)
+        assert len(path.read_text().splitlines()) == len(chunks)
+
+        loaded = load_jsonl(str(path))
+        assert [(c.id, c.text, c.start, c.end) for c in loaded] == [
+            (c.id, c.text, c.start, c.end) for c in chunks
+        ]
+        assert [c.metadata for c in loaded] == [c.metadata for c in chunks]
+        assert loaded[0].metadata.prev_chunk_id is None
+        assert loaded[1].metadata.prev_chunk_id == "doc-0"
+        assert loaded[0].metadata.overlap_chars is None
+
+    def test_invalid_line(self, tmp_path):
+        path = tmp_path / "bad.jsonl"
+        path.write_text('{"id": "a"}\n')
+        with pytest.raises(ProcessingError, match="line 1"):
+            load_jsonl(path)
+        with pytest.raises(FileNotFoundError):
+            load_jsonl(tmp_path / "missing.jsonl")
+
+
+class TestChunkIndex:
+    def test_sliding_overlaps(self, chunker):
+        chunks = chunker.chunk_sliding("hello world!", 5, 2)
+        index = ChunkIndex(chunks)
+        assert len(index) == 4
+        assert index.find(3) == [chunks[0].id, chunks[1].id]
+        assert index.find(11) == [chunks[3].id]
+        assert index.find(12) == []
+        assert index.find_range(4, 7) == [c.id for c in chunks[:3]]
+
+    def test_gaps(self, chunker):
+        text = "First.\n\n\nSecond."
+        chunks = chunker.chunk_paragraphs(text, 6)
+        index = ChunkIndex(chunks)
+        assert index.find(7) == []
+        assert index.find(9) == [chunks[1].id]
+
+
+class TestMergeSmall:
+    def test_heading_sections(self, chunker):
+        text = "# A\n\nOne.\n\n# B\n\nTwo.\n\n# C\n\nA much longer closing section body."
+        chunks = chunker.chunk_headings(text, 1000)
+        merged = merge_small(chunks, 20, 40)
+        assert [c.text for c in merged] == ["# A\n\nOne.\n\n# B\n\nTwo.", chunks[2].text]
+        assert merged[0].metadata.merged_from == [chunks[0].id, chunks[1].id]
+        assert merged[0].metadata.section == "h1: A"
+        assert (merged[0].start, merged[0].end) == (chunks[0].start, chunks[1].end)
+        assert merged[1].metadata.merged_from is None
+        assert [c.metadata.chunk_index for c in merged] == [0, 1]
+
+
+class TestHierarchical:
+    TEXT = "One fish. Two fish.\n\nRed fish. Blue fish. Old fish.\n\nNew fish."
+
+    def test_children_within_parents(self, chunker):
+        parents, children = chunker.chunk_hierarchical(self.TEXT, 45, 10)
+        assert len(parents) == 2
+        assert [c.text for c in children][:3] == ["One fish.", "Two fish.", "Red fish."]
+        by_id = {p.id: p for p in parents}
+        for child in children:
+            parent = by_id[child.metadata.parent_chunk_id]
+            assert parent.start <= child.start and child.end <= parent.end
+            assert child.original_slice(self.TEXT) == child.text
+        assert [c.metadata.chunk_index for c in children] == list(range(len(children)))
+
+    def test_sequential_ids_and_methods(self, chunker):
+        parents, children = chunker.chunk_hierarchical(
+            self.TEXT, 1000, 20, id_strategy="sequential", method_child="fixed_size"
+        )
+        assert [p.id for p in parents] == ["doc-0"]
+        assert children[0].id == "doc-1"
+        assert {c.metadata.parent_chunk_id for c in children} == {"doc-0"}
+        assert {c.metadata.method for c in children} == {"fixed_size"}
+        with pytest.raises(AlgorithmNotFoundError):
+            chunker.chunk_hierarchical(self.TEXT, method_parent="nope")
+        with pytest.raises(InvalidConfigError):
+            chunker.chunk_hierarchical(self.TEXT, child_size=0)
+
+
+class TestConcurrency:
+    TEXT = "The quick brown fox jumps over the lazy dog. " * 100_000
+
+    def test_gil_released(self, chunker):
+        begin = time.perf_counter()
+        chunker.chunk_sentences(self.TEXT, 200)
+        duration = time.perf_counter() - begin
+        started = threading.Event()
+        times = {}
+
+        def work():
+            times["start"] = time.perf_counter()
+            started.set()
+            chunker.chunk_sentences(self.TEXT, 200)
+
+        worker = threading.Thread(target=work)
+        worker.start()
+        started.wait()
+        # This thread can only wake up mid-call if the worker released the GIL
+        assert time.perf_counter() - times["start"] < duration / 2
+        chunks = chunker.chunk_sentences(self.TEXT, 200)
+        worker.join()
+        assert len(chunks) > 1
+
+    def test_threaded_results_match(self, chunker):
+        text = self.TEXT[:20_000]
+        calls = [
+            lambda: chunker.chunk_fixed(text, 100),
+            lambda: chunker.chunk_sliding(text, 100, 20),
+            lambda: chunker.chunk_sentences(text, 200),
+            lambda: chunker.chunk_recursive(text, 300),
+            lambda: chunker.chunk_words(text, 50),
         ]
-        for m in expected:
-            assert m in methods
+        spans = lambda chunks: [(c.text, c.start, c.end) for c in chunks]
+        expected = [spans(call()) for call in calls]
+        with ThreadPoolExecutor(max_workers=4) as pool:
+            results = [pool.submit(call) for call in calls * 4]
+            got = [spans(r.result()) for r in results]
+        assert got == expected * 4
@@ -0,0 +1,173 @@
+"""Tests for the Python-facing ChunkConfig."""
+
+import copy
+import pickle
+
+import pytest
+
+from bunkatsu import Chunk, ChunkConfig, SentenceDetector
+
+
+class TestChunkConfig:
+    def test_defaults(self):
+        config = ChunkConfig()
+        assert config.max_size == 512
+        assert config.overlap == 0
+        assert config.sentence_detector == SentenceDetector.Regex
+
+    def test_repr(self):
+        config = ChunkConfig(max_size=100, overlap=10)
+        assert repr(config).startswith("ChunkConfig(max_size=100, overlap=10,")
+
+    def test_eq(self):
+        assert ChunkConfig(100) == ChunkConfig(max_size=100)
+        assert ChunkConfig(100) != ChunkConfig(200)
+
+    def test_pickle_round_trip(self):
+        config = ChunkConfig(
+            64, 8, SentenceDetector.Unicode, preserve_html_blocks=True, id_strategy="hash"
+        )
+        assert pickle.loads(pickle.dumps(config)) == config
+
+    def test_copy(self):
+        config = ChunkConfig(64, 8)
+        clone = copy.deepcopy(config)
+        clone.max_size = 10
+        assert config.max_size == 64
+        assert copy.copy(config) == config
+
+
+class TestConfigInMethods:
+    def test_config_applies(self, chunker):
+        chunks = chunker.chunk_fixed("hello world", config=ChunkConfig(5))
+        assert chunks[0].text == "hello"
+
+    def test_kwargs_override_config(self, chunker):
+        config = ChunkConfig(max_size=5, overlap=2)
+        chunks = chunker.chunk_sliding("hello world!", max_size=6, config=config)
+        assert chunks[0].text == "hello "
+        assert chunks[1].metadata.overlap_chars == 2
+
+    def test_shared_config(self, chunker):
+        config = ChunkConfig(max_size=1000)
+        assert len(chunker.chunk_paragraphs("A.\n\nB.", config=config)) == 1
+        assert len(chunker.chunk_markdown("# A\n\nB.", config=config)) == 1
+        assert len(chunker.chunk("paragraph", "A.\n\nB.", config)) == 1
+        assert len(chunker.chunk("sentence", "A. B.", config)) == 1
+
+    def test_text_normalization(self, chunker):
+        text = "  Caf\u0065\u0301\x07  au\t\tlait.  "
+        plain = chunker.chunk_fixed(text, 100)
+        config = ChunkConfig(
+            max_size=100,
+            trim=True,
+            collapse_whitespace=True,
+            normalize_unicode="NFC",
+            strip_control_chars=True,
+        )
+        assert config.normalize_unicode == "nfc"
+        chunks = chunker.chunk_fixed(text, config=config)
+        assert chunks[0].text == "Caf\u00e9 au lait."
+        assert (chunks[0].start, chunks[0].end) == (plain[0].start, plain[0].end)
+        lazy = list(chunker.iter_chunks(text, "fixed_size", config=config))
+        assert lazy[0].text == chunks[0].text
+        assert pickle.loads(pickle.dumps(config)) == config
+        with pytest.raises(ValueError, match="normalization form"):
+            ChunkConfig(normalize_unicode="nfx")
+
+    def test_text_normalization_kwargs(self, chunker):
+        text = "  Caf\u0065\u0301\x07  au\t\tlait.  "
+        kwargs = dict(
+            trim=True,
+            collapse_whitespace=True,
+            normalize_unicode="nfc",
+            strip_control_chars=True,
+        )
+        assert chunker.chunk_fixed(text, 100, **kwargs)[0].text == "Caf\u00e9 au lait."
+        assert chunker.chunk_paragraphs(text, 100, **kwargs)[0].text == "Caf\u00e9 au lait."
+        assert chunker.chunk("sentence", text, max_size=100, **kwargs)[0].text == "Caf\u00e9 au lait."
+        lazy = list(chunker.iter_chunks(text, "fixed_size", 100, **kwargs))
+        assert lazy[0].text == "Caf\u00e9 au lait."
+        # Keyword arguments override the config
+        config = ChunkConfig(max_size=100, trim=True)
+        assert chunker.chunk_fixed(text, config=config, trim=False)[0].text.startswith("  ")
+        assert chunker.chunk_fixed(" a ", 10, trim=True)[0].text == "a"
+        with pytest.raises(ValueError, match="normalization form"):
+            chunker.chunk_fixed(text, 100, normalize_unicode="nfx")
+        # None leaves the config untouched; unknown keywords are rejected
+        assert chunker.chunk_fixed(text, config=config, trim=None)[0].text.startswith("C")
+        with pytest.raises(TypeError, match="unexpected keyword argument 'trimm'"):
+            chunker.chunk_fixed(text, 100, trimm=True)
+
+    def test_normalize_input(self, chunker):
+        nfd = "Cafe\u0301. Cre\u0300me bru\u0302le\u0301e."
+        nfc = "Caf\u00e9. Cr\u00e8me br\u00fbl\u00e9e."
+        chunks = chunker.chunk_sentences(nfd, 10, normalize="NFC")
+        assert [c.text for c in chunks] == ["Caf\u00e9.", "Cr\u00e8me br\u00fbl\u00e9e."]
+        # Positions are byte offsets into the normalized text
+        encoded = nfc.encode()
+        assert all(encoded[c.start:c.end].decode() == c.text for c in chunks)
+        assert chunker.chunk_sentences(nfd, 10)[0].text == "Cafe\u0301."
+        # Input normalization runs first, then chunk text normalization
+        chunks = chunker.chunk_sentences(nfd, 10, normalize="nfc", normalize_unicode="nfd")
+        assert [c.text for c in chunks] == ["Cafe\u0301.", "Cre\u0300me bru\u0302le\u0301e."]
+        assert [(c.start, c.end) for c in chunks] == [(0, 6), (7, len(encoded))]
+
+        config = ChunkConfig(max_size=10, normalization="nfc")
+        lazy = list(chunker.iter_chunks(nfd, "fixed_size", config=config))
+        assert "".join(c.text for c in lazy) == nfc
+        assert pickle.loads(pickle.dumps(config)).normalization == "nfc"
+        with pytest.raises(ValueError, match="normalization form"):
+            chunker.chunk_fixed(nfd, normalize="nfx")
+
+    def test_max_tokens(self, chunker):
+        text = " ".join(f"Sentence number {i} is here." for i in range(20))
+        config = ChunkConfig(max_size=10_000, max_tokens=16)
+        chunks = chunker.chunk_sentences(text, config=config)
+        assert len(chunks) > 1
+        assert all(c.token_estimate <= 16 for c in chunks)
+        assert chunker.chunk_fixed("hello world", 100)[0].token_estimate == 2
+
+        assert pickle.loads(pickle.dumps(config)).max_tokens == 16
+        with pytest.raises(ValueError, match="token estimator"):
+            ChunkConfig(token_estimator="gpt2")
+        with pytest.raises(ValueError, match="max_tokens"):
+            ChunkConfig(max_tokens=0).validate()
+
+    def test_checksum(self, chunker):
+        config = ChunkConfig(max_size=5, compute_checksum=True)
+        chunks = chunker.chunk_fixed("hello world", config=config)
+        assert all(len(c.metadata.checksum) == 8 for c in chunks)
+        assert all(c.verify_checksum() for c in chunks)
+        assert chunker.chunk_fixed("hello world", 5)[0].metadata.checksum is None
+
+        tampered = Chunk("x", "jello", 0, 5, chunks[0].metadata)
+        assert not tampered.verify_checksum()
+
+    def test_error_on_empty(self, chunker):
+        strict = ChunkConfig(max_size=10, error_on_empty=True)
+        with pytest.raises(ValueError, match="empty"):
+            chunker.chunk_fixed("", config=strict)
+        with pytest.raises(ValueError, match="empty"):
+            chunker.chunk_paragraphs("  \n\n  ", config=strict)
+        assert len(chunker.chunk_fixed("hello", config=strict)) == 1
+        assert chunker.chunk_fixed("") == []
+
+    def test_hash_ids_stable(self, chunker):
+        text = "First paragraph.\n\nSecond paragraph.\n\nThird paragraph."
+        first = chunker.chunk_paragraphs(text, 20, id_strategy="hash")
+        second = chunker.chunk_paragraphs(text, 20, id_strategy="hash")
+        assert [c.id for c in first] == [c.id for c in second]
+        assert chunker.chunk_paragraphs(text, 20)[0].id != first[0].id
+
+    def test_sequential_ids(self, chunker):
+        chunks = chunker.chunk_fixed("hello world", 5, id_strategy="sequential", doc_id="d1")
+        assert [c.id for c in chunks] == ["d1-0", "d1-1", "d1-2"]
+        config = ChunkConfig(5, id_strategy="sequential")
+        assert chunker.chunk_fixed("hello", config=config)[0].id == "doc-0"
+
+    def test_invalid_id_strategy(self, chunker):
+        with pytest.raises(ValueError, match="id_strategy"):
+            chunker.chunk_fixed("hello", id_strategy="random")
+        with pytest.raises(ValueError, match="id_strategy"):
+            ChunkConfig(id_strategy="random")
@@ -1,5 +1,9 @@
 """Tests for structural chunking algorithms (v0.2)."""
 
+import pytest
+
+from bunkatsu import ChunkConfig
+
 
 class TestMarkdown:
     def test_code_block_preserved(self, chunker):
@@ -11,6 +15,51 @@ class TestMarkdown:
         chunks = chunker.chunk_markdown("## Section\n\nText.", 1000)
         assert chunks[0].metadata.section == "h2: Section"
 
+    def test_crlf_matches_lf(self, chunker):
+        lf = "# One\n\nFirst body.\n\n```\ncode\n```\n\n## Two\n\nSecond body."
+        crlf = lf.replace("\n", "\r\n")
+        lf_chunks = chunker.chunk_markdown(lf, 20)
+        crlf_chunks = chunker.chunk_markdown(crlf, 20)
+        assert len(lf_chunks) == len(crlf_chunks) > 1
+        for a, b in zip(lf_chunks, crlf_chunks):
+            assert crlf[b.start:b.end].replace("\r\n", "\n") == lf[a.start:a.end]
+
+    def test_front_matter(self, chunker):
+        md = "---\ntitle: Guide\n---\n# Heading\n\nBody."
+        assert chunker.front_matter(md) == {"format": "yaml", "content": "title: Guide", "end": 21}
+        chunks = chunker.chunk_markdown(md, 1000)
+        assert "title" not in chunks[0].text
+        assert chunks[0].metadata.section == "h1: Heading"
+        assert chunker.front_matter("# No front matter") is None
+
+    def test_split_levels(self, chunker):
+        text = "## Install\n\nSteps.\n\n### Linux\n\napt.\n\n## Usage\n\nRun."
+        assert len(chunker.chunk_markdown(text)) == 3
+        chunks = chunker.chunk_markdown(text, split_levels=[1, 2])
+        assert [c.metadata.section for c in chunks] == ["h2: Install", "h2: Usage"]
+        assert "### Linux" in chunks[0].text
+        assert len(chunker.chunk("markdown", text, options={"split_levels": [2]})) == 2
+        with pytest.raises(ValueError):
+            chunker.chunk_markdown(text, split_levels=[])
+
+    def test_blockquote_and_list_blocks(self, chunker):
+        quote = "> A quoted line.\n> Another quoted line."
+        items = "- first\n  - nested\n- second"
+        chunks = chunker.chunk_markdown(f"Intro.\n\n{quote}\n\n{items}\n\nOutro.", 30)
+        assert [c.metadata.block_type for c in chunks] == [None, "blockquote", "list", None]
+        assert chunks[1].text == quote
+        assert chunks[2].text == items
+
+
+    def test_prepend_section(self, chunker):
+        text = "## Installation\n\nFirst step here. Second step here. Third step here."
+        config = ChunkConfig(max_size=20, prepend_section=True)
+        chunks = chunker.chunk_markdown(text, config=config)
+        assert chunks[1].text == "Installation:\nFirst step here."
+        assert chunks[2].text == "Installation:\nSecond step here."
+        assert chunks[2].metadata.extra == {"section_prefix": "Installation:\n"}
+        assert text[chunks[2].start:chunks[2].end] == "Second step here."
+
 
 class TestHeading:
     def test_split_at_headings(self, chunker):
@@ -18,6 +67,57 @@ class TestHeading:
         chunks = chunker.chunk_headings(text, 1000)
         assert len(chunks) == 2
 
+    def test_setext(self, chunker):
+        text = "Title\n=====\n\nIntro.\n\nSub\n---\n\nBody."
+        for chunks in (chunker.chunk_headings(text, 1000), chunker.chunk_markdown(text, 1000)):
+            assert [c.metadata.section for c in chunks] == ["h1: Title", "h2: Sub"]
+            assert "---" not in chunks[1].text
+
+    def test_levels(self, chunker):
+        text = "# One\n\nA.\n\n## Sub\n\nB.\n\n### Deep\n\nC."
+        assert len(chunker.chunk_headings(text, levels=[1])) == 1
+        assert len(chunker.chunk_headings(text, levels=[1, 2, 3])) == 3
+
+    def test_breadcrumbs(self, chunker):
+        text = "# Guide\n\nIntro.\n\n## Install\n\nSteps."
+        chunks = chunker.chunk_headings(text, breadcrumbs=True)
+        assert [c.metadata.section for c in chunks] == ["h1: Guide", "h1: Guide > h2: Install"]
+        chunks = chunker.chunk("heading", text, options={"breadcrumbs": True})
+        assert chunks[1].metadata.section == "h1: Guide > h2: Install"
+        assert chunker.chunk_headings(text)[1].metadata.section == "h2: Install"
+
+    def test_respect_max_size(self, chunker):
+        body = "\n\n".join(f"Paragraph {i} talks about chunking. It has two sentences." for i in range(60))
+        text = f"# Intro\n\nShort.\n\n# Long\n\n{body}"
+        assert len(chunker.chunk_headings(text, 500)) == 2
+        chunks = chunker.chunk_headings(text, 500, respect_max_size=True)
+        assert len(chunks) > 7
+        assert all(len(c.text.encode()) <= 500 for c in chunks)
+        assert chunks[1].text.startswith("# Long\n\nParagraph 0")
+        assert {c.metadata.section for c in chunks[1:]} == {"h1: Long"}
+        assert text[chunks[1].start:].startswith("# Long")
+        assert all(text[c.start:c.end].endswith(c.text[-20:]) for c in chunks)
+
+    def test_invalid_levels(self, chunker):
+        for levels in ([], [0], [1, 7]):
+            with pytest.raises(ValueError):
+                chunker.chunk_headings("# One\n\nA.", levels=levels)
+
+
+class TestOutline:
+    def test_nested(self, chunker):
+        text = "# Guide\n\n### Deep\n\nText.\n\n## Setup\n\nRun.\n\n# Guide\n\nAgain."
+        outline = chunker.outline(text)
+        assert [e["title"] for e in outline] == ["Guide", "Guide"]
+        deep, setup = outline[0]["children"]
+        assert (deep["level"], deep["end"]) == (3, setup["start"])
+        assert setup["children"] == []
+        sections = {c.metadata.section: c.start for c in chunker.chunk_markdown(text, 1000)}
+        assert sections[setup["section"]] == setup["start"]
+
+    def test_no_headings(self, chunker):
+        assert chunker.outline("Plain text.") == []
+
 
 class TestRecursive:
     def test_paragraph_fallback(self, chunker):
@@ -25,3 +125,92 @@ class TestRecursive:
         chunks = chunker.chunk_recursive(text, 30)
         assert len(chunks) >= 2
         assert "recursive" in chunks[0].metadata.method
+
+    def test_document_offsets(self, chunker):
+        text = "Intro.\n\nFirst long sentence goes here. Second long sentence follows it.\n\nEnd."
+        raw = text.encode()
+        chunks = chunker.chunk_recursive(text, 40)
+        assert len(chunks) == 4
+        for chunk in chunks:
+            assert chunk.text.encode() in raw[chunk.start:chunk.end]
+
+    def test_markdown_strategy(self, chunker):
+        items = "\n\n".join(
+            f"- Item {i} has a first sentence here. Then a second one follows it." for i in range(4)
+        )
+        text = f"# Guide\n\nIntro.\n\n## Installation\n\n{items}\n\n## Usage\n\nRun it."
+        chunks = chunker.chunk_recursive(text, 60, strategy="markdown")
+        deep = [c for c in chunks if c.metadata.method == "recursive_l2"]
+        assert len(deep) == 8
+        assert {c.metadata.section for c in deep} == {"h2: Installation"}
+        assert chunks[-1].metadata.section == "h2: Usage"
+        assert all(c.metadata.section is None for c in chunker.chunk_recursive(text, 60))
+        with pytest.raises(ValueError, match="recursive strategy"):
+            chunker.chunk_recursive(text, strategy="markdown_first")
+
+
+class TestHtml:
+    def test_strips_tags(self, chunker):
+        html = "<h1>Title</h1><p>Fish &amp; chips</p><script>x()</script>"
+        chunks = chunker.chunk_html(html, 1000)
+        assert chunks[0].text == "Title\n\nFish & chips"
+        assert chunks[0].metadata.section == "h1: Title"
+
+
+class TestXml:
+    def test_element_paths(self, chunker):
+        xml = "<doc><sec><p>A &amp; B</p><p>C</p></sec><note>x</note></doc>"
+        chunks = chunker.chunk_xml(xml, ["doc/sec/p"], 1000)
+        assert len(chunks) == 1
+        assert chunks[0].text == "A & B\n\nC"
+        assert chunks[0].metadata.section == "doc/sec/p"
+
+    def test_malformed(self, chunker):
+        with pytest.raises(ValueError):
+            chunker.chunk_xml("<a><b></a>", ["a"], 100)
+
+
+class TestLatex:
+    def test_sections_and_environments(self, chunker):
+        text = (
+            "\\section{Intro}\nText. % note\n\n"
+            "\\begin{equation}\nE = mc^2 % famous\n\\end{equation}\n\n"
+            "\\section{End}\nBye."
+        )
+        chunks = chunker.chunk_latex(text, max_size=25)
+        assert [c.metadata.section for c in chunks] == [
+            "\\section{Intro}",
+            "\\section{Intro}",
+            "\\section{End}",
+        ]
+        assert chunks[0].text == "\\section{Intro}\n\nText."
+        assert chunks[1].metadata.block_type == "equation"
+        assert chunks[1].text == "\\begin{equation}\nE = mc^2\n\\end{equation}"
+
+    def test_keep_comments(self, chunker):
+        chunks = chunker.chunk_latex("Text. % note", strip_comments=False)
+        assert chunks[0].text == "Text. % note"
+
+    def test_unclosed_environment(self, chunker):
+        chunks = chunker.chunk_latex("\\begin{figure}\n\\centering\n\\section{X}")
+        assert len(chunks) == 1
+        assert chunks[0].metadata.block_type == "figure"
+
+    def test_iter_chunks(self, chunker):
+        text = "\\section{A}\nOne.\n\n\\section{B}\nTwo."
+        assert len(list(chunker.iter_chunks(text, "latex"))) == 2
+
+
+class TestJson:
+    def test_array_elements_intact(self, chunker):
+        import json
+
+        data = [{"id": i} for i in range(50)]
+        chunks = chunker.chunk_json(json.dumps(data), 60)
+        assert len(chunks) > 1
+        ids = [item["id"] for c in chunks for item in json.loads(c.text)]
+        assert ids == list(range(50))
+
+    def test_invalid(self, chunker):
+        with pytest.raises(ValueError):
+            chunker.chunk_json("[1, 2", 100)